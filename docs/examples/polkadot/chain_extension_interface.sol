@@ -0,0 +1,17 @@
+// The "rand-extension" example chain extension demonstrated here:
+// https://use.ink/macros-attributes/chain-extension
+@chain_extension(0)
+interface RandExtension {
+    // Takes a bytes32 as input seed and returns a pseudo random bytes32.
+    @function_id(1101)
+    function fetch_random(bytes32 subject) external returns (bytes32);
+}
+
+contract Foo {
+    function fetch_random(bytes32 _seed) public returns (bytes32) {
+        bytes32 random = RandExtension.fetch_random(_seed);
+
+        print("pseudo random bytes: {}".format(random));
+        return random;
+    }
+}
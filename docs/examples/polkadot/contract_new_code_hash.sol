@@ -0,0 +1,13 @@
+contract hatchling {
+    string name;
+
+    constructor(string id) {
+        require(id != "", "name must be provided");
+        name = id;
+    }
+
+    // Hatch a new hatchling, running the code with the given code hash
+    function hatch(bytes32 code_hash, string id) public returns (hatchling) {
+        return new hatchling{codeHash: code_hash}(id);
+    }
+}
@@ -0,0 +1,12 @@
+contract foo {
+    function bar(other o) public {
+        // at most 1_000_000_000 ref time, 100_000 proof size and 1_000 storage deposit
+        o.feh{refTime: 1_000_000_000, proofSize: 100_000, storageDepositLimit: 1_000}(102);
+    }
+}
+
+contract other {
+    function feh(uint32 x) public {
+        // ...
+    }
+}
@@ -0,0 +1,18 @@
+contract Vault {
+    mapping(address => uint128) balances;
+
+    function deposit() public payable {
+        balances[msg.sender] += msg.value;
+    }
+
+    // The value is sent before the balance is cleared. Without the annotation,
+    // the caller could call withdraw() again while receiving the value, and be
+    // paid twice.
+    @nonReentrant
+    function withdraw() public {
+        uint128 amount = balances[msg.sender];
+        (bool ok, ) = msg.sender.call{value: amount}("");
+        require(ok);
+        balances[msg.sender] = 0;
+    }
+}
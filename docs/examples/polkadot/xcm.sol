@@ -0,0 +1,21 @@
+import "polkadot";
+
+contract CrossChain {
+    address owner = msg.sender;
+
+    event Sent(Hash message_hash);
+
+    // Both the destination and the message are SCALE encoded by the caller;
+    // dest is a VersionedLocation and message is a VersionedXcm.
+    function send(bytes dest, bytes message) public {
+        require(msg.sender == owner, "only owner");
+        Hash hash = Hash.wrap(xcm.send(dest, message));
+        emit Sent(hash);
+    }
+
+    // Executes a VersionedXcm message with the contract as its origin.
+    function execute(bytes message) public {
+        require(msg.sender == owner, "only owner");
+        xcm.execute(message);
+    }
+}
@@ -0,0 +1,16 @@
+contract vault {
+    function withdraw(uint64 available, uint64 amount) public pure returns (uint64) {
+        return available - amount;
+    }
+}
+
+contract client {
+    function withdraw(address vault_id, uint64 amount) public returns (uint64) {
+        try vault.withdraw{program_id: vault_id, accounts: []}(100, amount) returns (uint64 left) {
+            return left;
+        } catch {
+            // never executed: if the call fails, the transaction is aborted
+            return 0;
+        }
+    }
+}
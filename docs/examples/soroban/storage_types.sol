@@ -0,0 +1,22 @@
+contract storage_types {
+    @persistent
+    mapping(address => uint64) balances;
+
+    @temporary
+    mapping(address => uint64) nonces;
+
+    @instance
+    address admin;
+
+    uint64 total;
+
+    function deposit(address from, uint64 amount) public {
+        from.requireAuth();
+
+        balances[from] += amount;
+        total += amount;
+
+        // keep the balance around for at least 10000 more ledgers
+        balances[from].extendTtl(5000, 10000);
+    }
+}
@@ -0,0 +1,40 @@
+import "polkadot";
+
+// A proxy which forwards all calls to its implementation with delegatecall.
+//
+// The code of the implementation might be removed from the chain once no
+// contract uses it anymore, which would leave the proxy unusable. Locking it
+// as a delegate dependency keeps the code on chain for as long as the lock is
+// held. The storage deposit for the lock is charged to the proxy.
+//
+// The admin must pass the code hash of the implementation contract along with
+// its address.
+contract Proxy {
+    address admin;
+    address implementation;
+    Hash code_hash;
+
+    constructor(address _implementation, Hash _code_hash) {
+        admin = msg.sender;
+        implementation = _implementation;
+        code_hash = _code_hash;
+        lock_delegate_dependency(_code_hash);
+    }
+
+    function upgrade(address _implementation, Hash _code_hash) external {
+        require(msg.sender == admin, "only admin");
+
+        // Lock the new code before giving up the old, so the proxy always
+        // depends on code which cannot be removed.
+        lock_delegate_dependency(_code_hash);
+        unlock_delegate_dependency(code_hash);
+
+        implementation = _implementation;
+        code_hash = _code_hash;
+    }
+
+    fallback() external {
+        (bool ok, ) = implementation.delegatecall(msg.data);
+        require(ok);
+    }
+}
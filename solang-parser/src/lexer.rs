@@ -180,6 +180,7 @@ pub enum Token<'input> {
     YulArrow,
 
     Annotation(&'input str),
+    AnnotationWithValue(&'input str),
 }
 
 impl<'input> fmt::Display for Token<'input> {
@@ -315,7 +316,7 @@ impl<'input> fmt::Display for Token<'input> {
             Token::Case => write!(f, "case"),
             Token::Default => write!(f, "default"),
             Token::YulArrow => write!(f, "->"),
-            Token::Annotation(name) => write!(f, "@{name}"),
+            Token::Annotation(name) | Token::AnnotationWithValue(name) => write!(f, "@{name}"),
         }
     }
 }
@@ -978,6 +979,8 @@ impl<'input> Lexer<'input> {
                             Loc::File(self.file_no, start, start + 1),
                             id.to_owned(),
                         ));
+                    } else if self.input[end..].trim_start().starts_with('(') {
+                        return Some((start, Token::AnnotationWithValue(&id[1..]), end));
                     } else {
                         return Some((start, Token::Annotation(&id[1..]), end));
                     };
@@ -1912,6 +1915,20 @@ mod tests {
         assert!(errors.is_empty());
         assert!(comments.is_empty());
 
+        let tokens =
+            Lexer::new("@my_annotation (1)", 0, &mut comments, &mut errors).collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            vec![
+                (0, Token::AnnotationWithValue("my_annotation"), 14),
+                (15, Token::OpenParenthesis, 16),
+                (16, Token::Number("1", ""), 17),
+                (17, Token::CloseParenthesis, 18),
+            ]
+        );
+        assert!(errors.is_empty());
+        assert!(comments.is_empty());
+
         errors.clear();
         comments.clear();
         let tokens =
@@ -993,7 +993,7 @@ pub struct TypeDefinition {
 
 /// An annotation.
 ///
-/// `@<id>(<value>)`
+/// `@<id>(<value>)` or `@<id>`
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[cfg_attr(feature = "pt-serde", derive(Serialize, Deserialize))]
 pub struct Annotation {
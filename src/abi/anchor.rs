@@ -1,11 +1,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::codegen::revert::solana_error_code;
 use crate::sema::ast::{
     ArrayLength, Contract, Function, Namespace, Parameter, StructDecl, StructType, Tag, Type,
 };
 use anchor_syn::idl::types::{
-    Idl, IdlAccount, IdlAccountItem, IdlEnumVariant, IdlEvent, IdlEventField, IdlField,
-    IdlInstruction, IdlType, IdlTypeDefinition, IdlTypeDefinitionTy,
+    Idl, IdlAccount, IdlAccountItem, IdlEnumVariant, IdlErrorCode, IdlEvent, IdlEventField,
+    IdlField, IdlInstruction, IdlType, IdlTypeDefinition, IdlTypeDefinitionTy,
 };
 use base58::ToBase58;
 use num_traits::ToPrimitive;
@@ -53,6 +54,8 @@ pub fn generate_anchor_idl(contract_no: usize, ns: &Namespace, contract_version:
 
     let events = idl_events(contract, &mut type_manager, ns);
 
+    let errors = idl_errors(contract, ns);
+
     let metadata = contract
         .program_id
         .as_ref()
@@ -67,7 +70,7 @@ pub fn generate_anchor_idl(contract_no: usize, ns: &Namespace, contract_version:
         accounts: vec![],
         types: type_manager.generate_custom_idl_types(),
         events,
-        errors: None,
+        errors,
         metadata,
     }
 }
@@ -105,6 +108,34 @@ fn idl_events(
     }
 }
 
+/// Generate IDL errors. Custom errors are returned with their Anchor style error code,
+/// so list the errors of the contract with the `@notice` tag as message.
+fn idl_errors(contract: &Contract, ns: &Namespace) -> Option<Vec<IdlErrorCode>> {
+    if contract.errors.is_empty() {
+        None
+    } else {
+        let errors = contract
+            .errors
+            .iter()
+            .map(|error_no| {
+                let def = &ns.errors[*error_no];
+
+                IdlErrorCode {
+                    code: solana_error_code(contract, *error_no),
+                    name: def.name.clone(),
+                    msg: def
+                        .tags
+                        .iter()
+                        .find(|tag| tag.tag == "notice")
+                        .map(|tag| tag.value.clone()),
+                }
+            })
+            .collect();
+
+        Some(errors)
+    }
+}
+
 /// Generate the IDL instructions for a contract.
 fn idl_instructions(
     contract_no: usize,
@@ -13,7 +13,7 @@ use ink_metadata::{
 use serde_json::Value;
 
 use num_bigint::BigInt;
-use num_traits::ToPrimitive;
+use num_traits::{ToPrimitive, Zero};
 use scale_info::{
     form::PortableForm, Field, Path, PortableRegistryBuilder, Type, TypeDef, TypeDefArray,
     TypeDefComposite, TypeDefPrimitive, TypeDefSequence, TypeDefTuple, TypeDefVariant, Variant,
@@ -265,24 +265,53 @@ fn resolve_ast(ty: &ast::Type, ns: &ast::Namespace, registry: &mut PortableRegis
     }
 }
 
-/// Recursively build the storage layout after all types are registered
-fn type_to_storage_layout(
-    key: u32,
-    root: LayoutKey,
-    registry: &PortableRegistryBuilder,
-) -> Layout<PortableForm> {
-    let ty = registry.get(key).unwrap();
-    match &ty.type_def {
-        TypeDef::Composite(inner) => Layout::Struct(StructLayout::new(
-            ty.path.ident().unwrap_or_default(),
-            inner.fields.iter().map(|field| {
-                FieldLayout::new(
-                    field.name.clone().unwrap_or_default(),
-                    type_to_storage_layout(field.ty.id, root, registry),
-                )
-            }),
-        )),
-        _ => Layout::Leaf(LeafLayout::new(root, key.into())),
+/// Build the storage layout for a value of type `ty` stored at `slot`, if it can be described.
+///
+/// The keys in the layout are 32 bits, like the root keys of ink!. Solang stores its values under
+/// their 32 byte slot, which cannot be described this way. The exception are the entries of
+/// mappings with ink! storage keys, which are stored under the slot as root key and the mapping
+/// key, like the entries of an ink! `Mapping`.
+fn storage_layout(
+    ty: &ast::Type,
+    slot: &BigInt,
+    ns: &ast::Namespace,
+    registry: &mut PortableRegistryBuilder,
+) -> Option<Layout<PortableForm>> {
+    match ty.deref_any() {
+        ast::Type::Struct(s) => {
+            let def = s.definition(ns);
+            let mut offset = BigInt::zero();
+            let fields = def
+                .fields
+                .iter()
+                .filter(|field| !field.infinite_size)
+                .filter_map(|field| {
+                    let field_slot = slot + &offset;
+                    offset += field.ty.storage_slots(ns);
+
+                    storage_layout(&field.ty, &field_slot, ns, registry)
+                        .map(|layout| FieldLayout::new(field.name_as_str().to_string(), layout))
+                })
+                .collect::<Vec<_>>();
+
+            if fields.is_empty() {
+                None
+            } else {
+                Some(Layout::Struct(StructLayout::new(
+                    def.id.name.clone(),
+                    fields,
+                )))
+            }
+        }
+        ast::Type::Mapping(ast::Mapping { value, .. }) if ns.target.ink_storage_keys() => {
+            let root_key = LayoutKey::new(slot.to_u32()?);
+            let value = resolve_ast(value, ns, registry);
+            let ty = resolve_ast(ty, ns, registry);
+            let leaf = Layout::Leaf(LeafLayout::new(root_key, value.into()));
+
+            Some(Layout::Root(RootLayout::new(root_key, leaf, ty.into())))
+        }
+        _ => None,
     }
 }
 
@@ -290,26 +319,14 @@ fn type_to_storage_layout(
 pub fn gen_project<'a>(contract_no: usize, ns: &'a ast::Namespace) -> InkProject {
     let mut registry = PortableRegistryBuilder::new();
 
-    // This is only used by off-chain tooling. At the moment there is no such tooling available yet.
-    // So it is not exactly clear yet what this should look like.
-    // For now it just contains all root layouts (you get all storage keys in use).
+    // The layout of the state variables which can be described, see `storage_layout`
     let fields: Vec<FieldLayout<PortableForm>> = ns.contracts[contract_no]
         .layout
         .iter()
         .filter_map(|layout| {
             let var = &ns.contracts[layout.contract_no].variables[layout.var_no];
-            if let Some(slot) = layout.slot.to_u32() {
-                let ty = resolve_ast(&layout.ty, ns, &mut registry);
-                let layout_key = LayoutKey::new(slot);
-                let root = RootLayout::new(
-                    layout_key,
-                    type_to_storage_layout(ty, layout_key, &registry),
-                    ty.into(),
-                );
-                Some(FieldLayout::new(var.name.clone(), root))
-            } else {
-                None
-            }
+            let layout = storage_layout(&layout.ty, &layout.slot, ns, &mut registry)?;
+            Some(FieldLayout::new(var.name.clone(), layout))
         })
         .collect();
     let contract_name = ns.contracts[contract_no].id.name.clone();
@@ -8,8 +8,8 @@ use crate::file_resolver::FileResolver;
 use crate::sema::ast::Namespace;
 use crate::{codegen, parse_and_resolve, Target};
 use anchor_syn::idl::types::{
-    IdlAccount, IdlAccountItem, IdlEnumVariant, IdlEvent, IdlEventField, IdlField, IdlType,
-    IdlTypeDefinition, IdlTypeDefinitionTy,
+    IdlAccount, IdlAccountItem, IdlEnumVariant, IdlErrorCode, IdlEvent, IdlEventField, IdlField,
+    IdlType, IdlTypeDefinition, IdlTypeDefinitionTy,
 };
 use semver::Version;
 use serde_json::json;
@@ -1477,6 +1477,8 @@ fn data_account_signer() {
 fn accounts_call_chain() {
     let src = r#"
     contract Test {
+    uint64 counter;
+
     function call_1() public view returns (uint64) {
         return call_2();
     }
@@ -1486,7 +1488,7 @@ fn accounts_call_chain() {
     }
 
     function call_3() public view returns (uint64) {
-        return block.timestamp;
+        return counter;
     }
 }
     "#;
@@ -1503,7 +1505,7 @@ fn accounts_call_chain() {
     assert_eq!(idl.instructions[1].name, "call_1");
     assert_eq!(
         idl.instructions[1].accounts,
-        vec![idl_account("clock", false, false)]
+        vec![idl_account("dataAccount", false, false)]
     );
 
     assert_eq!(idl.instructions[2].name, "call_2");
@@ -1560,7 +1562,6 @@ fn accounts_on_recursion() {
         vec![
             idl_account("dataAccount", false, false),
             idl_account("SysvarInstruction", false, false),
-            idl_account("clock", false, false),
         ]
     );
 
@@ -1568,18 +1569,7 @@ fn accounts_on_recursion() {
     assert_eq!(idl.instructions[2].accounts, idl.instructions[1].accounts);
 
     assert_eq!(idl.instructions[3].name, "call_3");
-    assert_eq!(
-        idl.instructions[3].accounts[0],
-        idl.instructions[1].accounts[0]
-    );
-    assert_eq!(
-        idl.instructions[3].accounts[1],
-        idl.instructions[1].accounts[2]
-    );
-    assert_eq!(
-        idl.instructions[3].accounts[2],
-        idl.instructions[1].accounts[1]
-    );
+    assert_eq!(idl.instructions[3].accounts, idl.instructions[1].accounts);
 }
 
 #[test]
@@ -1842,7 +1832,6 @@ contract Child {
             idl_account("Child_programId", false, false),
             idl_account("payer", true, true),
             idl_account("Child_dataAccount", true, true),
-            idl_account("clock", false, false),
         ]
     );
 }
@@ -2003,7 +1992,6 @@ contract CT1 {
         vec![
             idl_account("dataAccount", true, true),
             idl_account("two_payer", true, true),
-            idl_account("clock", false, false),
             idl_account("systemProgram", false, false),
             idl_account("CT1_programId", false, false),
             idl_account("one_payer", true, true),
@@ -2021,7 +2009,6 @@ contract CT1 {
             idl_account("CT2_programId", false, false),
             idl_account("two_payer", true, true),
             idl_account("CT2_dataAccount", true, true),
-            idl_account("clock", false, false),
             idl_account("CT1_programId", false, false),
             idl_account("one_payer", true, true),
             idl_account("CT1_dataAccount", true, true),
@@ -2117,6 +2104,68 @@ contract Test2 {
     );
 }
 
+#[test]
+fn custom_errors() {
+    let src = r#"
+/// @notice Not enough funds
+error InsufficientBalance(uint64 available, uint64 required);
+
+error Unused();
+
+contract Bank {
+    error Unauthorized(address caller);
+
+    function withdraw(uint64 amount, uint64 balance) public pure {
+        require(amount <= balance, InsufficientBalance(balance, amount));
+    }
+
+    function close() public view {
+        revert Unauthorized(address(this));
+    }
+}
+
+contract Vault {
+    error Locked();
+
+    function open() public pure {
+        revert Locked();
+    }
+}
+    "#;
+
+    let mut ns = generate_namespace(src);
+    codegen(&mut ns, &Options::default());
+    let idl = generate_anchor_idl(0, &ns, "0.1.0");
+
+    assert_eq!(
+        idl.errors,
+        Some(vec![
+            IdlErrorCode {
+                code: 6000,
+                name: "InsufficientBalance".to_string(),
+                msg: Some("Not enough funds".to_string()),
+            },
+            IdlErrorCode {
+                code: 6001,
+                name: "Unauthorized".to_string(),
+                msg: None,
+            },
+        ])
+    );
+
+    // Errors are numbered per contract
+    let idl = generate_anchor_idl(1, &ns, "0.1.0");
+
+    assert_eq!(
+        idl.errors,
+        Some(vec![IdlErrorCode {
+            code: 6000,
+            name: "Locked".to_string(),
+            msg: None,
+        }])
+    );
+}
+
 fn idl_account(name: &str, is_mut: bool, is_signer: bool) -> IdlAccountItem {
     IdlAccountItem::IdlAccount(IdlAccount {
         name: name.to_string(),
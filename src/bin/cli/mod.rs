@@ -187,6 +187,10 @@ impl Compile {
                 "NOPRINTS" => {
                     self.debug_features.log_prints = *matches.get_one::<bool>("NOPRINTS").unwrap()
                 }
+                "LOGCOMPUTEUNITS" => {
+                    self.debug_features.log_compute_units =
+                        *matches.get_one::<bool>("LOGCOMPUTEUNITS").unwrap()
+                }
                 "GENERATEDEBUGINFORMATION" => {
                     self.debug_features.generate_debug_info =
                         *matches.get_one::<bool>("GENERATEDEBUGINFORMATION").unwrap()
@@ -227,6 +231,10 @@ impl Compile {
                 "VALUE_LENGTH" => {
                     self.target_arg.value_length = matches.get_one::<u64>("VALUE_LENGTH").copied()
                 }
+                "INK_STORAGE_KEYS" => {
+                    self.target_arg.ink_storage_keys =
+                        *matches.get_one::<bool>("INK_STORAGE_KEYS").unwrap()
+                }
 
                 _ => {}
             }
@@ -269,6 +277,9 @@ pub struct TargetArg {
 
     #[arg(name = "VALUE_LENGTH", help = "Value length on the Polkadot Parachain", long = "value-length", num_args = 1, value_parser = value_parser!(u64).range(4..1024))]
     pub value_length: Option<u64>,
+
+    #[arg(name = "INK_STORAGE_KEYS", help = "Store mapping entries under the same keys as ink! on the Polkadot Parachain", long = "ink-storage-keys", action = ArgAction::SetTrue)]
+    pub ink_storage_keys: bool,
 }
 
 #[derive(Args, Deserialize, Debug, PartialEq)]
@@ -281,6 +292,10 @@ pub struct CompileTargetArg {
 
     #[arg(name = "VALUE_LENGTH", help = "Value length on the Polkadot Parachain", long = "value-length", num_args = 1, value_parser = value_parser!(u64).range(4..1024))]
     pub value_length: Option<u64>,
+
+    #[arg(name = "INK_STORAGE_KEYS", help = "Store mapping entries under the same keys as ink! on the Polkadot Parachain", long = "ink-storage-keys", action = ArgAction::SetTrue)]
+    #[serde(default)]
+    pub ink_storage_keys: bool,
 }
 
 #[derive(Args)]
@@ -330,6 +345,16 @@ pub struct CompilePackage {
         num_args = 1
     )]
     pub soroban_version: Option<u64>,
+
+    #[arg(
+        name = "SOROBAN-NATIVE-TOKEN",
+        help = "specify the contract id of the soroban native token, in hex",
+        long = "soroban-native-token",
+        num_args = 1,
+        value_parser = ValueParser::new(parse_contract_id)
+    )]
+    #[serde(default, deserialize_with = "deserialize_contract_id")]
+    pub soroban_native_token: Option<String>,
 }
 
 #[derive(Args, Deserialize, Debug, PartialEq)]
@@ -342,6 +367,10 @@ pub struct DebugFeatures {
     #[serde(default = "default_true", rename(deserialize = "prints"))]
     pub log_prints: bool,
 
+    #[arg(name = "LOGCOMPUTEUNITS", help = "Log the compute units consumed by each function call on Solana, when runtime errors are logged", long = "log-compute-units", action = ArgAction::SetTrue)]
+    #[serde(default, rename(deserialize = "log-compute-units"))]
+    pub log_compute_units: bool,
+
     #[arg(name = "GENERATEDEBUGINFORMATION", help = "Enable generating debug information for LLVM IR", long = "generate-debug-info", action = ArgAction::SetTrue, short = 'g')]
     #[serde(default, rename(deserialize = "generate-debug-info"))]
     pub generate_debug_info: bool,
@@ -356,6 +385,7 @@ impl Default for DebugFeatures {
         DebugFeatures {
             log_runtime_errors: true,
             log_prints: true,
+            log_compute_units: false,
             generate_debug_info: false,
             release: false,
         }
@@ -406,6 +436,7 @@ pub trait TargetArgTrait {
     fn get_name(&self) -> &String;
     fn get_address_length(&self) -> &Option<u64>;
     fn get_value_length(&self) -> &Option<u64>;
+    fn get_ink_storage_keys(&self) -> bool;
 }
 
 impl TargetArgTrait for TargetArg {
@@ -420,6 +451,10 @@ impl TargetArgTrait for TargetArg {
     fn get_value_length(&self) -> &Option<u64> {
         &self.value_length
     }
+
+    fn get_ink_storage_keys(&self) -> bool {
+        self.ink_storage_keys
+    }
 }
 
 impl TargetArgTrait for CompileTargetArg {
@@ -439,6 +474,10 @@ impl TargetArgTrait for CompileTargetArg {
     fn get_value_length(&self) -> &Option<u64> {
         &self.value_length
     }
+
+    fn get_ink_storage_keys(&self) -> bool {
+        self.ink_storage_keys
+    }
 }
 
 pub(crate) fn target_arg<T: TargetArgTrait>(target_arg: &T) -> Target {
@@ -454,6 +493,11 @@ pub(crate) fn target_arg<T: TargetArgTrait>(target_arg: &T) -> Target {
             eprintln!("error: value length cannot be modified except for polkadot target");
             exit(1);
         }
+
+        if target_arg.get_ink_storage_keys() {
+            eprintln!("error: ink! storage keys cannot be used except for polkadot target");
+            exit(1);
+        }
     }
 
     let target = match target_name.as_str() {
@@ -461,6 +505,7 @@ pub(crate) fn target_arg<T: TargetArgTrait>(target_arg: &T) -> Target {
         "polkadot" => solang::Target::Polkadot {
             address_length: target_arg.get_address_length().unwrap_or(32) as usize,
             value_length: target_arg.get_value_length().unwrap_or(16) as usize,
+            ink_storage_keys: target_arg.get_ink_storage_keys(),
         },
         "evm" => solang::Target::EVM,
         "soroban" => solang::Target::Soroban,
@@ -581,6 +626,7 @@ pub fn options_arg(
         opt_level,
         log_runtime_errors: debug.log_runtime_errors && !debug.release,
         log_prints: debug.log_prints && !debug.release,
+        log_compute_units: debug.log_compute_units && debug.log_runtime_errors && !debug.release,
         #[cfg(feature = "wasm_opt")]
         wasm_opt: optimizations.wasm_opt_passes.or(if debug.release {
             Some(OptimizationPasses::Z)
@@ -588,6 +634,10 @@ pub fn options_arg(
             None
         }),
         soroban_version: compiler_inputs.soroban_version,
+        soroban_native_token: compiler_inputs
+            .soroban_native_token
+            .as_ref()
+            .map(|id| hex::decode(id).unwrap().try_into().unwrap()),
     }
 }
 
@@ -609,6 +659,14 @@ fn parse_version(version: &str) -> Result<String, String> {
     }
 }
 
+fn parse_contract_id(id: &str) -> Result<String, String> {
+    match hex::decode(id) {
+        Ok(bytes) if bytes.len() == 32 => Ok(id.to_string()),
+        Ok(_) => Err("contract id should be 32 bytes".to_string()),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
 fn deserialize_inline_table<'de, D>(
     deserializer: D,
 ) -> Result<Option<Vec<(String, PathBuf)>>, D::Error>
@@ -654,6 +712,21 @@ where
     }
 }
 
+fn deserialize_contract_id<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let res: Option<String> = Option::deserialize(deserializer)?;
+
+    match res {
+        Some(id) => match parse_contract_id(&id) {
+            Ok(id) => Ok(Some(id)),
+            Err(err) => Err(serde::de::Error::custom(err)),
+        },
+        None => Ok(None),
+    }
+}
+
 fn deserialize_emit<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -109,6 +109,7 @@ mod tests {
             authors: None,
             version: Some("0.1.0".to_string()),
             soroban_version: None,
+            soroban_native_token: None,
         };
 
         let opt = options_arg(&default_debug, &default_optimize, &compiler_package);
@@ -147,13 +148,15 @@ mod tests {
         let target_toml = r#"
         name = "polkadot"  # Valid targets are "solana" and "polkadot"
         address_length = 32
-        value_length = 16"#;
+        value_length = 16
+        ink_storage_keys = true"#;
 
         let target: cli::CompileTargetArg = toml::from_str(target_toml).unwrap();
 
         assert_eq!(target.name.unwrap(), "polkadot");
         assert_eq!(target.address_length.unwrap(), 32);
         assert_eq!(target.value_length.unwrap(), 16);
+        assert!(target.ink_storage_keys);
     }
 
     #[test]
@@ -196,7 +199,8 @@ mod tests {
                     import_map: Some(vec![]),
                     authors: None,
                     version: Some("0.1.0".to_string()),
-                    soroban_version: None
+                    soroban_version: None,
+                    soroban_native_token: None
                 },
                 compiler_output: cli::CompilerOutput {
                     emit: None,
@@ -208,11 +212,13 @@ mod tests {
                 target_arg: cli::CompileTargetArg {
                     name: Some("solana".to_owned()),
                     address_length: None,
-                    value_length: None
+                    value_length: None,
+                    ink_storage_keys: false
                 },
                 debug_features: cli::DebugFeatures {
                     log_runtime_errors: true,
                     log_prints: true,
+                    log_compute_units: false,
                     generate_debug_info: false,
                     release: false
                 },
@@ -251,7 +257,8 @@ mod tests {
                     import_map: Some(vec![]),
                     authors: Some(vec!["not_sesa".to_owned()]),
                     version: Some("0.1.0".to_string()),
-                    soroban_version: None
+                    soroban_version: None,
+                    soroban_native_token: None
                 },
                 compiler_output: cli::CompilerOutput {
                     emit: None,
@@ -263,11 +270,13 @@ mod tests {
                 target_arg: cli::CompileTargetArg {
                     name: Some("polkadot".to_owned()),
                     address_length: Some(33),
-                    value_length: Some(31)
+                    value_length: Some(31),
+                    ink_storage_keys: false
                 },
                 debug_features: cli::DebugFeatures {
                     log_runtime_errors: true,
                     log_prints: true,
+                    log_compute_units: false,
                     generate_debug_info: false,
                     release: false
                 },
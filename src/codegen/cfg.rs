@@ -12,7 +12,7 @@ use crate::codegen::subexpression_elimination::common_sub_expression_elimination
 use crate::codegen::{undefined_variable, Expression, LLVMName};
 use crate::sema::ast::{
     CallTy, Contract, ExternalCallAccounts, FunctionAttributes, Namespace, Parameter, RetrieveType,
-    Statement, StringLocation, StructType, Type,
+    Statement, StorageType, StringLocation, StructType, Type,
 };
 use crate::sema::{contracts::collect_base_args, diagnostics::Diagnostics, Recurse};
 use crate::{sema::ast, Target};
@@ -70,20 +70,35 @@ pub enum Instr {
         res: usize,
         ty: Type,
         storage: Expression,
+        storage_type: Option<StorageType>,
     },
     /// Clear storage at slot for ty (might span multiple slots)
-    ClearStorage { ty: Type, storage: Expression },
+    ClearStorage {
+        ty: Type,
+        storage: Expression,
+        storage_type: Option<StorageType>,
+    },
+    /// Load storage and clear the slot in the same operation
+    TakeStorage {
+        res: usize,
+        ty: Type,
+        storage: Expression,
+    },
+    /// Check whether there is a value stored at the slot
+    ContainsStorage { res: usize, storage: Expression },
     /// Set storage value at slot
     SetStorage {
         ty: Type,
         value: Expression,
         storage: Expression,
+        storage_type: Option<StorageType>,
     },
     /// In storage slot, set the value at the offset
     SetStorageBytes {
         value: Expression,
         storage: Expression,
         offset: Expression,
+        storage_type: Option<StorageType>,
     },
     /// Push an element onto an array in storage
     PushStorage {
@@ -91,12 +106,14 @@ pub enum Instr {
         ty: Type,
         value: Option<Expression>,
         storage: Expression,
+        storage_type: Option<StorageType>,
     },
     /// Pop an element from an array in storage
     PopStorage {
         res: Option<usize>,
         ty: Type,
         storage: Expression,
+        storage_type: Option<StorageType>,
     },
     /// Push element on memory array
     PushMemory {
@@ -123,6 +140,13 @@ pub enum Instr {
         encoded_args: Expression,
         value: Option<Expression>,
         gas: Expression,
+        /// Polkadot specific: the proof size limit
+        proof_size: Option<Expression>,
+        /// Polkadot specific: the storage deposit limit
+        storage_deposit_limit: Option<Expression>,
+        /// Polkadot specific: the code hash to instantiate, as a buffer of 32 bytes. If not set,
+        /// the code hash of the contract is used.
+        code_hash: Option<Expression>,
         salt: Option<Expression>,
         address: Option<Expression>,
         seeds: Option<Expression>,
@@ -140,6 +164,10 @@ pub enum Instr {
         payload: Expression,
         value: Expression,
         gas: Expression,
+        /// Polkadot specific: the proof size limit
+        proof_size: Option<Expression>,
+        /// Polkadot specific: the storage deposit limit
+        storage_deposit_limit: Option<Expression>,
         callty: CallTy,
         contract_function_no: Option<(usize, usize)>,
         flags: Option<Expression>,
@@ -223,6 +251,8 @@ impl Instr {
             Instr::BranchCond { cond: expr, .. }
             | Instr::LoadStorage { storage: expr, .. }
             | Instr::ClearStorage { storage: expr, .. }
+            | Instr::TakeStorage { storage: expr, .. }
+            | Instr::ContainsStorage { storage: expr, .. }
             | Instr::Print { expr }
             | Instr::AssertFailure {
                 encoded_args: Some(expr),
@@ -264,6 +294,7 @@ impl Instr {
                 value,
                 storage,
                 offset,
+                ..
             } => {
                 value.recurse(cx, f);
                 storage.recurse(cx, f);
@@ -280,6 +311,9 @@ impl Instr {
                 encoded_args,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
+                code_hash,
                 salt,
                 address,
                 accounts,
@@ -291,6 +325,18 @@ impl Instr {
                 }
                 gas.recurse(cx, f);
 
+                if let Some(expr) = proof_size {
+                    expr.recurse(cx, f);
+                }
+
+                if let Some(expr) = storage_deposit_limit {
+                    expr.recurse(cx, f);
+                }
+
+                if let Some(expr) = code_hash {
+                    expr.recurse(cx, f);
+                }
+
                 if let Some(expr) = salt {
                     expr.recurse(cx, f);
                 }
@@ -309,6 +355,8 @@ impl Instr {
                 payload,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
                 ..
             } => {
                 if let Some(expr) = address {
@@ -317,6 +365,12 @@ impl Instr {
                 payload.recurse(cx, f);
                 value.recurse(cx, f);
                 gas.recurse(cx, f);
+                if let Some(expr) = proof_size {
+                    expr.recurse(cx, f);
+                }
+                if let Some(expr) = storage_deposit_limit {
+                    expr.recurse(cx, f);
+                }
             }
 
             Instr::ValueTransfer { address, value, .. } => {
@@ -412,6 +466,8 @@ pub struct ControlFlowGraph {
     pub vars: Vars,
     pub blocks: Vec<BasicBlock>,
     pub nonpayable: bool,
+    /// Reject calls which re-enter the contract
+    pub nonreentrant: bool,
     pub public: bool,
     pub ty: pt::FunctionTy,
     pub selector: Vec<u8>,
@@ -486,6 +542,7 @@ impl ControlFlowGraph {
             vars: IndexMap::new(),
             blocks: Vec::new(),
             nonpayable: false,
+            nonreentrant: false,
             public: false,
             ty: pt::FunctionTy::Function,
             selector: Vec::new(),
@@ -509,6 +566,7 @@ impl ControlFlowGraph {
             vars: IndexMap::new(),
             blocks: Vec::new(),
             nonpayable: false,
+            nonreentrant: false,
             public: false,
             ty: pt::FunctionTy::Function,
             selector: Vec::new(),
@@ -1027,18 +1085,33 @@ impl ControlFlowGraph {
                 true_block,
                 false_block,
             ),
-            Instr::LoadStorage { ty, res, storage } => format!(
+            Instr::LoadStorage {
+                ty, res, storage, ..
+            } => format!(
                 "%{} = load storage slot({}) ty:{}",
                 self.vars[res].id.name,
                 self.expr_to_string(contract, ns, storage),
                 ty.to_string(ns),
             ),
-            Instr::ClearStorage { ty, storage } => format!(
+            Instr::ClearStorage { ty, storage, .. } => format!(
                 "clear storage slot({}) ty:{}",
                 self.expr_to_string(contract, ns, storage),
                 ty.to_string(ns),
             ),
-            Instr::SetStorage { ty, value, storage } => format!(
+            Instr::TakeStorage { ty, res, storage } => format!(
+                "%{} = take storage slot({}) ty:{}",
+                self.vars[res].id.name,
+                self.expr_to_string(contract, ns, storage),
+                ty.to_string(ns),
+            ),
+            Instr::ContainsStorage { res, storage } => format!(
+                "%{} = contains storage slot({})",
+                self.vars[res].id.name,
+                self.expr_to_string(contract, ns, storage),
+            ),
+            Instr::SetStorage {
+                ty, value, storage, ..
+            } => format!(
                 "store storage slot({}) ty:{} = {}",
                 self.expr_to_string(contract, ns, storage),
                 ty.to_string(ns),
@@ -1048,6 +1121,7 @@ impl ControlFlowGraph {
                 value,
                 storage,
                 offset,
+                ..
             } => format!(
                 "set storage slot({}) offset:{} = {}",
                 self.expr_to_string(contract, ns, storage),
@@ -1059,6 +1133,7 @@ impl ControlFlowGraph {
                 ty,
                 storage,
                 value,
+                ..
             } => {
                 format!(
                     "%{} = push storage ty:{} slot:{} = {}",
@@ -1076,6 +1151,7 @@ impl ControlFlowGraph {
                 res: Some(res),
                 ty,
                 storage,
+                ..
             } => {
                 format!(
                     "%{} = pop storage ty:{} slot({})",
@@ -1088,6 +1164,7 @@ impl ControlFlowGraph {
                 res: None,
                 ty,
                 storage,
+                ..
             } => {
                 format!(
                     "pop storage ty:{} slot({})",
@@ -1178,12 +1255,14 @@ impl ControlFlowGraph {
                 accounts,
                 seeds,
                 gas,
+                proof_size,
+                storage_deposit_limit,
                 callty,
                 contract_function_no,
                 flags, ..
             } => {
                 format!(
-                    "{} = external call::{} address:{} payload:{} value:{} gas:{} accounts:{} seeds:{} contract|function:{} flags:{}",
+                    "{} = external call::{} address:{} payload:{} value:{} gas:{} accounts:{} seeds:{} contract|function:{} flags:{} proof_size:{} storage_deposit_limit:{}",
                     match success {
                         Some(i) => format!("%{}", self.vars[i].id.name),
                         None => "_".to_string(),
@@ -1212,7 +1291,9 @@ impl ControlFlowGraph {
                     } else {
                         "_".to_string()
                     },
-                    flags.as_ref().map(|e| self.expr_to_string(contract, ns, e)).unwrap_or_default()
+                    flags.as_ref().map(|e| self.expr_to_string(contract, ns, e)).unwrap_or_default(),
+                    proof_size.as_ref().map(|e| self.expr_to_string(contract, ns, e)).unwrap_or_default(),
+                    storage_deposit_limit.as_ref().map(|e| self.expr_to_string(contract, ns, e)).unwrap_or_default()
                 )
             }
             Instr::ValueTransfer {
@@ -1242,6 +1323,9 @@ impl ControlFlowGraph {
                 contract_no,
                 encoded_args,
                 gas,
+                proof_size,
+                storage_deposit_limit,
+                code_hash,
                 salt,
                 value,
                 address,seeds,
@@ -1249,7 +1333,7 @@ impl ControlFlowGraph {
                 constructor_no,
                 loc:_
             } => format!(
-                "%{}, {} = constructor(no: {}) salt:{} value:{} gas:{} address:{} seeds:{} {} encoded buffer: {} accounts: {}",
+                "%{}, {} = constructor(no: {}) salt:{} value:{} gas:{} address:{} seeds:{} {} encoded buffer: {} accounts: {} proof_size:{} storage_deposit_limit:{} code_hash:{}",
                 self.vars[res].id.name,
                 match success {
                     Some(i) => format!("%{}", self.vars[i].id.name),
@@ -1284,7 +1368,10 @@ impl ControlFlowGraph {
                     self.expr_to_string(contract, ns, accounts)
                 } else {
                     String::new()
-                }
+                },
+                proof_size.as_ref().map(|e| self.expr_to_string(contract, ns, e)).unwrap_or_default(),
+                storage_deposit_limit.as_ref().map(|e| self.expr_to_string(contract, ns, e)).unwrap_or_default(),
+                code_hash.as_ref().map(|e| self.expr_to_string(contract, ns, e)).unwrap_or_default()
             ),
             Instr::SelfDestruct { recipient } => format!(
                 "selfdestruct {}",
@@ -1490,6 +1577,7 @@ pub fn generate_cfg(
             assert_eq!(func.ty, pt::FunctionTy::Function);
             let public = cfg.public;
             let nonpayable = cfg.nonpayable;
+            let nonreentrant = cfg.nonreentrant;
 
             cfg.public = false;
 
@@ -1511,6 +1599,7 @@ pub fn generate_cfg(
 
             cfg.public = public;
             cfg.nonpayable = nonpayable;
+            cfg.nonreentrant = nonreentrant;
             cfg.selector = ns.functions[func_no].selector(ns, &contract_no);
             cfg.modifier = Some(func_no);
         }
@@ -1646,6 +1735,7 @@ fn function_cfg(
     cfg.public = ns.function_externally_callable(contract_no, function_no);
     cfg.ty = func.ty;
     cfg.nonpayable = !func.is_payable();
+    cfg.nonreentrant = func.nonreentrant.is_some();
 
     // populate the argument variables
     populate_arguments(func, &mut cfg, &mut vartab);
@@ -2101,10 +2191,18 @@ impl Contract {
             .iter()
             .find(|l| l.contract_no == var_contract_no && l.var_no == var_no)
         {
+            let value = if ns.target == Target::Soroban {
+                // Soroban storage keys are host values. The key is a small U64Val, i.e.
+                // shifted left by 8 bits with the tag 6 in the low byte.
+                (layout.slot.clone() << 8u8) | BigInt::from(6u8)
+            } else {
+                layout.slot.clone()
+            };
+
             Expression::NumberLiteral {
                 loc,
                 ty: ty.unwrap_or_else(|| ns.storage_type()),
-                value: layout.slot.clone(),
+                value,
             }
         } else {
             panic!("get_storage_slot called on non-storage variable");
@@ -2152,10 +2250,10 @@ impl Namespace {
 
     /// Type storage
     pub fn storage_type(&self) -> Type {
-        if self.target == Target::Solana {
-            Type::Uint(32)
-        } else {
-            Type::Uint(256)
+        match self.target {
+            Target::Solana => Type::Uint(32),
+            Target::Soroban => Type::Uint(64),
+            _ => Type::Uint(256),
         }
     }
 
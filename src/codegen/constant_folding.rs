@@ -4,7 +4,7 @@ use super::cfg::{ControlFlowGraph, Instr};
 use super::reaching_definitions;
 use crate::codegen::{Builtin, Expression};
 use crate::sema::{
-    ast::{Diagnostic, Namespace, RetrieveType, StringLocation, Type},
+    ast::{Diagnostic, Namespace, RetrieveType, StorageType, StringLocation, Type},
     eval::overflow_diagnostic,
 };
 use num_bigint::{BigInt, Sign};
@@ -119,17 +119,27 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                         cfg.blocks[block_no].instr[instr_no] = Instr::Print { expr };
                     }
                 }
-                Instr::ClearStorage { ty, storage } => {
+                Instr::ClearStorage {
+                    ty,
+                    storage,
+                    storage_type,
+                } => {
                     let (storage, _) = expression(storage, Some(&vars), cfg, ns);
 
                     if !dry_run {
                         cfg.blocks[block_no].instr[instr_no] = Instr::ClearStorage {
                             ty: ty.clone(),
                             storage,
+                            storage_type: *storage_type,
                         };
                     }
                 }
-                Instr::SetStorage { ty, storage, value } => {
+                Instr::SetStorage {
+                    ty,
+                    storage,
+                    value,
+                    storage_type,
+                } => {
                     let (storage, _) = expression(storage, Some(&vars), cfg, ns);
                     let (value, _) = expression(value, Some(&vars), cfg, ns);
 
@@ -138,10 +148,16 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                             ty: ty.clone(),
                             storage,
                             value,
+                            storage_type: *storage_type,
                         };
                     }
                 }
-                Instr::LoadStorage { ty, storage, res } => {
+                Instr::LoadStorage {
+                    ty,
+                    storage,
+                    res,
+                    storage_type,
+                } => {
                     let (storage, _) = expression(storage, Some(&vars), cfg, ns);
 
                     if !dry_run {
@@ -149,13 +165,34 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                             ty: ty.clone(),
                             storage,
                             res: *res,
+                            storage_type: *storage_type,
                         };
                     }
                 }
+                Instr::TakeStorage { ty, storage, res } => {
+                    let (storage, _) = expression(storage, Some(&vars), cfg, ns);
+
+                    if !dry_run {
+                        cfg.blocks[block_no].instr[instr_no] = Instr::TakeStorage {
+                            ty: ty.clone(),
+                            storage,
+                            res: *res,
+                        };
+                    }
+                }
+                Instr::ContainsStorage { storage, res } => {
+                    let (storage, _) = expression(storage, Some(&vars), cfg, ns);
+
+                    if !dry_run {
+                        cfg.blocks[block_no].instr[instr_no] =
+                            Instr::ContainsStorage { storage, res: *res };
+                    }
+                }
                 Instr::SetStorageBytes {
                     storage,
                     value,
                     offset,
+                    storage_type,
                 } => {
                     let (storage, _) = expression(storage, Some(&vars), cfg, ns);
                     let (value, _) = expression(value, Some(&vars), cfg, ns);
@@ -166,6 +203,7 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                             storage,
                             value,
                             offset,
+                            storage_type: *storage_type,
                         };
                     }
                 }
@@ -174,6 +212,7 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                     ty,
                     storage,
                     value,
+                    storage_type,
                 } => {
                     let (storage, _) = expression(storage, Some(&vars), cfg, ns);
                     let value = value
@@ -186,10 +225,16 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                             ty: ty.clone(),
                             storage,
                             value,
+                            storage_type: *storage_type,
                         };
                     }
                 }
-                Instr::PopStorage { res, ty, storage } => {
+                Instr::PopStorage {
+                    res,
+                    ty,
+                    storage,
+                    storage_type,
+                } => {
                     let (storage, _) = expression(storage, Some(&vars), cfg, ns);
 
                     if !dry_run {
@@ -197,6 +242,7 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                             res: *res,
                             ty: ty.clone(),
                             storage,
+                            storage_type: *storage_type,
                         };
                     }
                 }
@@ -224,6 +270,9 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                     encoded_args,
                     value,
                     gas,
+                    proof_size,
+                    storage_deposit_limit,
+                    code_hash,
                     salt,
                     address,
                     seeds,
@@ -236,6 +285,15 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                         .as_ref()
                         .map(|expr| expression(expr, Some(&vars), cfg, ns).0);
                     let gas = expression(gas, Some(&vars), cfg, ns).0;
+                    let proof_size = proof_size
+                        .as_ref()
+                        .map(|expr| expression(expr, Some(&vars), cfg, ns).0);
+                    let storage_deposit_limit = storage_deposit_limit
+                        .as_ref()
+                        .map(|expr| expression(expr, Some(&vars), cfg, ns).0);
+                    let code_hash = code_hash
+                        .as_ref()
+                        .map(|expr| expression(expr, Some(&vars), cfg, ns).0);
                     let salt = salt
                         .as_ref()
                         .map(|expr| expression(expr, Some(&vars), cfg, ns).0);
@@ -256,6 +314,9 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                             encoded_args,
                             value,
                             gas,
+                            proof_size,
+                            storage_deposit_limit,
+                            code_hash,
                             salt,
                             address,
                             seeds,
@@ -271,6 +332,8 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                     payload,
                     value,
                     gas,
+                    proof_size,
+                    storage_deposit_limit,
                     accounts,
                     callty,
                     seeds,
@@ -279,6 +342,12 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                 } => {
                     let value = expression(value, Some(&vars), cfg, ns).0;
                     let gas = expression(gas, Some(&vars), cfg, ns).0;
+                    let proof_size = proof_size
+                        .as_ref()
+                        .map(|expr| expression(expr, Some(&vars), cfg, ns).0);
+                    let storage_deposit_limit = storage_deposit_limit
+                        .as_ref()
+                        .map(|expr| expression(expr, Some(&vars), cfg, ns).0);
                     let payload = expression(payload, Some(&vars), cfg, ns).0;
                     let address = address
                         .as_ref()
@@ -301,6 +370,8 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                             payload,
                             value,
                             gas,
+                            proof_size,
+                            storage_deposit_limit,
                             callty: callty.clone(),
                             contract_function_no: *contract_function_no,
                             flags,
@@ -600,7 +671,8 @@ fn expression(
             array_ty,
             expr,
             index,
-        } => subscript(loc, ty, array_ty, expr, index, vars, cfg, ns),
+            storage_type,
+        } => subscript(loc, ty, array_ty, expr, index, *storage_type, vars, cfg, ns),
         Expression::StructMember {
             loc,
             ty,
@@ -612,7 +684,8 @@ fn expression(
             ty,
             array,
             elem_ty,
-        } => storage_array_length(loc, ty, array, elem_ty, vars, cfg, ns),
+            storage_type,
+        } => storage_array_length(loc, ty, array, elem_ty, *storage_type, vars, cfg, ns),
         Expression::StringCompare { loc, left, right } => {
             string_compare(loc, left, right, vars, cfg, ns)
         }
@@ -1834,6 +1907,16 @@ fn not(
 ) -> (Expression, bool) {
     let expr = expression(expr, vars, cfg, ns);
 
+    if let Expression::BoolLiteral { value, .. } = expr.0 {
+        return (
+            Expression::BoolLiteral {
+                loc: *loc,
+                value: !value,
+            },
+            true,
+        );
+    }
+
     (
         Expression::Not {
             loc: *loc,
@@ -1849,6 +1932,7 @@ fn subscript(
     array_ty: &Type,
     array: &Expression,
     index: &Expression,
+    storage_type: Option<StorageType>,
     vars: Option<&reaching_definitions::VarDefs>,
     cfg: &ControlFlowGraph,
     ns: &mut Namespace,
@@ -1863,6 +1947,7 @@ fn subscript(
             array_ty: array_ty.clone(),
             expr: Box::new(array.0),
             index: Box::new(index.0),
+            storage_type,
         },
         false,
     )
@@ -1895,6 +1980,7 @@ fn storage_array_length(
     ty: &Type,
     array: &Expression,
     elem_ty: &Type,
+    storage_type: Option<StorageType>,
     vars: Option<&reaching_definitions::VarDefs>,
     cfg: &ControlFlowGraph,
     ns: &mut Namespace,
@@ -1907,6 +1993,7 @@ fn storage_array_length(
             ty: ty.clone(),
             array: Box::new(array.0),
             elem_ty: elem_ty.clone(),
+            storage_type,
         },
         false,
     )
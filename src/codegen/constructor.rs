@@ -13,6 +13,7 @@ use num_bigint::{BigInt, Sign};
 use solang_parser::pt::Loc;
 
 use super::encoding::abi_encode;
+use super::soroban;
 
 /// This function encodes the constructor arguments and place an instruction in the CFG to
 /// call the constructor of a contract.
@@ -41,6 +42,20 @@ pub(super) fn call_constructor(
     } else {
         default_gas(ns)
     };
+    let proof_size = call_args
+        .proof_size
+        .as_ref()
+        .map(|e| expression(e, cfg, callee_contract_no, func, ns, vartab, opt));
+    let storage_deposit_limit = call_args
+        .storage_deposit_limit
+        .as_ref()
+        .map(|e| expression(e, cfg, callee_contract_no, func, ns, vartab, opt));
+
+    // The code hash is passed to the host as a buffer
+    let code_hash = call_args.code_hash.as_ref().map(|e| {
+        let code_hash = expression(e, cfg, callee_contract_no, func, ns, vartab, opt);
+        abi_encode(loc, vec![code_hash], ns, vartab, cfg, true).0
+    });
 
     let salt = call_args
         .salt
@@ -81,14 +96,43 @@ pub(super) fn call_constructor(
         .map(|e| expression(e, cfg, callee_contract_no, func, ns, vartab, opt))
         .collect::<Vec<Expression>>();
 
+    if ns.target == Target::Soroban {
+        // The new contract is initialized by calling its exported `init` function
+        let encoded_args = soroban::call_payload(loc, "init", constructor_args, vartab, cfg);
+
+        cfg.add(
+            vartab,
+            Instr::Constructor {
+                success,
+                res: address_res,
+                contract_no,
+                constructor_no: *constructor_no,
+                encoded_args,
+                value,
+                gas,
+                proof_size,
+                storage_deposit_limit,
+                code_hash,
+                salt,
+                address,
+                seeds,
+                loc: *loc,
+                accounts,
+            },
+        );
+
+        return;
+    }
+
     let selector = match constructor_no {
         Some(func_no) => ns.functions[*func_no].selector(ns, &contract_no),
-        None => ns.contracts[contract_no]
-            .default_constructor
-            .as_ref()
-            .unwrap()
-            .0
-            .selector(ns, &contract_no),
+        // With a code hash, the contract may not have been generated yet
+        None => match &ns.contracts[contract_no].default_constructor {
+            Some((func, _)) => func.selector(ns, &contract_no),
+            None => ns
+                .default_constructor(contract_no)
+                .selector(ns, &contract_no),
+        },
     };
 
     let mut args = vec![Expression::BytesLiteral {
@@ -110,6 +154,9 @@ pub(super) fn call_constructor(
             encoded_args,
             value,
             gas,
+            proof_size,
+            storage_deposit_limit,
+            code_hash,
             salt,
             address,
             seeds,
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::cfg::{BasicBlock, ControlFlowGraph, Instr};
+use super::polkadot;
 use crate::codegen::Expression;
 use crate::sema::ast::{Namespace, RetrieveType, Type};
 use solang_parser::pt::Loc;
@@ -228,7 +229,7 @@ fn instr_transfers(block_no: usize, block: &BasicBlock) -> Vec<Vec<Transfer>> {
 
                 v
             }
-            Instr::LoadStorage { res, .. } => set_var(&[*res]),
+            Instr::LoadStorage { res, .. } | Instr::ContainsStorage { res, .. } => set_var(&[*res]),
             Instr::PushMemory { array, res, .. } => {
                 let mut v = set_var(&[*res]);
                 v.push(Transfer::Kill { var_no: *array });
@@ -287,7 +288,8 @@ fn instr_transfers(block_no: usize, block: &BasicBlock) -> Vec<Vec<Transfer>> {
                 storage,
                 ..
             }
-            | Instr::PushStorage { res, storage, .. } => {
+            | Instr::PushStorage { res, storage, .. }
+            | Instr::TakeStorage { res, storage, .. } => {
                 vec![
                     Transfer::Kill { var_no: *res },
                     Transfer::Gen { def, var_no: *res },
@@ -471,7 +473,7 @@ fn apply_transfers(
 }
 
 /// Eliminate dead storage load/store.
-pub fn dead_storage(cfg: &mut ControlFlowGraph, _ns: &mut Namespace) {
+pub fn dead_storage(cfg: &mut ControlFlowGraph, ns: &mut Namespace) {
     // first calculate reaching definitions. We use a special case reaching definitions, which we track
     let (blocktransfers, block_vars) = reaching_definitions(cfg);
 
@@ -488,7 +490,9 @@ pub fn dead_storage(cfg: &mut ControlFlowGraph, _ns: &mut Namespace) {
             let vars = &block_vars[&block_no][instr_no];
 
             match &cfg.blocks[block_no].instr[instr_no] {
-                Instr::LoadStorage { res, ty, storage } => {
+                Instr::LoadStorage {
+                    res, ty, storage, ..
+                } => {
                     // is there a definition which has the same storage expression
                     let mut found = None;
 
@@ -543,7 +547,10 @@ pub fn dead_storage(cfg: &mut ControlFlowGraph, _ns: &mut Namespace) {
                         }
                     }
                 }
-                Instr::PushStorage { storage, .. } | Instr::PopStorage { storage, .. } => {
+                Instr::PushStorage { storage, .. }
+                | Instr::PopStorage { storage, .. }
+                | Instr::TakeStorage { storage, .. }
+                | Instr::ContainsStorage { storage, .. } => {
                     for (def, expr) in &vars.stores {
                         let def_vars = get_vars_at(def, &block_vars);
 
@@ -599,6 +606,68 @@ pub fn dead_storage(cfg: &mut ControlFlowGraph, _ns: &mut Namespace) {
             }
         }
     }
+
+    if ns.target.is_polkadot() {
+        take_storage(cfg, &block_vars);
+    }
+}
+
+/// A value which is loaded from storage and then cleared before anything else touches storage
+/// can be read and cleared with a single `take_storage` call on Polkadot.
+fn take_storage(cfg: &mut ControlFlowGraph, block_vars: &BlockVars) {
+    for (block_no, vars) in block_vars {
+        for instr_no in 0..cfg.blocks[*block_no].instr.len() {
+            let Instr::ClearStorage { ty, storage, .. } = &cfg.blocks[*block_no].instr[instr_no]
+            else {
+                continue;
+            };
+
+            if !polkadot::is_storage_int(ty) {
+                continue;
+            }
+
+            let mut load_no = None;
+
+            for no in (0..instr_no).rev() {
+                match &cfg.blocks[*block_no].instr[no] {
+                    Instr::LoadStorage {
+                        ty: load_ty,
+                        storage: load_storage,
+                        ..
+                    } => {
+                        match expression_compare(
+                            storage,
+                            &vars[instr_no],
+                            load_storage,
+                            &vars[no],
+                            cfg,
+                            block_vars,
+                        ) {
+                            ExpressionCmp::Equal if load_ty == ty => {
+                                load_no = Some(no);
+                                break;
+                            }
+                            ExpressionCmp::NotEqual => (),
+                            _ => break,
+                        }
+                    }
+                    // these do not access storage
+                    Instr::Set { .. } | Instr::Nop => (),
+                    _ => break,
+                }
+            }
+
+            if let Some(load_no) = load_no {
+                if let Instr::LoadStorage {
+                    res, ty, storage, ..
+                } = cfg.blocks[*block_no].instr[load_no].clone()
+                {
+                    cfg.blocks[*block_no].instr[load_no] = Instr::TakeStorage { res, ty, storage };
+                    cfg.blocks[*block_no].instr[instr_no] = Instr::Nop;
+                }
+            }
+        }
+    }
 }
 
 struct StorageDef<'a> {
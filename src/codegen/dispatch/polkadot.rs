@@ -293,6 +293,7 @@ impl<'a> Dispatch<'a> {
         let case_bb = self.cfg.new_basic_block(format!("func_{func_no}_dispatch"));
         self.cfg.set_basic_block(case_bb);
         self.abort_if_value_transfer(func_no);
+        self.abort_if_reentrant(func_no);
 
         // Decode input data if necessary
         let cfg = &self.all_cfg[func_no];
@@ -421,6 +422,57 @@ impl<'a> Dispatch<'a> {
         self.cfg.set_basic_block(false_block);
     }
 
+    /// Insert a trap into the cfg, if the function `func_no` is `@nonReentrant` but the contract is
+    /// already on the call stack. The host keeps count, so no storage slot is needed for a lock.
+    fn abort_if_reentrant(&mut self, func_no: usize) {
+        if !self.all_cfg[func_no].nonreentrant {
+            return;
+        }
+
+        let true_block = self
+            .cfg
+            .new_basic_block(format!("func_{func_no}_reentered"));
+        let false_block = self
+            .cfg
+            .new_basic_block(format!("func_{func_no}_not_reentered"));
+        self.add(Instr::BranchCond {
+            cond: Expression::More {
+                loc: Codegen,
+                signed: false,
+                left: Expression::Builtin {
+                    loc: Codegen,
+                    tys: vec![Uint(32)],
+                    kind: Builtin::ReentranceCount,
+                    args: vec![],
+                }
+                .into(),
+                right: Expression::NumberLiteral {
+                    loc: Codegen,
+                    ty: Uint(32),
+                    value: 0.into(),
+                }
+                .into(),
+            },
+            true_block,
+            false_block,
+        });
+
+        self.cfg.set_basic_block(true_block);
+        let function_name = self.all_cfg[func_no].name.split("::").last().unwrap();
+        let function_type = self.all_cfg[func_no].ty;
+        log_runtime_error(
+            self.opt.log_runtime_errors,
+            &format!("runtime_error: reentrant call to {function_type} {function_name}"),
+            Codegen,
+            &mut self.cfg,
+            &mut self.vartab,
+            self.ns,
+        );
+        self.add(Instr::AssertFailure { encoded_args: None });
+
+        self.cfg.set_basic_block(false_block);
+    }
+
     /// Build calls to fallback or receive functions (if they are present in the contract).
     fn fallback_or_receive(&mut self) {
         let (fallback_cfg, receive_cfg) = self.all_cfg.iter().enumerate().fold(
@@ -465,6 +517,7 @@ impl<'a> Dispatch<'a> {
 
         self.cfg.set_basic_block(fallback_block);
         if let Some(cfg_no) = fallback_cfg {
+            self.abort_if_reentrant(cfg_no);
             self.add(Instr::Call {
                 res: vec![],
                 return_tys: vec![],
@@ -489,6 +542,7 @@ impl<'a> Dispatch<'a> {
 
         self.cfg.set_basic_block(receive_block);
         if let Some(cfg_no) = receive_cfg {
+            self.abort_if_reentrant(cfg_no);
             self.add(Instr::Call {
                 res: vec![],
                 return_tys: vec![],
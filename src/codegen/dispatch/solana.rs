@@ -6,7 +6,7 @@ use crate::codegen::{
     vartable::Vartable,
     Builtin, Expression, Options,
 };
-use crate::sema::ast::{Namespace, StructType, Type};
+use crate::sema::ast::{FormatArg, Namespace, StructType, Type};
 use num_bigint::{BigInt, Sign};
 use num_traits::Zero;
 use solang_parser::{pt, pt::Loc};
@@ -163,6 +163,7 @@ pub(crate) fn function_dispatch(
                 ns,
                 &mut vartab,
                 &mut cfg,
+                opt,
             )
         } else if func_cfg.ty == pt::FunctionTy::Constructor {
             add_constructor_dispatch_case(
@@ -277,6 +278,7 @@ fn add_function_dispatch_case(
     ns: &Namespace,
     vartab: &mut Vartable,
     cfg: &mut ControlFlowGraph,
+    opt: &Options,
 ) -> usize {
     let entry = cfg.new_basic_block(format!("function_cfg_{cfg_no}"));
     cfg.set_basic_block(entry);
@@ -332,6 +334,23 @@ fn add_function_dispatch_case(
         });
     }
 
+    let compute_units = if opt.log_compute_units {
+        let var_no = vartab.temp_name("compute_units", &Type::Uint(64));
+
+        cfg.add(
+            vartab,
+            Instr::Set {
+                loc: Loc::Codegen,
+                res: var_no,
+                expr: remaining_compute_units(),
+            },
+        );
+
+        Some(var_no)
+    } else {
+        None
+    };
+
     cfg.add(
         vartab,
         Instr::Call {
@@ -342,6 +361,10 @@ fn add_function_dispatch_case(
         },
     );
 
+    if let Some(var_no) = compute_units {
+        log_compute_units_consumed(&ns.functions[ast_func_no].signature, var_no, vartab, cfg);
+    }
+
     if !func_cfg.returns.is_empty() {
         let (data, data_len) = abi_encode(&Loc::Codegen, returns_expr, ns, vartab, cfg, false);
         let zext_len = Expression::ZeroExt {
@@ -474,6 +497,61 @@ fn add_constructor_dispatch_case(
     entry
 }
 
+fn remaining_compute_units() -> Expression {
+    Expression::Builtin {
+        loc: Loc::Codegen,
+        tys: vec![Type::Uint(64)],
+        kind: Builtin::RemainingComputeUnits,
+        args: vec![],
+    }
+}
+
+/// Print the compute units used since `start` was set to the remaining compute units
+fn log_compute_units_consumed(
+    signature: &str,
+    start: usize,
+    vartab: &mut Vartable,
+    cfg: &mut ControlFlowGraph,
+) {
+    let consumed = Expression::Subtract {
+        loc: Loc::Codegen,
+        ty: Type::Uint(64),
+        overflowing: true,
+        left: Expression::Variable {
+            loc: Loc::Codegen,
+            ty: Type::Uint(64),
+            var_no: start,
+        }
+        .into(),
+        right: remaining_compute_units().into(),
+    };
+
+    let string_literal = |value: String| {
+        (
+            FormatArg::StringLiteral,
+            Expression::BytesLiteral {
+                loc: Loc::Codegen,
+                ty: Type::Bytes(value.len() as u8),
+                value: value.into_bytes(),
+            },
+        )
+    };
+
+    cfg.add(
+        vartab,
+        Instr::Print {
+            expr: Expression::FormatString {
+                loc: Loc::Codegen,
+                args: vec![
+                    string_literal(format!("compute_units: {signature} consumed ")),
+                    (FormatArg::Default, consumed),
+                    string_literal(" units".into()),
+                ],
+            },
+        },
+    );
+}
+
 fn check_magic(magic_value: u32, cfg: &mut ControlFlowGraph, vartab: &mut Vartable) {
     // check for magic in data account, to see if data account is initialized
     let magic_ok = cfg.new_basic_block("magic_ok".into());
@@ -491,6 +569,7 @@ fn check_magic(magic_value: u32, cfg: &mut ControlFlowGraph, vartab: &mut Vartab
                 ty: Type::Uint(32),
                 value: 0.into(),
             },
+            storage_type: None,
         },
     );
 
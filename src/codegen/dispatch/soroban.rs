@@ -3,14 +3,15 @@
 use num_bigint::BigInt;
 use solang_parser::pt::{self};
 
-use crate::sema::ast;
 use crate::{
     codegen::{
         cfg::{ASTFunction, ControlFlowGraph, Instr, InternalCallTy},
+        expression::load_storage,
+        soroban,
         vartable::Vartable,
         Expression, Options,
     },
-    sema::ast::{Namespace, Type},
+    sema::ast::{Namespace, StorageType, Type},
 };
 
 pub fn function_dispatch(
@@ -30,6 +31,12 @@ pub fn function_dispatch(
             _ => continue,
         };
 
+        // The constructor is called from `init`
+        if function.is_constructor() {
+            cfg.public = false;
+            continue;
+        }
+
         let wrapper_name = {
             if cfg.public {
                 if function.mangled_name_contracts.contains(&contract_no) {
@@ -44,10 +51,15 @@ pub fn function_dispatch(
 
         let mut wrapper_cfg = ControlFlowGraph::new(wrapper_name.to_string(), ASTFunction::None);
 
+        // The wrapper keeps the Solidity types of the parameters and returns, so that they can
+        // be described in the contract spec. Every argument and return value is passed as a
+        // `Val`, which is converted to or from the Solidity type.
         wrapper_cfg.params = function.params.clone();
 
-        let param = ast::Parameter::new_default(Type::Uint(64));
-        wrapper_cfg.returns = vec![param].into();
+        // TODO: support multiple returns
+        if function.returns.len() == 1 {
+            wrapper_cfg.returns = function.returns.clone();
+        }
         wrapper_cfg.public = true;
 
         let mut vartab = Vartable::from_symbol_table(&function.symtable, ns.next_id);
@@ -79,60 +91,26 @@ pub fn function_dispatch(
                 .params
                 .iter()
                 .enumerate()
-                .map(|(i, p)| Expression::ShiftRight {
-                    loc: pt::Loc::Codegen,
-                    ty: Type::Uint(64),
-                    left: Expression::FunctionArg {
-                        loc: p.loc,
-                        ty: p.ty.clone(),
-                        arg_no: i,
-                    }
-                    .into(),
-                    right: Expression::NumberLiteral {
-                        loc: pt::Loc::Codegen,
-                        ty: Type::Uint(64),
-                        value: BigInt::from(8_u64),
-                    }
-                    .into(),
-
-                    signed: false,
+                .map(|(i, p)| {
+                    soroban::from_val(
+                        &p.loc,
+                        &p.ty,
+                        Expression::FunctionArg {
+                            loc: p.loc,
+                            ty: Type::Uint(64),
+                            arg_no: i,
+                        },
+                    )
                 })
                 .collect(),
         };
 
         wrapper_cfg.add(&mut vartab, placeholder);
 
-        // TODO: support multiple returns
         if value.len() == 1 {
-            // set the msb 8 bits of the return value to 6, the return value is 64 bits.
-            // FIXME: this assumes that the solidity function always returns one value.
-            let shifted = Expression::ShiftLeft {
-                loc: pt::Loc::Codegen,
-                ty: Type::Uint(64),
-                left: value[0].clone().into(),
-                right: Expression::NumberLiteral {
-                    loc: pt::Loc::Codegen,
-                    ty: Type::Uint(64),
-                    value: BigInt::from(8_u64),
-                }
-                .into(),
-            };
-
-            let tag = Expression::NumberLiteral {
-                loc: pt::Loc::Codegen,
-                ty: Type::Uint(64),
-                value: BigInt::from(6_u64),
-            };
-
-            let added = Expression::Add {
-                loc: pt::Loc::Codegen,
-                ty: Type::Uint(64),
-                overflowing: false,
-                left: shifted.into(),
-                right: tag.into(),
-            };
+            let val = soroban::to_val(&pt::Loc::Codegen, value.remove(0));
 
-            wrapper_cfg.add(&mut vartab, Instr::Return { value: vec![added] });
+            wrapper_cfg.add(&mut vartab, Instr::Return { value: vec![val] });
         } else {
             // Return 2 as numberliteral. 2 is the soroban Void type encoded.
             let two = Expression::NumberLiteral {
@@ -149,5 +127,121 @@ pub fn function_dispatch(
         wrapper_cfgs.push(wrapper_cfg);
     }
 
+    wrapper_cfgs.push(init_dispatch(contract_no, ns));
+
     wrapper_cfgs
 }
+
+/// Soroban contracts are initialized by calling the exported `init` function. This runs the
+/// storage initializers, followed by the constructor if the contract has one. The constructor
+/// arguments are passed as `Val`s. A contract can only be initialized once, so `init` fails
+/// if it has been called before.
+fn init_dispatch(contract_no: usize, ns: &mut Namespace) -> ControlFlowGraph {
+    let mut cfg = ControlFlowGraph::new("init".to_string(), ASTFunction::None);
+    let mut vartab = Vartable::new(ns.next_id);
+
+    cfg.public = true;
+
+    // The flag is kept in instance storage. Its key is a `Symbol`, so it cannot clash with
+    // the key of any state variable.
+    let initialized_key = soroban::symbol(&pt::Loc::Codegen, "init");
+
+    let initialized = load_storage(
+        &pt::Loc::Codegen,
+        &Type::Bool,
+        initialized_key.clone(),
+        Some(StorageType::Instance),
+        &mut cfg,
+        &mut vartab,
+    );
+
+    let already_initialized = cfg.new_basic_block("already_initialized".into());
+    let not_initialized = cfg.new_basic_block("not_initialized".into());
+
+    cfg.add(
+        &mut vartab,
+        Instr::BranchCond {
+            cond: initialized,
+            true_block: already_initialized,
+            false_block: not_initialized,
+        },
+    );
+
+    cfg.set_basic_block(already_initialized);
+    cfg.add(&mut vartab, Instr::AssertFailure { encoded_args: None });
+
+    cfg.set_basic_block(not_initialized);
+    cfg.add(
+        &mut vartab,
+        Instr::SetStorage {
+            ty: Type::Bool,
+            value: Expression::BoolLiteral {
+                loc: pt::Loc::Codegen,
+                value: true,
+            },
+            storage: initialized_key,
+            storage_type: Some(StorageType::Instance),
+        },
+    );
+
+    cfg.add(
+        &mut vartab,
+        Instr::Call {
+            res: vec![],
+            return_tys: vec![],
+            call: InternalCallTy::Static {
+                cfg_no: ns.contracts[contract_no].initializer.unwrap(),
+            },
+            args: vec![],
+        },
+    );
+
+    if let Some(func_no) = ns.contracts[contract_no].constructors(ns).first() {
+        let function = &ns.functions[*func_no];
+
+        cfg.params = function.params.clone();
+
+        cfg.add(
+            &mut vartab,
+            Instr::Call {
+                res: vec![],
+                return_tys: vec![],
+                call: InternalCallTy::Static {
+                    cfg_no: ns.contracts[contract_no].all_functions[func_no],
+                },
+                args: function
+                    .params
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| {
+                        soroban::from_val(
+                            &p.loc,
+                            &p.ty,
+                            Expression::FunctionArg {
+                                loc: p.loc,
+                                ty: Type::Uint(64),
+                                arg_no: i,
+                            },
+                        )
+                    })
+                    .collect(),
+            },
+        );
+    }
+
+    // Return 2, which is the Soroban Void type encoded
+    cfg.add(
+        &mut vartab,
+        Instr::Return {
+            value: vec![Expression::NumberLiteral {
+                loc: pt::Loc::Codegen,
+                ty: Type::Uint(64),
+                value: BigInt::from(2_u64),
+            }],
+        },
+    );
+
+    vartab.finalize(ns, &mut cfg);
+
+    cfg
+}
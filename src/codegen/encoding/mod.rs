@@ -1422,7 +1422,7 @@ pub(crate) trait AbiEncoding {
                 self.get_expr_size(arg_no, &loaded, ns, vartab, cfg)
             }
             Type::StorageRef(_, r) => {
-                let var = load_storage(&Codegen, r, expr.clone(), cfg, vartab);
+                let var = load_storage(&Codegen, r, expr.clone(), None, cfg, vartab);
                 let size = self.get_expr_size(arg_no, &var, ns, vartab, cfg);
                 self.storage_cache_insert(arg_no, var.clone());
                 size
@@ -1816,6 +1816,7 @@ fn index_array(
                 ty: Type::Uint(32),
                 var_no: indexes[dims.len() - i - 1],
             }),
+            storage_type: None,
         };
 
         // We should only load if the dimension is dynamic.
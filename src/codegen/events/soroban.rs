@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::codegen::cfg::{ControlFlowGraph, Instr};
+use crate::codegen::events::EventEmitter;
+use crate::codegen::expression::expression;
+use crate::codegen::soroban;
+use crate::codegen::vartable::Vartable;
+use crate::codegen::Options;
+use crate::sema::ast;
+use crate::sema::ast::{Function, Namespace};
+use solang_parser::pt::Loc;
+use tiny_keccak::{Hasher, Keccak};
+
+/// Implements [EventEmitter] to handle the emission of events on Soroban. Unless the event
+/// is anonymous, the first topic is the event name as a `Symbol`, followed by the indexed
+/// fields. The remaining fields are the event data: a single `Val` if there is only one,
+/// else a `Vec` of them.
+pub(super) struct SorobanEventEmitter<'a> {
+    pub(super) loc: Loc,
+    /// Arguments passed to the event
+    pub(super) args: &'a [ast::Expression],
+    pub(super) ns: &'a Namespace,
+    pub(super) event_no: usize,
+}
+
+impl EventEmitter for SorobanEventEmitter<'_> {
+    fn selector(&self, _emitting_contract_no: usize) -> Vec<u8> {
+        let mut hasher = Keccak::v256();
+        hasher.update(self.ns.events[self.event_no].signature.as_bytes());
+        let mut buf = [0; 32];
+        hasher.finalize(&mut buf);
+        buf.into()
+    }
+
+    fn emit(
+        &self,
+        contract_no: usize,
+        func: &Function,
+        cfg: &mut ControlFlowGraph,
+        vartab: &mut Vartable,
+        opt: &Options,
+    ) {
+        let event = &self.ns.events[self.event_no];
+        let mut topics = Vec::new();
+        let mut data = Vec::new();
+
+        if !event.anonymous {
+            topics.push(soroban::symbol(&self.loc, &event.id.name));
+        }
+
+        for (ast_exp, field) in self.args.iter().zip(event.fields.iter()) {
+            let value = expression(ast_exp, cfg, contract_no, Some(func), self.ns, vartab, opt);
+            let val = soroban::to_val(&self.loc, value);
+
+            if field.indexed {
+                topics.push(val);
+            } else {
+                data.push(val);
+            }
+        }
+
+        let data = soroban::vals_buffer(&self.loc, data, vartab, cfg);
+
+        cfg.add(
+            vartab,
+            Instr::EmitEvent {
+                event_no: self.event_no,
+                data,
+                topics,
+            },
+        );
+    }
+}
@@ -5,13 +5,14 @@ use super::revert::{
     assert_failure, expr_assert, log_runtime_error, require, PanicCode, SolidityError,
 };
 use super::storage::{
-    array_offset, array_pop, array_push, storage_slots_array_pop, storage_slots_array_push,
+    array_offset, array_pop, array_push, set_storage, storage_slots_array_pop,
+    storage_slots_array_push,
 };
 use super::{
     cfg::{ControlFlowGraph, Instr, InternalCallTy},
     vartable::Vartable,
 };
-use super::{polkadot, Options};
+use super::{polkadot, soroban, Options};
 use crate::codegen::array_boundary::handle_array_assign;
 use crate::codegen::constructor::call_constructor;
 use crate::codegen::events::new_event_emitter;
@@ -21,8 +22,8 @@ use crate::sema::ast::ExternalCallAccounts;
 use crate::sema::{
     ast,
     ast::{
-        ArrayLength, CallTy, FormatArg, Function, Namespace, RetrieveType, StringLocation,
-        StructType, Type,
+        ArrayLength, CallTy, FormatArg, Function, Namespace, RetrieveType, StorageType,
+        StringLocation, StructType, Type,
     },
     diagnostics::Diagnostics,
     eval::{eval_const_number, eval_const_rational, eval_constants_in_expression},
@@ -58,9 +59,15 @@ pub fn expression(
             ns.contracts[contract_no].get_storage_slot(*loc, *var_contract_no, *var_no, ns, None)
         }
         ast::Expression::StorageLoad { loc, ty, expr } => {
+            let storage_type = soroban::storage_type(expr, ns);
             let storage = expression(expr, cfg, contract_no, func, ns, vartab, opt);
 
-            load_storage(loc, ty, storage, cfg, vartab)
+            if *ty == Type::Bool && polkadot::zero_clears_mapping_entry(&storage.ty(), ty, ns) {
+                // the entry is only in storage if it is true
+                contains_storage(loc, storage, cfg, vartab)
+            } else {
+                load_storage(loc, ty, storage, storage_type, cfg, vartab)
+            }
         }
         ast::Expression::Add {
             loc,
@@ -243,16 +250,35 @@ pub fn expression(
             right: Box::new(expression(right, cfg, contract_no, func, ns, vartab, opt)),
             signed: *sign,
         },
-        ast::Expression::Equal { loc, left, right } => Expression::Equal {
-            loc: *loc,
-            left: Box::new(expression(left, cfg, contract_no, func, ns, vartab, opt)),
-            right: Box::new(expression(right, cfg, contract_no, func, ns, vartab, opt)),
-        },
-        ast::Expression::NotEqual { loc, left, right } => Expression::NotEqual {
-            loc: *loc,
-            left: Box::new(expression(left, cfg, contract_no, func, ns, vartab, opt)),
-            right: Box::new(expression(right, cfg, contract_no, func, ns, vartab, opt)),
-        },
+        ast::Expression::Equal { loc, left, right } => {
+            if let Some(exists) =
+                mapping_entry_exists(loc, left, right, cfg, contract_no, func, ns, vartab, opt)
+            {
+                return Expression::Not {
+                    loc: *loc,
+                    expr: Box::new(exists),
+                };
+            }
+
+            Expression::Equal {
+                loc: *loc,
+                left: Box::new(expression(left, cfg, contract_no, func, ns, vartab, opt)),
+                right: Box::new(expression(right, cfg, contract_no, func, ns, vartab, opt)),
+            }
+        }
+        ast::Expression::NotEqual { loc, left, right } => {
+            if let Some(exists) =
+                mapping_entry_exists(loc, left, right, cfg, contract_no, func, ns, vartab, opt)
+            {
+                return exists;
+            }
+
+            Expression::NotEqual {
+                loc: *loc,
+                left: Box::new(expression(left, cfg, contract_no, func, ns, vartab, opt)),
+                right: Box::new(expression(right, cfg, contract_no, func, ns, vartab, opt)),
+            }
+        }
         ast::Expression::More { loc, left, right } => {
             let l = expression(left, cfg, contract_no, func, ns, vartab, opt);
             let r = expression(right, cfg, contract_no, func, ns, vartab, opt);
@@ -512,6 +538,7 @@ pub fn expression(
             elem_ty,
         } => {
             let array_ty = array.ty().deref_into();
+            let storage_type = soroban::storage_type(array, ns);
             let array = expression(array, cfg, contract_no, func, ns, vartab, opt);
 
             match array_ty {
@@ -532,6 +559,7 @@ pub fn expression(
                     ty: ty.clone(),
                     array: Box::new(array),
                     elem_ty: elem_ty.clone(),
+                    storage_type,
                 },
                 Type::Array(_, dim) => match dim.last().unwrap() {
                     ArrayLength::Dynamic => {
@@ -541,9 +569,10 @@ pub fn expression(
                                 ty: ty.clone(),
                                 array: Box::new(array),
                                 elem_ty: elem_ty.clone(),
+                                storage_type,
                             }
                         } else {
-                            load_storage(loc, &ns.storage_type(), array, cfg, vartab)
+                            load_storage(loc, &ns.storage_type(), array, storage_type, cfg, vartab)
                         }
                     }
                     ArrayLength::Fixed(length) => {
@@ -960,6 +989,53 @@ pub fn expression(
 
             Expression::Poison
         }
+        ast::Expression::Builtin {
+            loc,
+            kind: ast::Builtin::LogComputeUnits,
+            ..
+        } => {
+            // The syscall returns nothing, so the call is assigned to a temporary which is never
+            // read. Otherwise it would be removed as an unused expression.
+            let res = vartab.temp_anonymous(&Type::Uint(64));
+
+            cfg.add(
+                vartab,
+                Instr::Set {
+                    loc: *loc,
+                    res,
+                    expr: Expression::Builtin {
+                        loc: *loc,
+                        tys: vec![Type::Uint(64)],
+                        kind: Builtin::LogComputeUnits,
+                        args: vec![],
+                    },
+                },
+            );
+
+            Expression::Poison
+        }
+        ast::Expression::Builtin {
+            loc,
+            kind: ast::Builtin::RentExempt,
+            args,
+            ..
+        } => {
+            let lamports = expression(&args[0], cfg, contract_no, func, ns, vartab, opt);
+            let space = expression(&args[1], cfg, contract_no, func, ns, vartab, opt);
+
+            Expression::MoreEqual {
+                loc: *loc,
+                signed: false,
+                left: lamports.into(),
+                right: Expression::Builtin {
+                    loc: *loc,
+                    tys: vec![Type::Uint(64)],
+                    kind: Builtin::RentMinimumBalance,
+                    args: vec![space],
+                }
+                .into(),
+            }
+        }
         ast::Expression::Builtin {
             kind: ast::Builtin::Require,
             args,
@@ -982,6 +1058,60 @@ pub fn expression(
             args,
             ..
         } => payable_transfer(args, cfg, contract_no, func, ns, vartab, loc, opt),
+        ast::Expression::Builtin {
+            loc,
+            kind: kind @ (ast::Builtin::RequireAuth | ast::Builtin::RequireAuthForArgs),
+            args,
+            ..
+        } => {
+            let args = args
+                .iter()
+                .map(|arg| expression(arg, cfg, contract_no, func, ns, vartab, opt))
+                .collect();
+
+            soroban::require_auth(
+                loc,
+                *kind == ast::Builtin::RequireAuthForArgs,
+                args,
+                vartab,
+                cfg,
+            )
+        }
+        ast::Expression::Builtin {
+            loc,
+            kind: ast::Builtin::ExtendTtl,
+            args,
+            ..
+        } => {
+            let storage_type = soroban::storage_type(&args[0], ns).unwrap();
+            let args = args
+                .iter()
+                .map(|arg| expression(arg, cfg, contract_no, func, ns, vartab, opt))
+                .collect();
+
+            soroban::extend_ttl(loc, storage_type, args, vartab, cfg)
+        }
+        ast::Expression::Builtin {
+            loc,
+            kind: kind @ (ast::Builtin::XcmExecute | ast::Builtin::XcmSend),
+            args,
+            ..
+        } => {
+            let args = args
+                .iter()
+                .map(|arg| expression(arg, cfg, contract_no, func, ns, vartab, opt))
+                .collect();
+
+            polkadot::xcm(
+                loc,
+                *kind == ast::Builtin::XcmSend,
+                args,
+                cfg,
+                ns,
+                opt,
+                vartab,
+            )
+        }
         ast::Expression::Builtin {
             loc,
             kind: ast::Builtin::AbiEncode,
@@ -1231,6 +1361,7 @@ fn post_incdec(
     opt: &Options,
 ) -> Expression {
     let res = vartab.temp_anonymous(ty);
+    let storage_type = soroban::storage_type(var, ns);
     let v = expression(var, cfg, contract_no, func, ns, vartab, opt);
     let v = match var.ty() {
         Type::Ref(ty) => Expression::Load {
@@ -1238,7 +1369,9 @@ fn post_incdec(
             ty: ty.as_ref().clone(),
             expr: Box::new(v),
         },
-        Type::StorageRef(_, ty) => load_storage(&var.loc(), ty.as_ref(), v, cfg, vartab),
+        Type::StorageRef(_, ty) => {
+            load_storage(&var.loc(), ty.as_ref(), v, storage_type, cfg, vartab)
+        }
         _ => v,
     };
     cfg.add(
@@ -1304,17 +1437,19 @@ fn post_incdec(
 
             match var.ty() {
                 Type::StorageRef(..) => {
-                    cfg.add(
-                        vartab,
-                        Instr::SetStorage {
-                            value: Expression::Variable {
-                                loc: *loc,
-                                ty: ty.clone(),
-                                var_no: res,
-                            },
+                    set_storage(
+                        loc,
+                        ty,
+                        Expression::Variable {
+                            loc: *loc,
                             ty: ty.clone(),
-                            storage: dest,
+                            var_no: res,
                         },
+                        dest,
+                        storage_type,
+                        cfg,
+                        vartab,
+                        ns,
                     );
                 }
                 Type::Ref(_) => {
@@ -1355,6 +1490,7 @@ fn pre_incdec(
     opt: &Options,
 ) -> Expression {
     let res = vartab.temp_anonymous(ty);
+    let storage_type = soroban::storage_type(var, ns);
     let v = expression(var, cfg, contract_no, func, ns, vartab, opt);
     let v = match var.ty() {
         Type::Ref(ty) => Expression::Load {
@@ -1362,7 +1498,9 @@ fn pre_incdec(
             ty: ty.as_ref().clone(),
             expr: Box::new(v),
         },
-        Type::StorageRef(_, ty) => load_storage(&var.loc(), ty.as_ref(), v, cfg, vartab),
+        Type::StorageRef(_, ty) => {
+            load_storage(&var.loc(), ty.as_ref(), v, storage_type, cfg, vartab)
+        }
         _ => v,
     };
     let one = Box::new(Expression::NumberLiteral {
@@ -1415,17 +1553,19 @@ fn pre_incdec(
 
             match var.ty() {
                 Type::StorageRef(..) => {
-                    cfg.add(
-                        vartab,
-                        Instr::SetStorage {
-                            value: Expression::Variable {
-                                loc: *loc,
-                                ty: ty.clone(),
-                                var_no: res,
-                            },
+                    set_storage(
+                        loc,
+                        ty,
+                        Expression::Variable {
+                            loc: *loc,
                             ty: ty.clone(),
-                            storage: dest,
+                            var_no: res,
                         },
+                        dest,
+                        storage_type,
+                        cfg,
+                        vartab,
+                        ns,
                     );
                 }
                 Type::Ref(_) => {
@@ -1634,6 +1774,8 @@ fn payable_send(
                     ty: Type::Uint(64),
                     value: BigInt::from(i64::MAX),
                 },
+                proof_size: None,
+                storage_deposit_limit: None,
                 callty: CallTy::Regular,
                 contract_function_no: None,
                 flags: None,
@@ -1700,6 +1842,8 @@ fn payable_transfer(
                     ty: Type::Uint(64),
                     value: BigInt::from(i64::MAX),
                 },
+                proof_size: None,
+                storage_deposit_limit: None,
                 callty: CallTy::Regular,
                 contract_function_no: None,
                 flags: None,
@@ -2206,7 +2350,8 @@ fn expr_builtin(
                 expr: Box::new(codegen_expr),
             }
         }
-        ast::Builtin::ECRecover => {
+        // On Polkadot and Soroban, this is done by host functions
+        ast::Builtin::ECRecover if ns.target == Target::EVM => {
             // TODO:
             // EVM: call precompile 1 (code below is untested)
             // let args = args
@@ -2679,7 +2824,10 @@ pub fn assign_single(
             match left_ty {
                 Type::StorageRef(..) if set_storage_bytes => {
                     if let Expression::Subscript {
-                        expr: array, index, ..
+                        expr: array,
+                        index,
+                        storage_type,
+                        ..
                     } = dest
                     {
                         // Set a byte in a byte array
@@ -2693,6 +2841,7 @@ pub fn assign_single(
                                 },
                                 storage: *array,
                                 offset: *index,
+                                storage_type,
                             },
                         );
                     } else {
@@ -2700,17 +2849,19 @@ pub fn assign_single(
                     }
                 }
                 Type::StorageRef(..) => {
-                    cfg.add(
-                        vartab,
-                        Instr::SetStorage {
-                            value: Expression::Variable {
-                                loc: left.loc(),
-                                ty: ty.clone(),
-                                var_no: pos,
-                            },
-                            ty: ty.deref_any().clone(),
-                            storage: dest,
+                    set_storage(
+                        &left.loc(),
+                        ty.deref_any(),
+                        Expression::Variable {
+                            loc: left.loc(),
+                            ty: ty.clone(),
+                            var_no: pos,
                         },
+                        dest,
+                        soroban::storage_type(left, ns),
+                        cfg,
+                        vartab,
+                        ns,
                     );
                 }
                 Type::Ref(_) => {
@@ -2772,6 +2923,21 @@ pub fn emit_function_call(
 
                 let ftype = &ns.functions[function_no];
 
+                if ftype
+                    .contract_no
+                    .is_some_and(|contract_no| ns.contracts[contract_no].chain_extension.is_some())
+                {
+                    return polkadot::chain_extension(
+                        &expr.loc(),
+                        function_no,
+                        args,
+                        cfg,
+                        ns,
+                        opt,
+                        vartab,
+                    );
+                }
+
                 let call = if ns.functions[function_no].loc_prototype == pt::Loc::Builtin {
                     InternalCallTy::Builtin {
                         ast_func_no: function_no,
@@ -2921,6 +3087,14 @@ pub fn emit_function_call(
                 .flags
                 .as_ref()
                 .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
+            let proof_size = call_args
+                .proof_size
+                .as_ref()
+                .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
+            let storage_deposit_limit = call_args
+                .storage_deposit_limit
+                .as_ref()
+                .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
 
             cfg.add(
                 vartab,
@@ -2933,6 +3107,8 @@ pub fn emit_function_call(
                     accounts,
                     seeds,
                     gas,
+                    proof_size,
+                    storage_deposit_limit,
                     callty: ty.clone(),
                     contract_function_no: None,
                     flags,
@@ -3012,6 +3188,37 @@ pub fn emit_function_call(
                     }
                 };
 
+                if ns.target == Target::Soroban {
+                    let payload = soroban::call_payload(loc, &dest_func.id.name, args, vartab, cfg);
+
+                    cfg.add(
+                        vartab,
+                        Instr::ExternalCall {
+                            loc: *loc,
+                            success: None,
+                            accounts,
+                            address: Some(address),
+                            payload,
+                            seeds,
+                            value,
+                            gas,
+                            proof_size: None,
+                            storage_deposit_limit: None,
+                            callty: CallTy::Regular,
+                            contract_function_no,
+                            flags: None,
+                        },
+                    );
+
+                    // Soroban functions return a single Val
+                    return match dest_func.returns.as_slice() {
+                        [ret] if returns[0] != Type::Void => {
+                            vec![soroban::return_value(loc, &ret.ty)]
+                        }
+                        _ => vec![Expression::Poison],
+                    };
+                }
+
                 let selector = dest_func.selector(ns, &caller_contract_no);
 
                 tys.insert(0, Type::Bytes(selector.len() as u8));
@@ -3031,6 +3238,14 @@ pub fn emit_function_call(
                     .flags
                     .as_ref()
                     .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
+                let proof_size = call_args
+                    .proof_size
+                    .as_ref()
+                    .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
+                let storage_deposit_limit = call_args
+                    .storage_deposit_limit
+                    .as_ref()
+                    .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
 
                 let success = ns
                     .target
@@ -3047,6 +3262,8 @@ pub fn emit_function_call(
                         seeds,
                         value,
                         gas,
+                        proof_size,
+                        storage_deposit_limit,
                         callty: CallTy::Regular,
                         contract_function_no,
                         flags,
@@ -3119,6 +3336,14 @@ pub fn emit_function_call(
                     .flags
                     .as_ref()
                     .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
+                let proof_size = call_args
+                    .proof_size
+                    .as_ref()
+                    .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
+                let storage_deposit_limit = call_args
+                    .storage_deposit_limit
+                    .as_ref()
+                    .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
                 let success = ns
                     .target
                     .is_polkadot()
@@ -3134,6 +3359,8 @@ pub fn emit_function_call(
                         payload,
                         value,
                         gas,
+                        proof_size,
+                        storage_deposit_limit,
                         callty: CallTy::Regular,
                         contract_function_no: None,
                         flags,
@@ -3213,6 +3440,7 @@ fn array_subscript(
             array_ty: array_ty.clone(),
             expr: Box::new(expression(array, cfg, contract_no, func, ns, vartab, opt)),
             index: Box::new(expression(index, cfg, contract_no, func, ns, vartab, opt)),
+            storage_type: soroban::storage_type(array, ns),
         };
     }
 
@@ -3220,14 +3448,17 @@ fn array_subscript(
         let array = expression(array, cfg, contract_no, func, ns, vartab, opt);
         let index = expression(index, cfg, contract_no, func, ns, vartab, opt);
 
-        return if ns.target == Target::Solana {
+        return if matches!(ns.target, Target::Solana | Target::Soroban) {
             Expression::Subscript {
                 loc: *loc,
                 ty: elem_ty.clone(),
                 array_ty: array_ty.clone(),
                 expr: Box::new(array),
                 index: Box::new(index),
+                storage_type: None,
             }
+        } else if ns.target.ink_storage_keys() {
+            polkadot::ink_storage_key(loc, array_ty, array, index, cfg, vartab, ns)
         } else {
             Expression::Keccak256 {
                 loc: *loc,
@@ -3237,6 +3468,7 @@ fn array_subscript(
         };
     }
 
+    let storage_type = soroban::storage_type(array, ns);
     let mut array = expression(array, cfg, contract_no, func, ns, vartab, opt);
     let index_ty = index.ty();
     let index = expression(index, cfg, contract_no, func, ns, vartab, opt);
@@ -3266,10 +3498,21 @@ fn array_subscript(
                             ty: ns.storage_type(),
                             array: Box::new(array.clone()),
                             elem_ty: array_ty.storage_array_elem().deref_into(),
+                            storage_type: None,
                         }
+                    } else if ns.target == Target::Soroban {
+                        // the elements have their own keys, see the Soroban branch below
+                        load_storage(
+                            loc,
+                            &ns.storage_type(),
+                            array.clone(),
+                            storage_type,
+                            cfg,
+                            vartab,
+                        )
                     } else {
                         let array_length =
-                            load_storage(loc, &Type::Uint(256), array.clone(), cfg, vartab);
+                            load_storage(loc, &Type::Uint(256), array.clone(), None, cfg, vartab);
 
                         array = Expression::Keccak256 {
                             loc: *loc,
@@ -3456,7 +3699,23 @@ fn array_subscript(
         let elem_ty = ty.storage_array_elem();
         let slot_ty = ns.storage_type();
 
-        if ns.target == Target::Solana {
+        if ns.target == Target::Soroban {
+            let index = Expression::Variable {
+                loc: index_loc,
+                ty: coerced_ty,
+                var_no: pos,
+            }
+            .cast(&slot_ty, ns);
+
+            Expression::Subscript {
+                loc: *loc,
+                ty: elem_ty,
+                array_ty: array_ty.clone(),
+                expr: Box::new(array),
+                index: Box::new(index),
+                storage_type: None,
+            }
+        } else if ns.target == Target::Solana {
             if ty.array_length().is_some() && ty.is_sparse_solana(ns) {
                 let index = Expression::Variable {
                     loc: index_loc,
@@ -3471,6 +3730,7 @@ fn array_subscript(
                     array_ty: array_ty.clone(),
                     expr: Box::new(array),
                     index: Box::new(index),
+                    storage_type: None,
                 }
             } else {
                 let index = Expression::Variable {
@@ -3508,6 +3768,7 @@ fn array_subscript(
                         array_ty: array_ty.clone(),
                         expr: Box::new(array),
                         index: Box::new(index),
+                        storage_type: None,
                     }
                 }
             }
@@ -3577,6 +3838,7 @@ fn array_subscript(
                     ty: coerced_ty,
                     var_no: pos,
                 }),
+                storage_type: None,
             },
             _ => {
                 // should not happen as type-checking already done
@@ -3614,6 +3876,7 @@ pub fn load_storage(
     loc: &pt::Loc,
     ty: &Type,
     storage: Expression,
+    storage_type: Option<StorageType>,
     cfg: &mut ControlFlowGraph,
     vartab: &mut Vartable,
 ) -> Expression {
@@ -3624,6 +3887,7 @@ pub fn load_storage(
             res,
             ty: ty.clone(),
             storage,
+            storage_type,
         },
     );
 
@@ -3634,6 +3898,66 @@ pub fn load_storage(
     }
 }
 
+// Generate an instruction which checks whether there is a value in a storage slot
+fn contains_storage(
+    loc: &pt::Loc,
+    storage: Expression,
+    cfg: &mut ControlFlowGraph,
+    vartab: &mut Vartable,
+) -> Expression {
+    let res = vartab.temp_anonymous(&Type::Bool);
+    cfg.add(vartab, Instr::ContainsStorage { res, storage });
+
+    Expression::Variable {
+        loc: *loc,
+        ty: Type::Bool,
+        var_no: res,
+    }
+}
+
+/// If one side of a comparison is a mapping entry and the other side is zero, then on Polkadot
+/// the value of the entry is not needed, only whether the entry is in storage. See
+/// [`polkadot::zero_clears_mapping_entry`].
+fn mapping_entry_exists(
+    loc: &pt::Loc,
+    left: &ast::Expression,
+    right: &ast::Expression,
+    cfg: &mut ControlFlowGraph,
+    contract_no: usize,
+    func: Option<&Function>,
+    ns: &Namespace,
+    vartab: &mut Vartable,
+    opt: &Options,
+) -> Option<Expression> {
+    let is_zero = |expr: &ast::Expression| {
+        let evaluated = eval_constants_in_expression(expr, &mut Diagnostics::default());
+
+        match evaluated.0.as_ref().unwrap_or(expr) {
+            ast::Expression::NumberLiteral { value, .. } => value.is_zero(),
+            ast::Expression::BoolLiteral { value, .. } => !value,
+            ast::Expression::BytesLiteral { value, .. } => value.iter().all(|b| *b == 0),
+            _ => false,
+        }
+    };
+
+    match (left, right) {
+        (ast::Expression::StorageLoad { ty, expr, .. }, other)
+        | (other, ast::Expression::StorageLoad { ty, expr, .. })
+            if is_zero(other)
+                && matches!(
+                    expr.as_ref(),
+                    ast::Expression::Subscript { array_ty, .. }
+                        if polkadot::zero_clears_mapping_entry(array_ty, ty, ns)
+                ) =>
+        {
+            let storage = expression(expr, cfg, contract_no, func, ns, vartab, opt);
+
+            Some(contains_storage(loc, storage, cfg, vartab))
+        }
+        _ => None,
+    }
+}
+
 fn array_literal_to_memory_array(
     loc: &pt::Loc,
     expr: &Expression,
@@ -3688,6 +4012,7 @@ fn array_literal_to_memory_array(
                         ty: Type::Uint(32),
                         value: BigInt::from(item_no),
                     }),
+                    storage_type: None,
                 },
                 data: item.clone(),
             },
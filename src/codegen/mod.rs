@@ -14,6 +14,7 @@ mod reaching_definitions;
 pub mod revert;
 mod solana_accounts;
 mod solana_deploy;
+pub(super) mod soroban;
 mod statements;
 mod storage;
 mod strength_reduce;
@@ -33,7 +34,7 @@ use self::{
     vartable::Vartable,
 };
 use crate::sema::ast::{
-    FormatArg, Function, Layout, Namespace, RetrieveType, StringLocation, Type,
+    FormatArg, Function, Layout, Namespace, RetrieveType, StorageType, StringLocation, Type,
 };
 use crate::{sema::ast, Target};
 use std::cmp::Ordering;
@@ -105,9 +106,12 @@ pub struct Options {
     pub opt_level: OptimizationLevel,
     pub log_runtime_errors: bool,
     pub log_prints: bool,
+    /// Print the compute units consumed by each external function on Solana
+    pub log_compute_units: bool,
     #[cfg(feature = "wasm_opt")]
     pub wasm_opt: Option<OptimizationPasses>,
     pub soroban_version: Option<u64>,
+    pub soroban_native_token: Option<[u8; 32]>,
 }
 
 impl Default for Options {
@@ -122,9 +126,11 @@ impl Default for Options {
             opt_level: OptimizationLevel::Default,
             log_runtime_errors: false,
             log_prints: true,
+            log_compute_units: false,
             #[cfg(feature = "wasm_opt")]
             wasm_opt: None,
             soroban_version: None,
+            soroban_native_token: None,
         }
     }
 }
@@ -284,6 +290,11 @@ fn storage_initializer(contract_no: usize, ns: &mut Namespace, opt: &Options) ->
                     value,
                     ty: var.ty.clone(),
                     storage,
+                    storage_type: soroban::variable_storage_type(
+                        layout.contract_no,
+                        layout.var_no,
+                        ns,
+                    ),
                 },
             );
         }
@@ -600,6 +611,7 @@ pub enum Expression {
         ty: Type,
         array: Box<Expression>,
         elem_ty: Type,
+        storage_type: Option<StorageType>,
     },
     StringCompare {
         loc: pt::Loc,
@@ -623,6 +635,7 @@ pub enum Expression {
         array_ty: Type,
         expr: Box<Expression>,
         index: Box<Expression>,
+        storage_type: Option<StorageType>,
     },
     Subtract {
         loc: pt::Loc,
@@ -1588,12 +1601,14 @@ impl Expression {
                     array_ty,
                     expr,
                     index,
+                    storage_type,
                 } => Expression::Subscript {
                     loc: *loc,
                     ty: elem_ty.clone(),
                     array_ty: array_ty.clone(),
                     expr: Box::new(filter(expr, ctx)),
                     index: Box::new(filter(index, ctx)),
+                    storage_type: *storage_type,
                 },
                 Expression::StructMember {
                     loc,
@@ -1622,11 +1637,13 @@ impl Expression {
                     ty,
                     array,
                     elem_ty,
+                    storage_type,
                 } => Expression::StorageArrayLength {
                     loc: *loc,
                     ty: ty.clone(),
                     array: Box::new(filter(array, ctx)),
                     elem_ty: elem_ty.clone(),
+                    storage_type: *storage_type,
                 },
                 Expression::StringCompare { loc, left, right } => Expression::StringCompare {
                     loc: *loc,
@@ -1723,6 +1740,7 @@ pub enum Builtin {
     Calldata,
     ChainId,
     ContractCode,
+    ECRecover,
     Gasleft,
     GasLimit,
     Gasprice,
@@ -1760,6 +1778,40 @@ pub enum Builtin {
     WriteUint256LE,
     WriteBytes,
     Concat,
+    /// Convert a value into a Soroban `Val`
+    ToVal,
+    /// Convert a Soroban `Val` into a value of the type in `tys`
+    FromVal,
+    /// Create a Soroban `Symbol` from a string literal
+    Symbol,
+    /// Require the authorization of a Soroban address for the current invocation
+    RequireAuth,
+    /// Require the authorization of a Soroban address for the `Val`s in a buffer
+    RequireAuthForArgs,
+    /// Extend the TTL of the contract data with the given storage key and storage type
+    ExtendTtl,
+    /// Extend the TTL of the contract instance and code, which instance storage shares
+    ExtendInstanceTtl,
+    /// The number of times the contract is on the call stack, other than the current call
+    ReentranceCount,
+    /// Execute an XCM message, returning the return code of the host
+    XcmExecute,
+    /// Send an XCM message, returning the return code of the host. The message hash is written
+    /// to the buffer in the last argument
+    XcmSend,
+    /// The slot of a mapping entry with ink! storage keys. Rather than 256 bits, it is pointer sized,
+    /// and points to the encoded storage key in the argument
+    StorageKey,
+    /// The compute units left in the current Solana transaction
+    RemainingComputeUnits,
+    /// Log the compute units left in the current Solana transaction
+    LogComputeUnits,
+    /// The current epoch, from the Solana clock sysvar
+    Epoch,
+    /// The number of slots per epoch, from the Solana epoch schedule sysvar
+    SlotsPerEpoch,
+    /// The lamports needed for an account to be rent exempt on Solana, for the data size in the argument
+    RentMinimumBalance,
 }
 
 impl From<&ast::Builtin> for Builtin {
@@ -1777,6 +1829,7 @@ impl From<&ast::Builtin> for Builtin {
             ast::Builtin::BlockNumber => Builtin::BlockNumber,
             ast::Builtin::Calldata => Builtin::Calldata,
             ast::Builtin::Gasleft => Builtin::Gasleft,
+            ast::Builtin::RemainingComputeUnits => Builtin::RemainingComputeUnits,
             ast::Builtin::GasLimit => Builtin::GasLimit,
             ast::Builtin::Gasprice => Builtin::Gasprice,
             ast::Builtin::GetAddress => Builtin::GetAddress,
@@ -1799,6 +1852,9 @@ impl From<&ast::Builtin> for Builtin {
             ast::Builtin::Ripemd160 => Builtin::Ripemd160,
             ast::Builtin::Sender => Builtin::Sender,
             ast::Builtin::Slot => Builtin::Slot,
+            ast::Builtin::Epoch => Builtin::Epoch,
+            ast::Builtin::SlotsPerEpoch => Builtin::SlotsPerEpoch,
+            ast::Builtin::RentMinimumBalance => Builtin::RentMinimumBalance,
             ast::Builtin::Sha256 => Builtin::Sha256,
             ast::Builtin::Signature => Builtin::Signature,
             ast::Builtin::SignatureVerify => Builtin::SignatureVerify,
@@ -1821,6 +1877,7 @@ impl From<&ast::Builtin> for Builtin {
             ast::Builtin::BaseFee => Builtin::BaseFee,
             ast::Builtin::PrevRandao => Builtin::PrevRandao,
             ast::Builtin::ContractCode => Builtin::ContractCode,
+            ast::Builtin::ECRecover => Builtin::ECRecover,
             ast::Builtin::StringConcat | ast::Builtin::BytesConcat => Builtin::Concat,
             _ => panic!("Builtin should not be in the cfg"),
         }
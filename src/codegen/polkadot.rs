@@ -6,12 +6,13 @@ use solang_parser::pt::Loc;
 
 use crate::{
     codegen::{
-        cfg::{ControlFlowGraph, Instr},
+        cfg::{ControlFlowGraph, Instr, InternalCallTy},
+        encoding::{abi_decode, abi_encode},
         revert::log_runtime_error,
         vartable::Vartable,
-        Expression, Options,
+        Builtin, Expression, Options,
     },
-    sema::ast::{Namespace, Type},
+    sema::ast::{Mapping, Namespace, Type},
 };
 
 // When using the seal api, we use our own scratch buffer.
@@ -174,3 +175,242 @@ pub(super) fn check_transfer_ret(
 
     None
 }
+
+/// Execute an XCM message with `xcm_execute`, or send it with `xcm_send` if `send` is set. The
+/// contract reverts if the host rejects the message. Sending returns the hash of the message.
+pub(super) fn xcm(
+    loc: &Loc,
+    send: bool,
+    mut args: Vec<Expression>,
+    cfg: &mut ControlFlowGraph,
+    ns: &Namespace,
+    opt: &Options,
+    vartab: &mut Vartable,
+) -> Expression {
+    let hash = send.then(|| {
+        let hash = vartab.temp_name("xcm_hash", &Type::DynamicBytes);
+        cfg.add(
+            vartab,
+            Instr::Set {
+                loc: *loc,
+                res: hash,
+                expr: Expression::AllocDynamicBytes {
+                    loc: *loc,
+                    ty: Type::DynamicBytes,
+                    size: Expression::NumberLiteral {
+                        loc: *loc,
+                        ty: Type::Uint(32),
+                        value: 32.into(),
+                    }
+                    .into(),
+                    initializer: None,
+                },
+            },
+        );
+        Expression::Variable {
+            loc: *loc,
+            ty: Type::DynamicBytes,
+            var_no: hash,
+        }
+    });
+
+    let (kind, msg) = if let Some(hash) = &hash {
+        args.push(hash.clone());
+        (Builtin::XcmSend, "xcm send failed")
+    } else {
+        (Builtin::XcmExecute, "xcm execute failed")
+    };
+
+    let ret_code = vartab.temp_name("xcm_ret", &Type::Uint(32));
+    cfg.add(
+        vartab,
+        Instr::Set {
+            loc: *loc,
+            res: ret_code,
+            expr: Expression::Builtin {
+                loc: *loc,
+                tys: vec![Type::Uint(32)],
+                kind,
+                args,
+            },
+        },
+    );
+
+    RetCodeCheckBuilder::default()
+        .loc(*loc)
+        .msg(msg)
+        .success_var(ret_code)
+        .insert(cfg, vartab)
+        .handle_cases(cfg, ns, opt, vartab);
+
+    match hash {
+        Some(hash) => Expression::Builtin {
+            loc: *loc,
+            tys: vec![Type::Bytes(32)],
+            kind: Builtin::ReadFromBuffer,
+            args: vec![
+                hash,
+                Expression::NumberLiteral {
+                    loc: *loc,
+                    ty: Type::Uint(32),
+                    value: 0.into(),
+                },
+            ],
+        },
+        None => Expression::Poison,
+    }
+}
+
+/// Call a function of a chain extension interface. The arguments are SCALE encoded and passed to
+/// `call_chain_extension`, with the extension id in the upper and the function id in the lower 16
+/// bits of the id. The contract reverts if the chain extension returns a non-zero status code;
+/// otherwise the output is decoded as the return values of the function.
+pub(super) fn chain_extension(
+    loc: &Loc,
+    function_no: usize,
+    args: Vec<Expression>,
+    cfg: &mut ControlFlowGraph,
+    ns: &Namespace,
+    opt: &Options,
+    vartab: &mut Vartable,
+) -> Vec<Expression> {
+    let func = &ns.functions[function_no];
+    let extension = func
+        .contract_no
+        .and_then(|contract_no| ns.contracts[contract_no].chain_extension)
+        .unwrap();
+    let (_, function_id) = func.chain_extension_id.unwrap();
+    let id = (u32::from(extension) << 16) | u32::from(function_id);
+
+    let input = if args.is_empty() {
+        Expression::AllocDynamicBytes {
+            loc: *loc,
+            ty: Type::DynamicBytes,
+            size: Expression::NumberLiteral {
+                loc: *loc,
+                ty: Type::Uint(32),
+                value: 0.into(),
+            }
+            .into(),
+            initializer: None,
+        }
+    } else {
+        abi_encode(loc, args, ns, vartab, cfg, false).0
+    };
+
+    let ast_func_no = ns
+        .functions
+        .iter()
+        .position(|f| f.loc_prototype == Loc::Builtin && f.id.name == "chain_extension")
+        .unwrap();
+    let ret_code = vartab.temp_name("chain_extension_ret", &Type::Uint(32));
+    let output = vartab.temp_name("chain_extension_output", &Type::DynamicBytes);
+    cfg.add(
+        vartab,
+        Instr::Call {
+            res: vec![ret_code, output],
+            call: InternalCallTy::Builtin { ast_func_no },
+            args: vec![
+                Expression::NumberLiteral {
+                    loc: *loc,
+                    ty: Type::Uint(32),
+                    value: id.into(),
+                },
+                input,
+            ],
+            return_tys: vec![Type::Uint(32), Type::DynamicBytes],
+        },
+    );
+
+    let success_block = cfg.new_basic_block("chain_extension_success".into());
+    let fail_block = cfg.new_basic_block("chain_extension_fail".into());
+    cfg.add(
+        vartab,
+        Instr::BranchCond {
+            cond: Expression::Equal {
+                loc: *loc,
+                left: Expression::Variable {
+                    loc: *loc,
+                    ty: Type::Uint(32),
+                    var_no: ret_code,
+                }
+                .into(),
+                right: Expression::NumberLiteral {
+                    loc: *loc,
+                    ty: Type::Uint(32),
+                    value: 0.into(),
+                }
+                .into(),
+            },
+            true_block: success_block,
+            false_block: fail_block,
+        },
+    );
+
+    cfg.set_basic_block(fail_block);
+    let msg = "chain extension call failed";
+    log_runtime_error(opt.log_runtime_errors, msg, *loc, cfg, vartab, ns);
+    cfg.add(vartab, Instr::AssertFailure { encoded_args: None });
+
+    cfg.set_basic_block(success_block);
+
+    if func.returns.is_empty() {
+        return vec![Expression::Poison];
+    }
+
+    let output = Expression::Variable {
+        loc: *loc,
+        ty: Type::DynamicBytes,
+        var_no: output,
+    };
+    let return_tys: Vec<Type> = func.returns.iter().map(|ret| ret.ty.clone()).collect();
+
+    abi_decode(loc, &output, &return_tys, ns, vartab, cfg, None)
+}
+
+/// Values of these types are stored as a single integer, which is zero if the slot is empty.
+pub(crate) fn is_storage_int(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Bool | Type::Int(_) | Type::Uint(_) | Type::Bytes(_) | Type::Enum(_) | Type::Value
+    )
+}
+
+/// Storing zero in a mapping entry with an integer value clears the entry rather than storing the
+/// zero, so whether the value is zero can be found with `contains_storage` without reading it.
+/// `storage_ty` is the type of the slot expression, which is the mapping for mapping entries.
+/// With ink! storage keys the mapping may also be written by ink!, which does store zeros.
+pub(crate) fn zero_clears_mapping_entry(storage_ty: &Type, ty: &Type, ns: &Namespace) -> bool {
+    ns.target.is_polkadot()
+        && !ns.target.ink_storage_keys()
+        && is_storage_int(ty)
+        && matches!(storage_ty.deref_any(), Type::Mapping(Mapping { value, .. }) if value.as_ref() == ty)
+}
+
+/// With ink! storage keys, a mapping entry is stored under the SCALE encoded tuple of the root key
+/// of the mapping and the mapping key, just like an entry of an ink! `Mapping`. The root key is
+/// the slot of the mapping.
+pub(super) fn ink_storage_key(
+    loc: &Loc,
+    mapping_ty: &Type,
+    slot: Expression,
+    key: Expression,
+    cfg: &mut ControlFlowGraph,
+    vartab: &mut Vartable,
+    ns: &Namespace,
+) -> Expression {
+    let root_key = Expression::Trunc {
+        loc: *loc,
+        ty: Type::Uint(32),
+        expr: slot.into(),
+    };
+
+    let (encoded, _) = abi_encode(loc, vec![root_key, key], ns, vartab, cfg, false);
+
+    Expression::Builtin {
+        loc: *loc,
+        tys: vec![mapping_ty.clone()],
+        kind: Builtin::StorageKey,
+        args: vec![encoded],
+    }
+}
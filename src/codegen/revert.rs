@@ -28,6 +28,30 @@ pub(crate) const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
 /// Signature of `Keccak256('Panic(uint256)')[:4]`
 pub(crate) const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
 
+/// Anchor numbers user defined errors from this offset onwards; Solang does the same so
+/// that Anchor clients can recognize custom errors.
+pub(crate) const ERROR_CODE_OFFSET: u32 = 6000;
+
+/// Return the Anchor compatible error code of a custom error on Solana. Like Anchor numbers
+/// the errors of a program, the errors are numbered per contract.
+pub(crate) fn solana_error_code(contract: &ast::Contract, error_no: usize) -> u32 {
+    ERROR_CODE_OFFSET + error_index(contract, error_no)
+}
+
+/// Return the code of a custom error on Soroban, which is the code of the contract error the
+/// invocation fails with. The errors are numbered per contract from 1 onwards.
+pub(crate) fn soroban_error_code(contract: &ast::Contract, error_no: usize) -> u32 {
+    error_index(contract, error_no) + 1
+}
+
+fn error_index(contract: &ast::Contract, error_no: usize) -> u32 {
+    contract
+        .errors
+        .iter()
+        .position(|no| *no == error_no)
+        .expect("error should be listed in contract") as u32
+}
+
 /// Corresponds to the error types from the Solidity language.
 ///
 /// Marked as non-exhaustive because Solidity may add more variants in the future.
@@ -75,7 +99,10 @@ impl SolidityError {
         }
     }
 
-    /// ABI encode the selector and any error data.
+    /// ABI encode the selector and any error data. On Solana, custom errors are
+    /// Borsh encoded and prefixed with their error code rather than a selector; this
+    /// is already the first expression, see [`revert`]. On Soroban, the error code is
+    /// the only expression.
     ///
     /// Returns `None` if the data can't be ABI encoded.
     pub(super) fn abi_encode(
@@ -108,7 +135,9 @@ impl SolidityError {
             }
             Self::Custom { exprs, .. } => {
                 let mut args = exprs.to_owned();
-                args.insert(0, self.selector_expression(ns));
+                if !matches!(ns.target, Target::Solana | Target::Soroban) {
+                    args.insert(0, self.selector_expression(ns));
+                }
                 create_encoder(ns, false)
                     .const_encode(&args)
                     .map(|bytes| {
@@ -186,8 +215,10 @@ pub(super) fn assert_failure(
     cfg: &mut ControlFlowGraph,
     vartab: &mut Vartable,
 ) {
-    // On Solana, returning the encoded arguments has no effect
-    if ns.target == Target::Solana {
+    // On Solana and Soroban, returning the encoded arguments has no effect, except
+    // for custom errors, which are returned with their error code
+    let custom_error = matches!(error, SolidityError::Custom { .. });
+    if matches!(ns.target, Target::Solana | Target::Soroban) && !custom_error {
         cfg.add(vartab, Instr::AssertFailure { encoded_args: None });
         return;
     }
@@ -257,8 +288,10 @@ pub(super) fn require(
         .get(1)
         .map(|s| expression(s, cfg, contract_no, func, ns, vartab, opt));
 
-    // On Solana and Polkadot, print the reason
-    if opt.log_runtime_errors && (ns.target == Target::Solana || ns.target.is_polkadot()) {
+    // On Solana, Polkadot and Soroban, print the reason
+    if opt.log_runtime_errors
+        && (ns.target == Target::Solana || ns.target.is_polkadot() || ns.target == Target::Soroban)
+    {
         if let Some(expr) = expr.clone() {
             let prefix = b"runtime_error: ";
             let error_string = format!(
@@ -371,7 +404,30 @@ pub(super) fn revert(
 
     let error = match (*error_no, exprs.first()) {
         // Having an error number requires a custom error
-        (Some(error_no), _) => SolidityError::Custom { error_no, exprs },
+        (Some(error_no), _) => {
+            let mut exprs = exprs;
+
+            // The error code depends on the contract, so it is not known to the error itself
+            if ns.target == Target::Solana {
+                exprs.insert(
+                    0,
+                    Expression::NumberLiteral {
+                        loc: Codegen,
+                        ty: Type::Uint(32),
+                        value: solana_error_code(&ns.contracts[contract_no], error_no).into(),
+                    },
+                );
+            } else if ns.target == Target::Soroban {
+                // the error fields cannot be returned, only the error code
+                exprs = vec![Expression::NumberLiteral {
+                    loc: Codegen,
+                    ty: Type::Uint(32),
+                    value: soroban_error_code(&ns.contracts[contract_no], error_no).into(),
+                }];
+            }
+
+            SolidityError::Custom { error_no, exprs }
+        }
         // No error number but an expression requires Error(String)
         (None, Some(expr)) => SolidityError::String(expr.clone()),
         // No error number and no data means just "revert();" without any reason
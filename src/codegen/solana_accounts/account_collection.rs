@@ -205,6 +205,8 @@ fn check_instruction(instr: &Instr, data: &mut RecurseData) {
         Instr::Print { expr }
         | Instr::LoadStorage { storage: expr, .. }
         | Instr::ClearStorage { storage: expr, .. }
+        | Instr::TakeStorage { storage: expr, .. }
+        | Instr::ContainsStorage { storage: expr, .. }
         | Instr::BranchCond { cond: expr, .. }
         | Instr::PopStorage { storage: expr, .. }
         | Instr::SelfDestruct { recipient: expr }
@@ -289,6 +291,7 @@ fn check_instruction(instr: &Instr, data: &mut RecurseData) {
             value: expr_1,
             storage: expr_2,
             offset: expr_3,
+            ..
         } => {
             expr_1.recurse(data, check_expression);
             expr_2.recurse(data, check_expression);
@@ -449,20 +452,6 @@ fn check_instruction(instr: &Instr, data: &mut RecurseData) {
 /// Collect accounts from this expression
 fn check_expression(expr: &Expression, data: &mut RecurseData) -> bool {
     match expr {
-        Expression::Builtin {
-            kind: Builtin::Timestamp | Builtin::BlockNumber | Builtin::Slot,
-            ..
-        } => {
-            data.add_account(
-                BuiltinAccounts::ClockAccount.to_string(),
-                &SolanaAccount {
-                    loc: Loc::Codegen,
-                    is_signer: false,
-                    is_writer: false,
-                    generated: true,
-                },
-            );
-        }
         Expression::Builtin {
             kind: Builtin::SignatureVerify,
             ..
@@ -125,6 +125,7 @@ pub(super) fn solana_deploy(
             value: BigInt::zero(),
         }
         .into(),
+        storage_type: None,
     };
 
     let loaded_item = Expression::Load {
@@ -606,6 +607,8 @@ pub(super) fn solana_deploy(
                     ty: Type::Uint(64),
                     value: BigInt::from(0),
                 },
+                proof_size: None,
+                storage_deposit_limit: None,
                 callty: CallTy::Regular,
                 contract_function_no: None,
                 flags: None,
@@ -639,6 +642,7 @@ pub(super) fn solana_deploy(
                 ty: Type::Uint(64),
                 value: BigInt::zero(),
             },
+            storage_type: None,
         },
     );
 
@@ -663,6 +667,7 @@ pub(super) fn solana_deploy(
                 ty: Type::Uint(64),
                 value: BigInt::from(12),
             },
+            storage_type: None,
         },
     );
 }
@@ -0,0 +1,274 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Contains `codegen` helpers for the Soroban target.
+
+use num_bigint::BigInt;
+use solang_parser::pt::Loc;
+
+use crate::{
+    codegen::{
+        cfg::{ControlFlowGraph, Instr},
+        vartable::Vartable,
+        Builtin, Expression,
+    },
+    sema::ast::{self, Namespace, RetrieveType, StorageType, Type},
+    Target,
+};
+
+/// Every value passed to or received from the Soroban host is a 64 bit `Val`
+const VAL_SIZE: u64 = 8;
+
+/// Convert a value into a `Val`
+pub(crate) fn to_val(loc: &Loc, expr: Expression) -> Expression {
+    Expression::Builtin {
+        loc: *loc,
+        tys: vec![Type::Uint(64)],
+        kind: Builtin::ToVal,
+        args: vec![expr],
+    }
+}
+
+/// Convert a `Val` into a value of the given type
+pub(crate) fn from_val(loc: &Loc, ty: &Type, val: Expression) -> Expression {
+    Expression::Builtin {
+        loc: *loc,
+        tys: vec![ty.clone()],
+        kind: Builtin::FromVal,
+        args: vec![val],
+    }
+}
+
+/// The storage type of the state variable which a storage reference points into. The host
+/// functions for contract data need it, so it is only found on Soroban. Expressions which
+/// are not in storage have none.
+pub(crate) fn storage_type(expr: &ast::Expression, ns: &Namespace) -> Option<StorageType> {
+    if ns.target != Target::Soroban {
+        return None;
+    }
+
+    match expr {
+        ast::Expression::StorageVariable {
+            contract_no,
+            var_no,
+            ..
+        } => variable_storage_type(*contract_no, *var_no, ns),
+        ast::Expression::Subscript { array: expr, .. }
+        | ast::Expression::StructMember { expr, .. }
+        | ast::Expression::StorageLoad { expr, .. } => storage_type(expr, ns),
+        ast::Expression::Builtin {
+            kind: ast::Builtin::ArrayPush | ast::Builtin::ArrayPop,
+            args,
+            ..
+        } => storage_type(&args[0], ns),
+        // any other storage reference, e.g. in a variable or selected by a conditional
+        // operator, is rejected by sema on Soroban
+        _ if matches!(expr.ty(), Type::StorageRef(..)) => {
+            unreachable!("storage reference of unknown storage type")
+        }
+        _ => None,
+    }
+}
+
+/// The storage type of a state variable. Variables without an annotation are stored in
+/// instance storage.
+pub(crate) fn variable_storage_type(
+    contract_no: usize,
+    var_no: usize,
+    ns: &Namespace,
+) -> Option<StorageType> {
+    if ns.target != Target::Soroban {
+        return None;
+    }
+
+    Some(
+        ns.contracts[contract_no].variables[var_no]
+            .storage_type
+            .unwrap_or_default(),
+    )
+}
+
+/// Create a `Symbol` from a name, e.g. the name of a function
+pub(crate) fn symbol(loc: &Loc, name: &str) -> Expression {
+    Expression::Builtin {
+        loc: *loc,
+        tys: vec![Type::Uint(64)],
+        kind: Builtin::Symbol,
+        args: vec![Expression::BytesLiteral {
+            loc: *loc,
+            ty: Type::String,
+            value: name.as_bytes().to_vec(),
+        }],
+    }
+}
+
+/// Build the payload for calling a function on another contract. The payload starts with
+/// the function name as a `Symbol`, followed by the arguments as `Val`s.
+pub(crate) fn call_payload(
+    loc: &Loc,
+    function_name: &str,
+    args: Vec<Expression>,
+    vartab: &mut Vartable,
+    cfg: &mut ControlFlowGraph,
+) -> Expression {
+    let mut vals = vec![symbol(loc, function_name)];
+
+    vals.extend(args.into_iter().map(|arg| to_val(loc, arg)));
+
+    vals_buffer(loc, vals, vartab, cfg)
+}
+
+/// Write a list of `Val`s into a new buffer
+pub(crate) fn vals_buffer(
+    loc: &Loc,
+    vals: Vec<Expression>,
+    vartab: &mut Vartable,
+    cfg: &mut ControlFlowGraph,
+) -> Expression {
+    let buffer = vartab.temp_name("vals", &Type::DynamicBytes);
+
+    cfg.add(
+        vartab,
+        Instr::Set {
+            loc: *loc,
+            res: buffer,
+            expr: Expression::AllocDynamicBytes {
+                loc: *loc,
+                ty: Type::DynamicBytes,
+                size: Expression::NumberLiteral {
+                    loc: *loc,
+                    ty: Type::Uint(32),
+                    value: BigInt::from(vals.len() as u64 * VAL_SIZE),
+                }
+                .into(),
+                initializer: None,
+            },
+        },
+    );
+
+    for (no, val) in vals.into_iter().enumerate() {
+        cfg.add(
+            vartab,
+            Instr::WriteBuffer {
+                buf: Expression::Variable {
+                    loc: *loc,
+                    ty: Type::DynamicBytes,
+                    var_no: buffer,
+                },
+                offset: Expression::NumberLiteral {
+                    loc: *loc,
+                    ty: Type::Uint(32),
+                    value: BigInt::from(no as u64 * VAL_SIZE),
+                },
+                value: val,
+            },
+        );
+    }
+
+    Expression::Variable {
+        loc: *loc,
+        ty: Type::DynamicBytes,
+        var_no: buffer,
+    }
+}
+
+/// Abort unless the address in the first argument has authorized the current invocation. If
+/// `for_args` is set, the authorization is for the remaining arguments instead.
+pub(crate) fn require_auth(
+    loc: &Loc,
+    for_args: bool,
+    mut args: Vec<Expression>,
+    vartab: &mut Vartable,
+    cfg: &mut ControlFlowGraph,
+) -> Expression {
+    let address = to_val(loc, args.remove(0));
+
+    if for_args {
+        let vals = args.into_iter().map(|arg| to_val(loc, arg)).collect();
+        let vals = vals_buffer(loc, vals, vartab, cfg);
+
+        host_call(
+            loc,
+            Builtin::RequireAuthForArgs,
+            vec![address, vals],
+            vartab,
+            cfg,
+        )
+    } else {
+        host_call(loc, Builtin::RequireAuth, vec![address], vartab, cfg)
+    }
+}
+
+/// Extend the TTL of the contract data for a storage reference. The arguments are the
+/// storage key, the threshold and the new TTL. Instance storage has no TTL of its own, so
+/// for that the TTL of the contract instance is extended.
+pub(crate) fn extend_ttl(
+    loc: &Loc,
+    storage_type: StorageType,
+    mut args: Vec<Expression>,
+    vartab: &mut Vartable,
+    cfg: &mut ControlFlowGraph,
+) -> Expression {
+    if storage_type == StorageType::Instance {
+        args.remove(0);
+
+        host_call(loc, Builtin::ExtendInstanceTtl, args, vartab, cfg)
+    } else {
+        args.insert(
+            1,
+            Expression::NumberLiteral {
+                loc: *loc,
+                ty: Type::Uint(64),
+                value: BigInt::from(storage_type as u64),
+            },
+        );
+
+        host_call(loc, Builtin::ExtendTtl, args, vartab, cfg)
+    }
+}
+
+/// Call a host function for its side effects. The call is assigned to a temporary, so that
+/// it is not removed as an unused expression.
+fn host_call(
+    loc: &Loc,
+    kind: Builtin,
+    args: Vec<Expression>,
+    vartab: &mut Vartable,
+    cfg: &mut ControlFlowGraph,
+) -> Expression {
+    let res = vartab.temp_anonymous(&Type::Uint(64));
+
+    cfg.add(
+        vartab,
+        Instr::Set {
+            loc: *loc,
+            res,
+            expr: Expression::Builtin {
+                loc: *loc,
+                tys: vec![Type::Uint(64)],
+                kind,
+                args,
+            },
+        },
+    );
+
+    Expression::Poison
+}
+
+/// The value returned by a call to another contract
+pub(crate) fn return_value(loc: &Loc, ty: &Type) -> Expression {
+    let val = Expression::Builtin {
+        loc: *loc,
+        tys: vec![Type::Uint(64)],
+        kind: Builtin::ReadFromBuffer,
+        args: vec![
+            Expression::ReturnData { loc: *loc },
+            Expression::NumberLiteral {
+                loc: *loc,
+                ty: Type::Uint(32),
+                value: BigInt::from(0u8),
+            },
+        ],
+    };
+
+    from_val(loc, ty, val)
+}
@@ -5,6 +5,7 @@ use super::{
     events::new_event_emitter,
     expression::{assign_single, emit_function_call, expression},
     revert::revert,
+    soroban,
     unused_variable::{
         should_remove_assignment, should_remove_variable, SideEffectsCheckParameters,
     },
@@ -14,7 +15,7 @@ use super::{
 };
 use crate::sema::ast::{
     self, ArrayLength, DestructureField, Function, Namespace, RetrieveType, SolanaAccount,
-    Statement, Type, Type::Uint,
+    Statement, StorageType, Type, Type::Uint,
 };
 use crate::sema::solana_accounts::BuiltinAccounts;
 use crate::sema::Recurse;
@@ -221,6 +222,7 @@ pub(crate) fn statement(
                 Instr::ClearStorage {
                     ty: ty.clone(),
                     storage: var_expr,
+                    storage_type: soroban::storage_type(expr, ns),
                 },
             );
         }
@@ -823,23 +825,26 @@ fn returns(
         | ast::Expression::ExternalFunctionCall { .. }
         | ast::Expression::ExternalFunctionCallRaw { .. } => {
             emit_function_call(expr, contract_no, cfg, Some(func), ns, vartab, opt)
+                .into_iter()
+                .map(|value| (value, None))
+                .collect()
         }
 
         ast::Expression::List { list, .. } => list
             .iter()
-            .map(|e| expression(e, cfg, contract_no, Some(func), ns, vartab, opt))
-            .collect::<Vec<Expression>>(),
+            .map(|e| {
+                (
+                    expression(e, cfg, contract_no, Some(func), ns, vartab, opt),
+                    soroban::storage_type(e, ns),
+                )
+            })
+            .collect::<Vec<_>>(),
 
         // Can be any other expression
         _ => {
-            vec![expression(
-                expr,
-                cfg,
-                contract_no,
-                Some(func),
-                ns,
-                vartab,
-                opt,
+            vec![(
+                expression(expr, cfg, contract_no, Some(func), ns, vartab, opt),
+                soroban::storage_type(expr, ns),
             )]
         }
     };
@@ -848,7 +853,17 @@ fn returns(
         .returns
         .iter()
         .zip(uncast_values)
-        .map(|(left, right)| try_load_and_cast(&right.loc(), &right, &left.ty, ns, cfg, vartab))
+        .map(|(left, (right, storage_type))| {
+            try_load_and_cast(
+                &right.loc(),
+                &right,
+                &left.ty,
+                storage_type,
+                ns,
+                cfg,
+                vartab,
+            )
+        })
         .collect();
 
     cfg.add(vartab, Instr::Return { value: cast_values });
@@ -913,6 +928,7 @@ fn destructure(
 
             for expr in list {
                 let loc = expr.loc();
+                let storage_type = soroban::storage_type(expr, ns);
                 let expr = expression(expr, cfg, contract_no, Some(func), ns, vartab, opt);
                 let ty = expr.ty();
 
@@ -920,11 +936,14 @@ fn destructure(
 
                 cfg.add(vartab, Instr::Set { loc, res, expr });
 
-                values.push(Expression::Variable {
-                    loc,
-                    ty,
-                    var_no: res,
-                });
+                values.push((
+                    Expression::Variable {
+                        loc,
+                        ty,
+                        var_no: res,
+                    },
+                    storage_type,
+                ));
             }
 
             values
@@ -932,18 +951,22 @@ fn destructure(
         _ => {
             // must be function call, either internal or external
             emit_function_call(expr, contract_no, cfg, Some(func), ns, vartab, opt)
+                .into_iter()
+                .map(|value| (value, None))
+                .collect()
         }
     };
 
     for field in fields.iter() {
-        let right = values.remove(0);
+        let (right, storage_type) = values.remove(0);
 
         match field {
             DestructureField::None => {
                 // nothing to do
             }
             DestructureField::VariableDecl(res, param) => {
-                let expr = try_load_and_cast(&param.loc, &right, &param.ty, ns, cfg, vartab);
+                let expr =
+                    try_load_and_cast(&param.loc, &right, &param.ty, storage_type, ns, cfg, vartab);
 
                 if should_remove_variable(*res, func, opt, ns) {
                     continue;
@@ -959,7 +982,15 @@ fn destructure(
                 );
             }
             DestructureField::Expression(left) => {
-                let expr = try_load_and_cast(&left.loc(), &right, &left.ty(), ns, cfg, vartab);
+                let expr = try_load_and_cast(
+                    &left.loc(),
+                    &right,
+                    &left.ty(),
+                    storage_type,
+                    ns,
+                    cfg,
+                    vartab,
+                );
 
                 if should_remove_assignment(left, func, opt, ns) {
                     continue;
@@ -978,6 +1009,7 @@ fn try_load_and_cast(
     loc: &pt::Loc,
     expr: &Expression,
     to_ty: &Type,
+    storage_type: Option<StorageType>,
     ns: &Namespace,
     cfg: &mut ControlFlowGraph,
     vartab: &mut Vartable,
@@ -1002,6 +1034,7 @@ fn try_load_and_cast(
                     res: anonymous_no,
                     ty: (*ty).clone(),
                     storage: expr.cast(to_ty, ns),
+                    storage_type,
                 },
             );
 
@@ -12,9 +12,9 @@ use crate::codegen::{
     Expression,
 };
 use crate::sema::ast::{
-    self, CallTy, ExternalCallAccounts, Function, Namespace, RetrieveType, TryCatch, Type,
-    Type::Uint,
+    self, CallTy, Function, Namespace, RetrieveType, TryCatch, Type, Type::Uint,
 };
+use crate::Target;
 use num_bigint::{BigInt, Sign};
 use num_traits::Zero;
 use solang_parser::pt::{self, CodeLocation, Loc::Codegen};
@@ -32,7 +32,7 @@ pub(super) fn try_catch(
     return_override: Option<&Instr>,
     opt: &Options,
 ) {
-    if !ns.target.is_polkadot() {
+    if !ns.target.is_polkadot() && ns.target != Target::Solana {
         unimplemented!()
     }
 
@@ -123,26 +123,28 @@ fn insert_try_expression(
 
     vartab.new_dirty_tracker();
 
-    cfg.set_basic_block(cases.error_no_data);
-    cfg.add(
-        vartab,
-        Instr::Set {
-            loc: Codegen,
-            res: error_ret_data_var,
-            expr: Expression::AllocDynamicBytes {
+    if let Some(error_no_data) = cases.error_no_data {
+        cfg.set_basic_block(error_no_data);
+        cfg.add(
+            vartab,
+            Instr::Set {
                 loc: Codegen,
-                ty: Type::DynamicBytes,
-                size: Expression::NumberLiteral {
+                res: error_ret_data_var,
+                expr: Expression::AllocDynamicBytes {
                     loc: Codegen,
-                    ty: Uint(32),
-                    value: 0.into(),
-                }
-                .into(),
-                initializer: Some(vec![]),
+                    ty: Type::DynamicBytes,
+                    size: Expression::NumberLiteral {
+                        loc: Codegen,
+                        ty: Uint(32),
+                        value: 0.into(),
+                    }
+                    .into(),
+                    initializer: Some(vec![]),
+                },
             },
-        },
-    );
-    cfg.add(vartab, Instr::Branch { block: catch_block });
+        );
+        cfg.add(vartab, Instr::Branch { block: catch_block });
+    }
 
     cfg.set_basic_block(cases.revert);
     cfg.add(
@@ -185,6 +187,71 @@ fn insert_try_expression(
     error_ret_data_var
 }
 
+/// The blocks the execution of the `try` expression branches to.
+struct TryCases {
+    success: usize,
+    revert: usize,
+    /// Polkadot specific: the call failed without returning any error data
+    error_no_data: Option<usize>,
+}
+
+impl TryCases {
+    /// Check the return code of the call. On Solana, the invocation returns the error
+    /// code of the program, which is zero on success; the error data is in the return data.
+    fn insert(
+        loc: pt::Loc,
+        success: usize,
+        ns: &Namespace,
+        cfg: &mut ControlFlowGraph,
+        vartab: &mut Vartable,
+    ) -> Self {
+        if ns.target != Target::Solana {
+            let cases = polkadot::RetCodeCheckBuilder::default()
+                .loc(loc)
+                .success_var(success)
+                .insert(cfg, vartab);
+
+            return TryCases {
+                success: cases.success,
+                revert: cases.revert,
+                error_no_data: Some(cases.error_no_data),
+            };
+        }
+
+        let cases = TryCases {
+            success: cfg.new_basic_block("ret_success".into()),
+            revert: cfg.new_basic_block("ret_bubble".into()),
+            error_no_data: None,
+        };
+
+        let cond = Expression::Equal {
+            loc,
+            left: Expression::Variable {
+                loc,
+                ty: Type::Uint(64),
+                var_no: success,
+            }
+            .into(),
+            right: Expression::NumberLiteral {
+                loc,
+                ty: Type::Uint(64),
+                value: 0.into(),
+            }
+            .into(),
+        };
+        cfg.add(
+            vartab,
+            Instr::BranchCond {
+                cond,
+                true_block: cases.success,
+                false_block: cases.revert,
+            },
+        );
+
+        cases
+    }
+}
+
 /// Insert the execution of the `try` expression into the CFG.
 fn exec_try(
     try_stmt: &TryCatch,
@@ -194,13 +261,17 @@ fn exec_try(
     ns: &Namespace,
     vartab: &mut Vartable,
     opt: &Options,
-) -> (polkadot::RetCodeCheck, Vec<Type>) {
+) -> (TryCases, Vec<Type>) {
     let success = vartab.temp(
         &pt::Identifier {
             loc: try_stmt.expr.loc(),
             name: "success".to_owned(),
         },
-        &Type::Bool,
+        if ns.target == Target::Solana {
+            &Type::Uint(64)
+        } else {
+            &Type::Bool
+        },
     );
     match &try_stmt.expr {
         ast::Expression::ExternalFunctionCall {
@@ -215,6 +286,15 @@ fn exec_try(
                 ..
             } = function.ty()
             {
+                // Solana needs to know which function is called to collect its accounts
+                let contract_function_no = match function.as_ref() {
+                    ast::Expression::ExternalFunction { function_no, .. } => ns.functions
+                        [*function_no]
+                        .contract_no
+                        .map(|contract_no| (contract_no, *function_no)),
+                    _ => None,
+                };
+
                 let value = if let Some(value) = &call_args.value {
                     expression(value, cfg, callee_contract_no, Some(func), ns, vartab, opt)
                 } else {
@@ -251,9 +331,21 @@ fn exec_try(
                 args.insert(0, selector);
                 let (payload, _) = abi_encode(loc, args, ns, vartab, cfg, false);
 
+                let accounts = call_args.accounts.map(|expr| {
+                    expression(expr, cfg, callee_contract_no, Some(func), ns, vartab, opt)
+                });
+                let seeds = call_args.seeds.as_ref().map(|expr| {
+                    expression(expr, cfg, callee_contract_no, Some(func), ns, vartab, opt)
+                });
                 let flags = call_args.flags.as_ref().map(|expr| {
                     expression(expr, cfg, callee_contract_no, Some(func), ns, vartab, opt)
                 });
+                let proof_size = call_args.proof_size.as_ref().map(|expr| {
+                    expression(expr, cfg, callee_contract_no, Some(func), ns, vartab, opt)
+                });
+                let storage_deposit_limit = call_args.storage_deposit_limit.as_ref().map(|expr| {
+                    expression(expr, cfg, callee_contract_no, Some(func), ns, vartab, opt)
+                });
 
                 cfg.add(
                     vartab,
@@ -261,21 +353,20 @@ fn exec_try(
                         loc: *loc,
                         success: Some(success),
                         address: Some(address),
-                        accounts: ExternalCallAccounts::AbsentArgument,
-                        seeds: None,
+                        accounts,
+                        seeds,
                         payload,
                         value,
                         gas,
+                        proof_size,
+                        storage_deposit_limit,
                         callty: CallTy::Regular,
-                        contract_function_no: None,
+                        contract_function_no,
                         flags,
                     },
                 );
 
-                let cases = polkadot::RetCodeCheckBuilder::default()
-                    .loc(*loc)
-                    .success_var(success)
-                    .insert(cfg, vartab);
+                let cases = TryCases::insert(*loc, success, ns, cfg, vartab);
                 (cases, func_returns)
             } else {
                 // dynamic dispatch
@@ -311,10 +402,7 @@ fn exec_try(
                 opt,
             );
 
-            let cases = polkadot::RetCodeCheckBuilder::default()
-                .loc(*loc)
-                .success_var(success)
-                .insert(cfg, vartab);
+            let cases = TryCases::insert(*loc, success, ns, cfg, vartab);
             (cases, vec![])
         }
         _ => unreachable!(),
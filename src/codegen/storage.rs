@@ -2,6 +2,7 @@
 
 use crate::codegen::Expression;
 use crate::sema::ast;
+use crate::Target;
 use num_bigint::BigInt;
 use num_traits::FromPrimitive;
 use num_traits::One;
@@ -15,8 +16,9 @@ use super::{
     cfg::{ControlFlowGraph, Instr},
     vartable::Vartable,
 };
+use super::{polkadot, soroban};
 use crate::codegen::revert::{assert_failure, log_runtime_error};
-use crate::sema::ast::{Function, Namespace, RetrieveType, Type};
+use crate::sema::ast::{Function, Namespace, RetrieveType, StorageType, Type};
 use solang_parser::pt;
 
 /// Given a storage slot which is the start of the array, calculate the
@@ -81,6 +83,41 @@ pub fn array_offset(
     }
 }
 
+/// Calculate the storage slot of an element of a dynamic storage array. On Soroban, each
+/// element has its own key derived from the key of the array, so this is done by
+/// the target. Otherwise the elements start at the keccak256 hash of the array slot.
+fn dynamic_array_element_slot(
+    loc: &pt::Loc,
+    array_ty: Type,
+    array: Expression,
+    index: Expression,
+    elem_ty: Type,
+    ns: &Namespace,
+) -> Expression {
+    if ns.target == Target::Soroban {
+        Expression::Subscript {
+            loc: *loc,
+            ty: Type::StorageRef(false, Box::new(elem_ty.deref_any().clone())),
+            array_ty,
+            expr: Box::new(array),
+            index: Box::new(index),
+            storage_type: None,
+        }
+    } else {
+        array_offset(
+            loc,
+            Expression::Keccak256 {
+                loc: *loc,
+                ty: ns.storage_type(),
+                exprs: vec![array],
+            },
+            index,
+            elem_ty,
+            ns,
+        )
+    }
+}
+
 /// Push() method on dynamic array in storage
 pub fn storage_slots_array_push(
     loc: &pt::Loc,
@@ -95,10 +132,11 @@ pub fn storage_slots_array_push(
     // set array+length to val_expr
     let slot_ty = ns.storage_type();
     let length_pos = vartab.temp_anonymous(&slot_ty);
+    let storage_type = soroban::storage_type(&args[0], ns);
 
     let var_expr = expression(&args[0], cfg, contract_no, func, ns, vartab, opt);
 
-    let expr = load_storage(loc, &slot_ty, var_expr.clone(), cfg, vartab);
+    let expr = load_storage(loc, &slot_ty, var_expr.clone(), storage_type, cfg, vartab);
 
     cfg.add(
         vartab,
@@ -118,13 +156,10 @@ pub fn storage_slots_array_push(
         Instr::Set {
             loc: pt::Loc::Codegen,
             res: entry_pos,
-            expr: array_offset(
+            expr: dynamic_array_element_slot(
                 loc,
-                Expression::Keccak256 {
-                    loc: *loc,
-                    ty: slot_ty.clone(),
-                    exprs: vec![var_expr.clone()],
-                },
+                args[0].ty(),
+                var_expr.clone(),
                 Expression::Variable {
                     loc: *loc,
                     ty: slot_ty.clone(),
@@ -149,6 +184,7 @@ pub fn storage_slots_array_push(
                     ty: slot_ty.clone(),
                     var_no: entry_pos,
                 },
+                storage_type,
             },
         );
     }
@@ -176,6 +212,7 @@ pub fn storage_slots_array_push(
             ty: slot_ty,
             value: new_length,
             storage: var_expr,
+            storage_type,
         },
     );
 
@@ -208,9 +245,10 @@ pub fn storage_slots_array_pop(
     let length_pos = vartab.temp_anonymous(&slot_ty);
 
     let ty = args[0].ty();
+    let storage_type = soroban::storage_type(&args[0], ns);
     let var_expr = expression(&args[0], cfg, contract_no, func, ns, vartab, opt);
 
-    let expr = load_storage(loc, &length_ty, var_expr.clone(), cfg, vartab);
+    let expr = load_storage(loc, &length_ty, var_expr.clone(), storage_type, cfg, vartab);
 
     cfg.add(
         vartab,
@@ -293,13 +331,10 @@ pub fn storage_slots_array_pop(
         Instr::Set {
             loc: pt::Loc::Codegen,
             res: entry_pos,
-            expr: array_offset(
+            expr: dynamic_array_element_slot(
                 loc,
-                Expression::Keccak256 {
-                    loc: *loc,
-                    ty: slot_ty.clone(),
-                    exprs: vec![var_expr.clone()],
-                },
+                ty.clone(),
+                var_expr.clone(),
                 Expression::Variable {
                     loc: *loc,
                     ty: slot_ty.clone(),
@@ -322,6 +357,7 @@ pub fn storage_slots_array_pop(
                 ty: elem_ty.clone(),
                 var_no: entry_pos,
             },
+            storage_type,
             cfg,
             vartab,
         );
@@ -354,6 +390,7 @@ pub fn storage_slots_array_pop(
                 ty: slot_ty.clone(),
                 var_no: entry_pos,
             },
+            storage_type,
         },
     );
 
@@ -368,12 +405,128 @@ pub fn storage_slots_array_pop(
                 var_no: new_length,
             },
             storage: var_expr,
+            storage_type,
         },
     );
 
     val
 }
 
+/// Store a value in contract storage. On Polkadot, storing zero in a mapping entry clears it
+/// instead; see [`polkadot::zero_clears_mapping_entry`].
+pub fn set_storage(
+    loc: &pt::Loc,
+    ty: &Type,
+    value: Expression,
+    storage: Expression,
+    storage_type: Option<StorageType>,
+    cfg: &mut ControlFlowGraph,
+    vartab: &mut Vartable,
+    ns: &Namespace,
+) {
+    if !polkadot::zero_clears_mapping_entry(&storage.ty(), ty, ns) {
+        cfg.add(
+            vartab,
+            Instr::SetStorage {
+                ty: ty.clone(),
+                value,
+                storage,
+                storage_type,
+            },
+        );
+
+        return;
+    }
+
+    // a literal value tells us which one is needed
+    let known_zero = match &value {
+        Expression::BoolLiteral { value, .. } => Some(!value),
+        Expression::NumberLiteral { value, .. } => Some(value.is_zero()),
+        Expression::BytesLiteral {
+            ty: Type::Bytes(_),
+            value,
+            ..
+        } => Some(value.iter().all(|b| *b == 0)),
+        _ => None,
+    };
+
+    if let Some(is_zero) = known_zero {
+        let instr = if is_zero {
+            Instr::ClearStorage {
+                ty: ty.clone(),
+                storage,
+                storage_type,
+            }
+        } else {
+            Instr::SetStorage {
+                ty: ty.clone(),
+                value,
+                storage,
+                storage_type,
+            }
+        };
+
+        cfg.add(vartab, instr);
+
+        return;
+    }
+
+    let is_zero = if *ty == Type::Bool {
+        Expression::Not {
+            loc: *loc,
+            expr: Box::new(value.clone()),
+        }
+    } else {
+        Expression::Equal {
+            loc: *loc,
+            left: Box::new(value.clone()),
+            right: Box::new(Expression::NumberLiteral {
+                loc: *loc,
+                ty: ty.clone(),
+                value: BigInt::zero(),
+            }),
+        }
+    };
+
+    let clear = cfg.new_basic_block("clear_entry".to_string());
+    let set = cfg.new_basic_block("set_entry".to_string());
+    let done = cfg.new_basic_block("entry_stored".to_string());
+
+    cfg.add(
+        vartab,
+        Instr::BranchCond {
+            cond: is_zero,
+            true_block: clear,
+            false_block: set,
+        },
+    );
+
+    cfg.set_basic_block(clear);
+    cfg.add(
+        vartab,
+        Instr::ClearStorage {
+            ty: ty.clone(),
+            storage: storage.clone(),
+            storage_type,
+        },
+    );
+    cfg.add(vartab, Instr::Branch { block: done });
+
+    cfg.set_basic_block(set);
+    cfg.add(
+        vartab,
+        Instr::SetStorage {
+            ty: ty.clone(),
+            value,
+            storage,
+            storage_type,
+        },
+    );
+    cfg.add(vartab, Instr::Branch { block: done });
+
+    cfg.set_basic_block(done);
+}
+
 /// Push() method on array or bytes in storage
 pub fn array_push(
     loc: &pt::Loc,
@@ -385,6 +538,7 @@ pub fn array_push(
     vartab: &mut Vartable,
     opt: &Options,
 ) -> Expression {
+    let storage_type = soroban::storage_type(&args[0], ns);
     let storage = expression(&args[0], cfg, contract_no, func, ns, vartab, opt);
 
     let mut ty = args[0].ty().storage_array_elem();
@@ -416,6 +570,7 @@ pub fn array_push(
             ty: ty.deref_any().clone(),
             storage,
             value,
+            storage_type,
         },
     );
 
@@ -438,6 +593,7 @@ pub fn array_pop(
     vartab: &mut Vartable,
     opt: &Options,
 ) -> Expression {
+    let storage_type = soroban::storage_type(&args[0], ns);
     let storage = expression(&args[0], cfg, contract_no, func, ns, vartab, opt);
 
     let ty = args[0].ty().storage_array_elem().deref_into();
@@ -454,6 +610,7 @@ pub fn array_pop(
             res,
             ty: ty.clone(),
             storage,
+            storage_type,
         },
     );
 
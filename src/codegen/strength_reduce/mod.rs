@@ -130,7 +130,9 @@ fn block_reduce(
             Instr::Print { expr } => {
                 *expr = expression_reduce(expr, &vars, ns);
             }
-            Instr::ClearStorage { storage, .. } => {
+            Instr::ClearStorage { storage, .. }
+            | Instr::TakeStorage { storage, .. }
+            | Instr::ContainsStorage { storage, .. } => {
                 *storage = expression_reduce(storage, &vars, ns);
             }
             Instr::SetStorage { storage, value, .. } => {
@@ -19,6 +19,8 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
             Instr::BranchCond { cond: expr, .. }
             | Instr::LoadStorage { storage: expr, .. }
             | Instr::ClearStorage { storage: expr, .. }
+            | Instr::TakeStorage { storage: expr, .. }
+            | Instr::ContainsStorage { storage: expr, .. }
             | Instr::Print { expr }
             | Instr::AssertFailure {
                 encoded_args: Some(expr),
@@ -91,6 +93,7 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 value,
                 storage,
                 offset,
+                ..
             } => {
                 let _ = self.gen_expression(value, ave, cst);
                 let _ = self.gen_expression(storage, ave, cst);
@@ -107,6 +110,9 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 encoded_args,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
+                code_hash,
                 salt,
                 address,
                 accounts,
@@ -119,6 +125,18 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
 
                 let _ = self.gen_expression(gas, ave, cst);
 
+                if let Some(expr) = proof_size {
+                    let _ = self.gen_expression(expr, ave, cst);
+                }
+
+                if let Some(expr) = storage_deposit_limit {
+                    let _ = self.gen_expression(expr, ave, cst);
+                }
+
+                if let Some(expr) = code_hash {
+                    let _ = self.gen_expression(expr, ave, cst);
+                }
+
                 if let Some(expr) = salt {
                     let _ = self.gen_expression(expr, ave, cst);
                 }
@@ -137,6 +155,8 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 payload,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
                 accounts,
                 seeds,
                 ..
@@ -153,6 +173,12 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 let _ = self.gen_expression(payload, ave, cst);
                 let _ = self.gen_expression(value, ave, cst);
                 let _ = self.gen_expression(gas, ave, cst);
+                if let Some(expr) = proof_size {
+                    let _ = self.gen_expression(expr, ave, cst);
+                }
+                if let Some(expr) = storage_deposit_limit {
+                    let _ = self.gen_expression(expr, ave, cst);
+                }
             }
 
             Instr::ValueTransfer { address, value, .. } => {
@@ -267,31 +293,61 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 expr: self.regenerate_expression(expr, ave, cst).1,
             },
 
-            Instr::LoadStorage { res, ty, storage } => Instr::LoadStorage {
+            Instr::LoadStorage {
+                res,
+                ty,
+                storage,
+                storage_type,
+            } => Instr::LoadStorage {
                 res: *res,
                 ty: ty.clone(),
                 storage: self.regenerate_expression(storage, ave, cst).1,
+                storage_type: *storage_type,
+            },
+
+            Instr::ClearStorage {
+                ty,
+                storage,
+                storage_type,
+            } => Instr::ClearStorage {
+                ty: ty.clone(),
+                storage: self.regenerate_expression(storage, ave, cst).1,
+                storage_type: *storage_type,
             },
 
-            Instr::ClearStorage { ty, storage } => Instr::ClearStorage {
+            Instr::TakeStorage { res, ty, storage } => Instr::TakeStorage {
+                res: *res,
                 ty: ty.clone(),
                 storage: self.regenerate_expression(storage, ave, cst).1,
             },
 
-            Instr::SetStorage { ty, value, storage } => Instr::SetStorage {
+            Instr::ContainsStorage { res, storage } => Instr::ContainsStorage {
+                res: *res,
+                storage: self.regenerate_expression(storage, ave, cst).1,
+            },
+
+            Instr::SetStorage {
+                ty,
+                value,
+                storage,
+                storage_type,
+            } => Instr::SetStorage {
                 ty: ty.clone(),
                 value: self.regenerate_expression(value, ave, cst).1,
                 storage: self.regenerate_expression(storage, ave, cst).1,
+                storage_type: *storage_type,
             },
 
             Instr::SetStorageBytes {
                 value,
                 storage,
                 offset,
+                storage_type,
             } => Instr::SetStorageBytes {
                 value: self.regenerate_expression(value, ave, cst).1,
                 storage: self.regenerate_expression(storage, ave, cst).1,
                 offset: self.regenerate_expression(offset, ave, cst).1,
+                storage_type: *storage_type,
             },
 
             Instr::PushStorage {
@@ -299,6 +355,7 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 ty,
                 value,
                 storage,
+                storage_type,
             } => Instr::PushStorage {
                 res: *res,
                 ty: ty.clone(),
@@ -306,12 +363,19 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                     .as_ref()
                     .map(|expr| self.regenerate_expression(expr, ave, cst).1),
                 storage: self.regenerate_expression(storage, ave, cst).1,
+                storage_type: *storage_type,
             },
 
-            Instr::PopStorage { res, ty, storage } => Instr::PopStorage {
+            Instr::PopStorage {
+                res,
+                ty,
+                storage,
+                storage_type,
+            } => Instr::PopStorage {
                 res: *res,
                 ty: ty.clone(),
                 storage: self.regenerate_expression(storage, ave, cst).1,
+                storage_type: *storage_type,
             },
 
             Instr::PushMemory {
@@ -333,6 +397,9 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 encoded_args,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
+                code_hash,
                 salt,
                 address,
                 seeds,
@@ -344,6 +411,18 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                     .as_ref()
                     .map(|expr| self.regenerate_expression(expr, ave, cst).1);
 
+                let new_proof_size = proof_size
+                    .as_ref()
+                    .map(|expr| self.regenerate_expression(expr, ave, cst).1);
+
+                let new_storage_deposit_limit = storage_deposit_limit
+                    .as_ref()
+                    .map(|expr| self.regenerate_expression(expr, ave, cst).1);
+
+                let new_code_hash = code_hash
+                    .as_ref()
+                    .map(|expr| self.regenerate_expression(expr, ave, cst).1);
+
                 let new_salt = salt
                     .as_ref()
                     .map(|expr| self.regenerate_expression(expr, ave, cst).1);
@@ -368,6 +447,9 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                     encoded_args: self.regenerate_expression(encoded_args, ave, cst).1,
                     value: new_value,
                     gas: self.regenerate_expression(gas, ave, cst).1,
+                    proof_size: new_proof_size,
+                    storage_deposit_limit: new_storage_deposit_limit,
+                    code_hash: new_code_hash,
                     salt: new_salt,
                     address: new_address,
                     seeds: new_seeds,
@@ -384,6 +466,8 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 payload,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
                 callty,
                 seeds,
                 contract_function_no,
@@ -401,6 +485,14 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                     .as_ref()
                     .map(|expr| self.regenerate_expression(expr, ave, cst).1);
 
+                let new_proof_size = proof_size
+                    .as_ref()
+                    .map(|expr| self.regenerate_expression(expr, ave, cst).1);
+
+                let new_storage_deposit_limit = storage_deposit_limit
+                    .as_ref()
+                    .map(|expr| self.regenerate_expression(expr, ave, cst).1);
+
                 let flags = flags
                     .as_ref()
                     .map(|expr| self.regenerate_expression(expr, ave, cst).1);
@@ -414,6 +506,8 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                     payload: self.regenerate_expression(payload, ave, cst).1,
                     value: self.regenerate_expression(value, ave, cst).1,
                     gas: self.regenerate_expression(gas, ave, cst).1,
+                    proof_size: new_proof_size,
+                    storage_deposit_limit: new_storage_deposit_limit,
                     callty: callty.clone(),
                     contract_function_no: *contract_function_no,
                     flags,
@@ -417,6 +417,9 @@ fn string() {
         encoded_args: compare.clone(),
         value: None,
         gas: compare2.clone(),
+        proof_size: None,
+        storage_deposit_limit: None,
+        code_hash: None,
         salt: None,
         address: None,
         seeds: None,
@@ -18,6 +18,7 @@ fn test_builtin_conversion() {
         ast::Builtin::BlockNumber,
         ast::Builtin::Calldata,
         ast::Builtin::Gasleft,
+        ast::Builtin::RemainingComputeUnits,
         ast::Builtin::GasLimit,
         ast::Builtin::Gasprice,
         ast::Builtin::GetAddress,
@@ -40,6 +41,9 @@ fn test_builtin_conversion() {
         ast::Builtin::Ripemd160,
         ast::Builtin::Sender,
         ast::Builtin::Slot,
+        ast::Builtin::Epoch,
+        ast::Builtin::SlotsPerEpoch,
+        ast::Builtin::RentMinimumBalance,
         ast::Builtin::Sha256,
         ast::Builtin::Signature,
         ast::Builtin::SignatureVerify,
@@ -59,6 +63,7 @@ fn test_builtin_conversion() {
         ast::Builtin::WriteUint256LE,
         ast::Builtin::WriteString,
         ast::Builtin::WriteBytes,
+        ast::Builtin::ECRecover,
     ];
 
     let output: Vec<codegen::Builtin> = vec![
@@ -74,6 +79,7 @@ fn test_builtin_conversion() {
         codegen::Builtin::BlockNumber,
         codegen::Builtin::Calldata,
         codegen::Builtin::Gasleft,
+        codegen::Builtin::RemainingComputeUnits,
         codegen::Builtin::GasLimit,
         codegen::Builtin::Gasprice,
         codegen::Builtin::GetAddress,
@@ -96,6 +102,9 @@ fn test_builtin_conversion() {
         codegen::Builtin::Ripemd160,
         codegen::Builtin::Sender,
         codegen::Builtin::Slot,
+        codegen::Builtin::Epoch,
+        codegen::Builtin::SlotsPerEpoch,
+        codegen::Builtin::RentMinimumBalance,
         codegen::Builtin::Sha256,
         codegen::Builtin::Signature,
         codegen::Builtin::SignatureVerify,
@@ -115,6 +124,7 @@ fn test_builtin_conversion() {
         codegen::Builtin::WriteUint256LE,
         codegen::Builtin::WriteBytes,
         codegen::Builtin::WriteBytes,
+        codegen::Builtin::ECRecover,
     ];
 
     for (i, item) in input.iter().enumerate() {
@@ -107,6 +107,8 @@ fn find_writable_vectors(
             | Instr::LoadStorage { .. }
             | Instr::SetStorage { .. }
             | Instr::ClearStorage { .. }
+            | Instr::TakeStorage { .. }
+            | Instr::ContainsStorage { .. }
             | Instr::SetStorageBytes { .. }
             | Instr::PushStorage { .. }
             | Instr::PopStorage { .. }
@@ -133,6 +133,7 @@ fn contract_constant_variable() {
         }),
         assigned: false,
         read: false,
+        storage_type: None,
     };
 
     let contract = Contract {
@@ -154,12 +155,14 @@ fn contract_constant_variable() {
         variables: vec![var],
         creates: vec![],
         emits_events: vec![],
+        errors: vec![],
         initializer: None,
         default_constructor: None,
         cfg: vec![],
         code: OnceCell::new(),
         instantiable: true,
         program_id: None,
+        chain_extension: None,
     };
     ns.contracts.push(contract);
 
@@ -198,6 +201,7 @@ fn global_constant_variable() {
         }),
         assigned: false,
         read: false,
+        storage_type: None,
     };
     ns.constants.push(var);
     let expr = ast::YulExpression::ConstantVariable(loc, Type::Uint(64), None, 0);
@@ -292,12 +296,14 @@ fn slot_suffix() {
         variables: vec![],
         creates: vec![],
         emits_events: vec![],
+        errors: vec![],
         initializer: None,
         default_constructor: None,
         cfg: vec![],
         code: OnceCell::new(),
         instantiable: true,
         program_id: None,
+        chain_extension: None,
     };
     ns.contracts.push(contract);
 
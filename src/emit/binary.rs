@@ -148,7 +148,7 @@ pub struct Binary<'a> {
     pub module: Module<'a>,
     pub(crate) options: &'a Options,
     pub runtime: Option<Box<Binary<'a>>>,
-    target: Target,
+    pub(crate) target: Target,
     pub(crate) function_abort_value_transfers: bool,
     pub(crate) constructor_abort_value_transfers: bool,
     pub builder: Builder<'a>,
@@ -553,6 +553,35 @@ impl<'a> Binary<'a> {
         res
     }
 
+    /// Write a `bytesN` value to `dest` in big endian order. Values are kept little endian, but host
+    /// functions which take a hash or signature expect big endian bytes
+    pub(crate) fn write_be_bytes(
+        &self,
+        function: inkwell::values::FunctionValue<'a>,
+        value: IntValue<'a>,
+        dest: PointerValue<'a>,
+    ) {
+        let ty = value.get_type();
+        let src = self.build_alloca(function, ty, "value");
+
+        self.builder.build_store(src, value).unwrap();
+
+        self.builder
+            .build_call(
+                self.module.get_function("__leNtobeN").unwrap(),
+                &[
+                    src.into(),
+                    dest.into(),
+                    self.context
+                        .i32_type()
+                        .const_int(ty.get_bit_width() as u64 / 8, false)
+                        .into(),
+                ],
+                "",
+            )
+            .unwrap();
+    }
+
     /// Emit a loop from `from` to `to`. The closure exists to insert the body of the loop; the closure
     /// gets the loop variable passed to it as an IntValue, and a userdata PointerValue
     pub fn emit_static_loop_with_pointer<F>(
@@ -1283,8 +1312,8 @@ fn load_stdlib<'a>(context: &'a Context, target: &Target) -> Module<'a> {
             .unwrap();
     }
 
-    if let Target::Polkadot { .. } = *target {
-        // contracts pallet does not provide ripemd160
+    if let Target::Polkadot { .. } | Target::Soroban = *target {
+        // neither the contracts pallet nor the Soroban host provide ripemd160
         let memory = MemoryBuffer::create_from_memory_range(RIPEMD160_IR, "ripemd160");
 
         module
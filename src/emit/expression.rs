@@ -844,37 +844,10 @@ pub(super) fn expression<'a, T: TargetRuntime<'a> + ?Sized>(
         }
         Expression::Equal { left, right, .. } => {
             if left.ty().is_address() {
-                let mut res = bin.context.bool_type().const_int(1, false);
                 let left = expression(target, bin, left, vartab, function, ns).into_array_value();
                 let right = expression(target, bin, right, vartab, function, ns).into_array_value();
 
-                // TODO: Address should be passed around as pointer. Once this is done, we can replace
-                // this with a call to address_equal()
-                for index in 0..ns.address_length {
-                    let l = bin
-                        .builder
-                        .build_extract_value(left, index as u32, "left")
-                        .unwrap()
-                        .into_int_value();
-                    let r = bin
-                        .builder
-                        .build_extract_value(right, index as u32, "right")
-                        .unwrap()
-                        .into_int_value();
-
-                    res = bin
-                        .builder
-                        .build_and(
-                            res,
-                            bin.builder
-                                .build_int_compare(IntPredicate::EQ, l, r, "")
-                                .unwrap(),
-                            "cmp",
-                        )
-                        .unwrap();
-                }
-
-                res.into()
+                target.address_equal(bin, function, left, right, ns).into()
             } else {
                 let left = expression(target, bin, left, vartab, function, ns).into_int_value();
                 let right = expression(target, bin, right, vartab, function, ns).into_int_value();
@@ -887,37 +860,12 @@ pub(super) fn expression<'a, T: TargetRuntime<'a> + ?Sized>(
         }
         Expression::NotEqual { left, right, .. } => {
             if left.ty().is_address() {
-                let mut res = bin.context.bool_type().const_int(0, false);
                 let left = expression(target, bin, left, vartab, function, ns).into_array_value();
                 let right = expression(target, bin, right, vartab, function, ns).into_array_value();
 
-                // TODO: Address should be passed around as pointer. Once this is done, we can replace
-                // this with a call to address_equal()
-                for index in 0..ns.address_length {
-                    let l = bin
-                        .builder
-                        .build_extract_value(left, index as u32, "left")
-                        .unwrap()
-                        .into_int_value();
-                    let r = bin
-                        .builder
-                        .build_extract_value(right, index as u32, "right")
-                        .unwrap()
-                        .into_int_value();
-
-                    res = bin
-                        .builder
-                        .build_or(
-                            res,
-                            bin.builder
-                                .build_int_compare(IntPredicate::NE, l, r, "")
-                                .unwrap(),
-                            "cmp",
-                        )
-                        .unwrap();
-                }
+                let equal = target.address_equal(bin, function, left, right, ns);
 
-                res.into()
+                bin.builder.build_not(equal, "").unwrap().into()
             } else {
                 let left = expression(target, bin, left, vartab, function, ns).into_int_value();
                 let right = expression(target, bin, right, vartab, function, ns).into_int_value();
@@ -1360,12 +1308,21 @@ pub(super) fn expression<'a, T: TargetRuntime<'a> + ?Sized>(
             array_ty: ty,
             expr: a,
             index,
+            storage_type,
         } => {
             if ty.is_storage_bytes() {
                 let index = expression(target, bin, index, vartab, function, ns).into_int_value();
                 let slot = expression(target, bin, a, vartab, function, ns).into_int_value();
                 target
-                    .get_storage_bytes_subscript(bin, function, slot, index, *loc, ns)
+                    .get_storage_bytes_subscript(
+                        bin,
+                        function,
+                        slot,
+                        *storage_type,
+                        index,
+                        *loc,
+                        ns,
+                    )
                     .into()
             } else if ty.is_contract_storage() {
                 let array = expression(target, bin, a, vartab, function, ns).into_int_value();
@@ -1733,11 +1690,16 @@ pub(super) fn expression<'a, T: TargetRuntime<'a> + ?Sized>(
                 .unwrap()
         }
         Expression::ReturnData { .. } => target.return_data(bin, function).into(),
-        Expression::StorageArrayLength { array, elem_ty, .. } => {
+        Expression::StorageArrayLength {
+            array,
+            elem_ty,
+            storage_type,
+            ..
+        } => {
             let slot = expression(target, bin, array, vartab, function, ns).into_int_value();
 
             target
-                .storage_array_length(bin, function, slot, elem_ty, ns)
+                .storage_array_length(bin, function, slot, *storage_type, elem_ty, ns)
                 .into()
         }
         Expression::Builtin {
@@ -112,28 +112,53 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
                 .build_conditional_branch(cond.into_int_value(), bb_true, bb_false)
                 .unwrap();
         }
-        Instr::LoadStorage { res, ty, storage } => {
+        Instr::LoadStorage {
+            res,
+            ty,
+            storage,
+            storage_type,
+        } => {
             let mut slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
 
             w.vars.get_mut(res).unwrap().value =
-                target.storage_load(bin, ty, &mut slot, function, ns);
+                target.storage_load(bin, ty, &mut slot, *storage_type, function, ns);
         }
-        Instr::ClearStorage { ty, storage } => {
+        Instr::ClearStorage {
+            ty,
+            storage,
+            storage_type,
+        } => {
             let mut slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
 
-            target.storage_delete(bin, ty, &mut slot, function, ns);
+            target.storage_delete(bin, ty, &mut slot, *storage_type, function, ns);
+        }
+        Instr::TakeStorage { res, ty, storage } => {
+            let slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
+
+            w.vars.get_mut(res).unwrap().value = target.storage_take(bin, ty, slot, function, ns);
         }
-        Instr::SetStorage { ty, value, storage } => {
+        Instr::ContainsStorage { res, storage } => {
+            let slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
+
+            w.vars.get_mut(res).unwrap().value = target.storage_contains(bin, slot).into();
+        }
+        Instr::SetStorage {
+            ty,
+            value,
+            storage,
+            storage_type,
+        } => {
             let value = expression(target, bin, value, &w.vars, function, ns);
 
             let mut slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
 
-            target.storage_store(bin, ty, true, &mut slot, value, function, ns);
+            target.storage_store(bin, ty, true, &mut slot, *storage_type, value, function, ns);
         }
         Instr::SetStorageBytes {
             storage,
             value,
             offset,
+            storage_type,
         } => {
             let index_loc = offset.loc();
             let value = expression(target, bin, value, &w.vars, function, ns);
@@ -145,6 +170,7 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
                 bin,
                 function,
                 slot,
+                *storage_type,
                 offset,
                 value.into_int_value(),
                 ns,
@@ -156,6 +182,7 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
             ty,
             storage,
             value,
+            storage_type,
         } => {
             let val = value
                 .as_ref()
@@ -163,13 +190,27 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
             let slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
 
             w.vars.get_mut(res).unwrap().value =
-                target.storage_push(bin, function, ty, slot, val, ns);
+                target.storage_push(bin, function, ty, slot, *storage_type, val, ns);
         }
-        Instr::PopStorage { res, ty, storage } => {
+        Instr::PopStorage {
+            res,
+            ty,
+            storage,
+            storage_type,
+        } => {
             let loc = storage.loc();
             let slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
 
-            let value = target.storage_pop(bin, function, ty, slot, res.is_some(), ns, loc);
+            let value = target.storage_pop(
+                bin,
+                function,
+                ty,
+                slot,
+                *storage_type,
+                res.is_some(),
+                ns,
+                loc,
+            );
 
             if let Some(res) = res {
                 w.vars.get_mut(res).unwrap().value = value.unwrap();
@@ -750,6 +791,9 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
             encoded_args,
             value,
             gas,
+            proof_size,
+            storage_deposit_limit,
+            code_hash,
             salt,
             address,
             seeds,
@@ -763,9 +807,19 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
             let address_stack = bin.build_alloca(function, bin.address_type(ns), "address");
 
             let gas = expression(target, bin, gas, &w.vars, function, ns).into_int_value();
+            let proof_size = proof_size
+                .as_ref()
+                .map(|v| expression(target, bin, v, &w.vars, function, ns).into_int_value());
+            let storage_deposit_limit = storage_deposit_limit
+                .as_ref()
+                .map(|v| expression(target, bin, v, &w.vars, function, ns).into_int_value());
             let value = value
                 .as_ref()
                 .map(|v| expression(target, bin, v, &w.vars, function, ns).into_int_value());
+            let code_hash = code_hash.as_ref().map(|v| {
+                let code_hash = expression(target, bin, v, &w.vars, function, ns);
+                bin.vector_bytes(code_hash)
+            });
             let salt = salt
                 .as_ref()
                 .map(|v| expression(target, bin, v, &w.vars, function, ns).into_int_value());
@@ -859,6 +913,9 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
                     program_id: None,
                     accounts: llvm_accounts,
                     gas: Some(gas),
+                    proof_size,
+                    storage_deposit_limit,
+                    code_hash,
                     value,
                     salt,
                     seeds,
@@ -879,6 +936,8 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
             payload,
             value,
             gas,
+            proof_size,
+            storage_deposit_limit,
             callty,
             accounts,
             seeds,
@@ -935,6 +994,12 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
             let flags = flags
                 .as_ref()
                 .map(|e| expression(target, bin, e, &w.vars, function, ns).into_int_value());
+            let proof_size = proof_size
+                .as_ref()
+                .map(|e| expression(target, bin, e, &w.vars, function, ns).into_int_value());
+            let storage_deposit_limit = storage_deposit_limit
+                .as_ref()
+                .map(|e| expression(target, bin, e, &w.vars, function, ns).into_int_value());
             let success = match success {
                 Some(n) => Some(&mut w.vars.get_mut(n).unwrap().value),
                 None => None,
@@ -951,6 +1016,9 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
                     program_id: None,
                     value: Some(value),
                     gas: Some(gas),
+                    proof_size,
+                    storage_deposit_limit,
+                    code_hash: None,
                     salt: None,
                     seeds,
                     accounts,
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::codegen::Expression;
-use crate::sema::ast::{CallTy, Function, Namespace, Type};
+use crate::sema::ast::{CallTy, Function, Namespace, StorageType, Type};
 use std::collections::HashMap;
 use std::fmt;
 use std::str;
@@ -12,6 +12,7 @@ use inkwell::types::{BasicTypeEnum, IntType};
 use inkwell::values::{
     ArrayValue, BasicMetadataValueEnum, BasicValueEnum, FunctionValue, IntValue, PointerValue,
 };
+use inkwell::IntPredicate;
 use solang_parser::pt::Loc;
 
 pub mod binary;
@@ -42,6 +43,9 @@ pub struct ContractArgs<'b> {
     program_id: Option<PointerValue<'b>>,
     value: Option<IntValue<'b>>,
     gas: Option<IntValue<'b>>,
+    proof_size: Option<IntValue<'b>>,
+    storage_deposit_limit: Option<IntValue<'b>>,
+    code_hash: Option<PointerValue<'b>>,
     salt: Option<IntValue<'b>>,
     seeds: Option<(PointerValue<'b>, IntValue<'b>)>,
     accounts: Option<(PointerValue<'b>, IntValue<'b>)>,
@@ -74,7 +78,8 @@ pub trait TargetRuntime<'a> {
         &self,
         bin: &Binary<'a>,
         function: FunctionValue,
-        slot: PointerValue<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         ty: IntType<'a>,
     ) -> IntValue<'a>;
 
@@ -83,6 +88,7 @@ pub trait TargetRuntime<'a> {
         binary: &Binary<'a>,
         ty: &ast::Type,
         slot: &mut IntValue<'a>,
+        storage_type: Option<StorageType>,
         function: FunctionValue<'a>,
         ns: &ast::Namespace,
     ) -> BasicValueEnum<'a>;
@@ -94,6 +100,7 @@ pub trait TargetRuntime<'a> {
         ty: &ast::Type,
         existing: bool,
         slot: &mut IntValue<'a>,
+        storage_type: Option<StorageType>,
         dest: BasicValueEnum<'a>,
         function: FunctionValue<'a>,
         ns: &ast::Namespace,
@@ -105,16 +112,35 @@ pub trait TargetRuntime<'a> {
         bin: &Binary<'a>,
         ty: &Type,
         slot: &mut IntValue<'a>,
+        storage_type: Option<StorageType>,
         function: FunctionValue<'a>,
         ns: &Namespace,
     );
 
+    /// Load an integer value from storage and clear the slot
+    fn storage_take(
+        &self,
+        _bin: &Binary<'a>,
+        _ty: &Type,
+        _slot: IntValue<'a>,
+        _function: FunctionValue<'a>,
+        _ns: &Namespace,
+    ) -> BasicValueEnum<'a> {
+        unreachable!("storage_take is only generated by the dead storage pass on Polkadot")
+    }
+
+    /// Check whether the slot holds a value
+    fn storage_contains(&self, _bin: &Binary<'a>, _slot: IntValue<'a>) -> IntValue<'a> {
+        unreachable!("storage_contains is only used on Polkadot, see zero_clears_mapping_entry")
+    }
+
     // Bytes and string have special storage layout
     fn set_storage_string(
         &self,
         bin: &Binary<'a>,
         function: FunctionValue<'a>,
-        slot: PointerValue<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         dest: BasicValueEnum<'a>,
     );
 
@@ -122,14 +148,16 @@ pub trait TargetRuntime<'a> {
         &self,
         bin: &Binary<'a>,
         function: FunctionValue,
-        slot: PointerValue<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
     ) -> PointerValue<'a>;
 
     fn set_storage_extfunc(
         &self,
         bin: &Binary<'a>,
         function: FunctionValue,
-        slot: PointerValue,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         dest: PointerValue,
         dest_ty: BasicTypeEnum,
     );
@@ -138,7 +166,8 @@ pub trait TargetRuntime<'a> {
         &self,
         bin: &Binary<'a>,
         function: FunctionValue,
-        slot: PointerValue<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         ns: &Namespace,
     ) -> PointerValue<'a>;
 
@@ -147,6 +176,7 @@ pub trait TargetRuntime<'a> {
         bin: &Binary<'a>,
         function: FunctionValue,
         slot: IntValue<'a>,
+        storage_type: Option<StorageType>,
         index: IntValue<'a>,
         loc: Loc,
         ns: &Namespace,
@@ -157,6 +187,7 @@ pub trait TargetRuntime<'a> {
         bin: &Binary<'a>,
         function: FunctionValue,
         slot: IntValue<'a>,
+        storage_type: Option<StorageType>,
         index: IntValue<'a>,
         value: IntValue<'a>,
         ns: &Namespace,
@@ -179,6 +210,7 @@ pub trait TargetRuntime<'a> {
         function: FunctionValue<'a>,
         ty: &Type,
         slot: IntValue<'a>,
+        storage_type: Option<StorageType>,
         val: Option<BasicValueEnum<'a>>,
         ns: &Namespace,
     ) -> BasicValueEnum<'a>;
@@ -189,6 +221,7 @@ pub trait TargetRuntime<'a> {
         function: FunctionValue<'a>,
         ty: &Type,
         slot: IntValue<'a>,
+        storage_type: Option<StorageType>,
         load: bool,
         ns: &Namespace,
         loc: Loc,
@@ -199,6 +232,7 @@ pub trait TargetRuntime<'a> {
         _bin: &Binary<'a>,
         _function: FunctionValue,
         _slot: IntValue<'a>,
+        _storage_type: Option<StorageType>,
         _elem_ty: &Type,
         _ns: &Namespace,
     ) -> IntValue<'a>;
@@ -207,9 +241,9 @@ pub trait TargetRuntime<'a> {
     fn keccak256_hash(
         &self,
         bin: &Binary<'a>,
-        src: PointerValue,
-        length: IntValue,
-        dest: PointerValue,
+        src: PointerValue<'a>,
+        length: IntValue<'a>,
+        dest: PointerValue<'a>,
         ns: &Namespace,
     );
 
@@ -269,7 +303,7 @@ pub trait TargetRuntime<'a> {
     fn value_transfer<'b>(
         &self,
         _bin: &Binary<'b>,
-        _function: FunctionValue,
+        _function: FunctionValue<'b>,
         _success: Option<&mut BasicValueEnum<'b>>,
         _address: PointerValue<'b>,
         _value: IntValue<'b>,
@@ -296,6 +330,46 @@ pub trait TargetRuntime<'a> {
     /// Terminate execution, destroy bin and send remaining funds to addr
     fn selfdestruct<'b>(&self, binary: &Binary<'b>, addr: ArrayValue<'b>, ns: &Namespace);
 
+    /// Check whether two addresses are equal, by comparing them byte by byte
+    fn address_equal(
+        &self,
+        bin: &Binary<'a>,
+        _function: FunctionValue<'a>,
+        left: ArrayValue<'a>,
+        right: ArrayValue<'a>,
+        ns: &Namespace,
+    ) -> IntValue<'a> {
+        let mut res = bin.context.bool_type().const_int(1, false);
+
+        // TODO: Address should be passed around as pointer. Once this is done, we can replace
+        // this with a call to address_equal()
+        for index in 0..ns.address_length {
+            let l = bin
+                .builder
+                .build_extract_value(left, index as u32, "left")
+                .unwrap()
+                .into_int_value();
+            let r = bin
+                .builder
+                .build_extract_value(right, index as u32, "right")
+                .unwrap()
+                .into_int_value();
+
+            res = bin
+                .builder
+                .build_and(
+                    res,
+                    bin.builder
+                        .build_int_compare(IntPredicate::EQ, l, r, "")
+                        .unwrap(),
+                    "cmp",
+                )
+                .unwrap();
+        }
+
+        res
+    }
+
     /// Crypto Hash
     fn hash<'b>(
         &self,
@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::emit::binary::Binary;
+use crate::emit::polkadot::PolkadotTarget;
+use crate::emit_context;
+use inkwell::values::{BasicValueEnum, FunctionValue, IntValue, PointerValue};
+use inkwell::IntPredicate;
+
+/// Length of a compressed secp256k1 public key
+const ECDSA_PUBLIC_KEY_LEN: u64 = 33;
+
+/// Length of an sr25519 signature
+const SR25519_SIGNATURE_LEN: u64 = 64;
+
+impl PolkadotTarget {
+    /// Verify an sr25519 signature with `sr25519_verify`. Signatures which are not 64 bytes
+    /// long are never valid.
+    pub(super) fn sr25519_verify<'a>(
+        &self,
+        bin: &Binary<'a>,
+        function: FunctionValue<'a>,
+        public_key: IntValue<'a>,
+        message: BasicValueEnum<'a>,
+        signature: BasicValueEnum<'a>,
+    ) -> IntValue<'a> {
+        emit_context!(bin);
+
+        let key = bin.build_array_alloca(function, bin.context.i8_type(), i32_const!(32), "key");
+
+        bin.write_be_bytes(function, public_key, key);
+
+        let entry = bin.builder.get_insert_block().unwrap();
+        let verify = bin.context.append_basic_block(function, "sr25519_verify");
+        let done = bin.context.append_basic_block(function, "sr25519_verified");
+
+        let signature_len = bin.vector_len(signature);
+
+        let is_signature = bin
+            .builder
+            .build_int_compare(
+                IntPredicate::EQ,
+                signature_len,
+                i32_const!(SR25519_SIGNATURE_LEN),
+                "is_signature",
+            )
+            .unwrap();
+
+        bin.builder
+            .build_conditional_branch(is_signature, verify, done)
+            .unwrap();
+
+        bin.builder.position_at_end(verify);
+
+        let ret = call!(
+            "sr25519_verify",
+            &[
+                bin.vector_bytes(signature).into(),
+                key.into(),
+                bin.vector_len(message).into(),
+                bin.vector_bytes(message).into(),
+            ],
+            "seal_sr25519_verify"
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        let verified = bin
+            .builder
+            .build_int_compare(IntPredicate::EQ, ret, i32_zero!(), "verified")
+            .unwrap();
+
+        bin.builder.build_unconditional_branch(done).unwrap();
+
+        bin.builder.position_at_end(done);
+
+        let bool_type = bin.context.bool_type();
+        let res = bin.builder.build_phi(bool_type, "sr25519_verify").unwrap();
+
+        res.add_incoming(&[(&bool_type.const_zero(), entry), (&verified, verify)]);
+
+        res.as_basic_value().into_int_value()
+    }
+
+    /// Recover the public key which signed `hash` with `ecdsa_recover`, and return the Ethereum
+    /// address for it. If the signature is not valid, the address is zero.
+    pub(super) fn ecrecover<'a>(
+        &self,
+        bin: &Binary<'a>,
+        function: FunctionValue<'a>,
+        hash: IntValue<'a>,
+        v: IntValue<'a>,
+        r: IntValue<'a>,
+        s: IntValue<'a>,
+    ) -> IntValue<'a> {
+        emit_context!(bin);
+
+        let i8_type = bin.context.i8_type();
+
+        let message_hash =
+            bin.build_array_alloca(function, i8_type, i32_const!(32), "message_hash");
+
+        bin.write_be_bytes(function, hash, message_hash);
+
+        // the signature is r, s and then v, which the host accepts as either 27/28 or 0/1
+        let signature = bin.build_array_alloca(function, i8_type, i32_const!(65), "signature");
+
+        bin.write_be_bytes(function, r, signature);
+
+        let signature_s = unsafe {
+            bin.builder
+                .build_gep(i8_type, signature, &[i32_const!(32)], "signature_s")
+                .unwrap()
+        };
+
+        bin.write_be_bytes(function, s, signature_s);
+
+        let signature_v = unsafe {
+            bin.builder
+                .build_gep(i8_type, signature, &[i32_const!(64)], "signature_v")
+                .unwrap()
+        };
+
+        bin.builder.build_store(signature_v, v).unwrap();
+
+        // if recovery fails, the zeroed key is not valid either
+        let public_key_ty = i8_type.array_type(ECDSA_PUBLIC_KEY_LEN as u32);
+        let public_key = bin.build_alloca(function, public_key_ty, "public_key");
+
+        bin.builder
+            .build_store(public_key, public_key_ty.const_zero())
+            .unwrap();
+
+        call!(
+            "ecdsa_recover",
+            &[signature.into(), message_hash.into(), public_key.into()],
+            "seal_ecdsa_recover"
+        );
+
+        self.ecdsa_to_eth_address(bin, function, public_key)
+    }
+
+    /// Convert a compressed ECDSA public key to an Ethereum address with `ecdsa_to_eth_address`.
+    /// If the key is not valid, the address is zero.
+    pub(super) fn ecdsa_to_eth_address<'a>(
+        &self,
+        bin: &Binary<'a>,
+        function: FunctionValue<'a>,
+        public_key: PointerValue<'a>,
+    ) -> IntValue<'a> {
+        emit_context!(bin);
+
+        let address_ty = bin.context.i8_type().array_type(20);
+        let address = bin.build_alloca(function, address_ty, "eth_address");
+
+        bin.builder
+            .build_store(address, address_ty.const_zero())
+            .unwrap();
+
+        call!(
+            "ecdsa_to_eth_address",
+            &[public_key.into(), address.into()],
+            "seal_ecdsa_to_eth_address"
+        );
+
+        // bytesN values are stored little endian
+        let ty = bin.context.custom_width_int_type(160);
+        let res = bin.build_alloca(function, ty, "res");
+
+        call!(
+            "__beNtoleN",
+            &[address.into(), res.into(), i32_const!(20).into()]
+        );
+
+        bin.builder
+            .build_load(ty, res, "eth_address")
+            .unwrap()
+            .into_int_value()
+    }
+}
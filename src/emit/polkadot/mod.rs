@@ -14,6 +14,7 @@ use crate::codegen::dispatch::polkadot::DispatchType;
 use crate::emit::functions::emit_functions;
 use crate::emit::{Binary, TargetRuntime};
 
+mod crypto;
 mod storage;
 pub(super) mod target;
 
@@ -93,6 +94,8 @@ impl PolkadotTarget {
             "set_storage",
             "get_storage",
             "clear_storage",
+            "take_storage",
+            "contains_storage",
             "hash_keccak_256",
             "hash_sha2_256",
             "hash_blake2_128",
@@ -118,7 +121,16 @@ impl PolkadotTarget {
             "transfer",
             "is_contract",
             "set_code_hash",
+            "lock_delegate_dependency",
+            "unlock_delegate_dependency",
             "caller_is_root",
+            "reentrance_count",
+            "account_reentrance_count",
+            "sr25519_verify",
+            "ecdsa_recover",
+            "ecdsa_to_eth_address",
+            "xcm_execute",
+            "xcm_send",
         ]);
 
         binary
@@ -222,12 +234,16 @@ impl PolkadotTarget {
         external!("debug_message", i32_type, u8_ptr, u32_val);
         external!("clear_storage", i32_type, u8_ptr, u32_val);
         external!("get_storage", i32_type, u8_ptr, u32_val, u8_ptr, u32_ptr);
+        external!("take_storage", i32_type, u8_ptr, u32_val, u8_ptr, u32_ptr);
+        external!("contains_storage", i32_type, u8_ptr, u32_val);
         external!("seal_return", void_type, u32_val, u8_ptr, u32_val);
         external!(
             "instantiate",
             i32_type,
             u8_ptr,
             u64_val,
+            u64_val,
+            u8_ptr,
             u8_ptr,
             u8_ptr,
             u32_val,
@@ -246,6 +262,8 @@ impl PolkadotTarget {
             u32_val,
             u8_ptr,
             u64_val,
+            u64_val,
+            u8_ptr,
             u8_ptr,
             u8_ptr,
             u32_val,
@@ -277,7 +295,42 @@ impl PolkadotTarget {
         external!("deposit_event", void_type, u8_ptr, u32_val, u8_ptr, u32_val);
         external!("is_contract", i32_type, u8_ptr);
         external!("set_code_hash", i32_type, u8_ptr);
+        external!("lock_delegate_dependency", void_type, u8_ptr);
+        external!("unlock_delegate_dependency", void_type, u8_ptr);
         external!("caller_is_root", i32_type,);
+        external!("reentrance_count", i32_type,);
+        external!("account_reentrance_count", i32_type, u8_ptr);
+        external!("sr25519_verify", i32_type, u8_ptr, u8_ptr, u32_val, u8_ptr);
+        external!("ecdsa_recover", i32_type, u8_ptr, u8_ptr, u8_ptr);
+        external!("ecdsa_to_eth_address", i32_type, u8_ptr, u8_ptr);
+        external!("xcm_execute", i32_type, u8_ptr, u32_val);
+        external!("xcm_send", i32_type, u8_ptr, u8_ptr, u32_val, u8_ptr);
+    }
+
+    /// The storage deposit limit for `seal_call` and `instantiate` is passed by pointer. Without
+    /// a limit, the pointer is `u32::MAX`, which the host takes as no limit.
+    fn storage_deposit_limit<'a>(
+        &self,
+        binary: &Binary<'a>,
+        function: FunctionValue<'a>,
+        limit: Option<IntValue<'a>>,
+        ns: &Namespace,
+    ) -> PointerValue<'a> {
+        match limit {
+            Some(limit) => {
+                let limit_ptr =
+                    binary.build_alloca(function, binary.value_type(ns), "storage_deposit_limit");
+
+                binary.builder.build_store(limit_ptr, limit).unwrap();
+
+                limit_ptr
+            }
+            None => binary
+                .context
+                .i32_type()
+                .const_all_ones()
+                .const_to_pointer(binary.context.i8_type().ptr_type(AddressSpace::default())),
+        }
     }
 
     /// Emits the "deploy" function if `storage_initializer` is `Some`, otherwise emits the "call" function.
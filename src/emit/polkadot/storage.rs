@@ -13,10 +13,11 @@ use num_bigint::BigInt;
 use num_traits::{One, ToPrimitive};
 
 impl StorageSlot for PolkadotTarget {
-    fn set_storage(
+    fn set_storage<'a>(
         &self,
-        binary: &Binary,
-        slot: PointerValue,
+        binary: &Binary<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         dest: PointerValue,
         dest_ty: BasicTypeEnum,
     ) {
@@ -35,18 +36,14 @@ impl StorageSlot for PolkadotTarget {
                 .const_cast(binary.context.i32_type(), false)
         };
 
-        seal_set_storage!(
-            slot.into(),
-            i32_const!(32).into(),
-            dest.into(),
-            dest_size.into()
-        );
+        seal_set_storage!(key.into(), key_len.into(), dest.into(), dest_size.into());
     }
 
     fn get_storage_address<'a>(
         &self,
         binary: &Binary<'a>,
-        slot: PointerValue<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         ns: &Namespace,
     ) -> ArrayValue<'a> {
         emit_context!(binary);
@@ -59,8 +56,8 @@ impl StorageSlot for PolkadotTarget {
             .unwrap();
 
         let exists = seal_get_storage!(
-            slot.into(),
-            i32_const!(32).into(),
+            key.into(),
+            key_len.into(),
             scratch_buf.into(),
             scratch_len.into()
         );
@@ -86,10 +83,15 @@ impl StorageSlot for PolkadotTarget {
             .into_array_value()
     }
 
-    fn storage_delete_single_slot(&self, binary: &Binary, slot: PointerValue) {
+    fn storage_delete_single_slot<'a>(
+        &self,
+        binary: &Binary<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
+    ) {
         emit_context!(binary);
 
-        call!("clear_storage", &[slot.into(), i32_const!(32).into()])
+        call!("clear_storage", &[key.into(), key_len.into()])
             .try_as_basic_value()
             .left()
             .unwrap()
@@ -294,11 +296,17 @@ impl StorageSlot for PolkadotTarget {
             Type::String | Type::DynamicBytes => {
                 bin.builder.build_store(slot_ptr, *slot).unwrap();
 
-                let ret = self.get_storage_string(bin, function, slot_ptr);
+                let (key, key_len) = self.storage_key(bin, *slot, slot_ptr);
+
+                let ret = self.get_storage_string(bin, function, key, key_len);
 
                 *slot = bin
                     .builder
-                    .build_int_add(*slot, bin.number_literal(256, &BigInt::one(), ns), "string")
+                    .build_int_add(
+                        *slot,
+                        bin.number_literal(slot.get_type().get_bit_width(), &BigInt::one(), ns),
+                        "string",
+                    )
                     .unwrap();
 
                 ret.into()
@@ -306,11 +314,13 @@ impl StorageSlot for PolkadotTarget {
             Type::InternalFunction { .. } => {
                 bin.builder.build_store(slot_ptr, *slot).unwrap();
 
+                let (key, key_len) = self.storage_key(bin, *slot, slot_ptr);
+
                 let ptr_ty = bin
                     .context
                     .custom_width_int_type(ns.target.ptr_size() as u32);
 
-                let ret = self.get_storage_int(bin, function, slot_ptr, ptr_ty);
+                let ret = self.get_storage_int(bin, function, key, key_len, ptr_ty);
 
                 bin.builder
                     .build_int_to_ptr(
@@ -325,11 +335,17 @@ impl StorageSlot for PolkadotTarget {
             Type::ExternalFunction { .. } => {
                 bin.builder.build_store(slot_ptr, *slot).unwrap();
 
-                let ret = self.get_storage_extfunc(bin, function, slot_ptr, ns);
+                let (key, key_len) = self.storage_key(bin, *slot, slot_ptr);
+
+                let ret = self.get_storage_extfunc(bin, function, key, key_len, ns);
 
                 *slot = bin
                     .builder
-                    .build_int_add(*slot, bin.number_literal(256, &BigInt::one(), ns), "string")
+                    .build_int_add(
+                        *slot,
+                        bin.number_literal(slot.get_type().get_bit_width(), &BigInt::one(), ns),
+                        "string",
+                    )
                     .unwrap();
 
                 ret.into()
@@ -337,11 +353,17 @@ impl StorageSlot for PolkadotTarget {
             Type::Address(_) | Type::Contract(_) => {
                 bin.builder.build_store(slot_ptr, *slot).unwrap();
 
-                let ret = self.get_storage_address(bin, slot_ptr, ns);
+                let (key, key_len) = self.storage_key(bin, *slot, slot_ptr);
+
+                let ret = self.get_storage_address(bin, key, key_len, ns);
 
                 *slot = bin
                     .builder
-                    .build_int_add(*slot, bin.number_literal(256, &BigInt::one(), ns), "string")
+                    .build_int_add(
+                        *slot,
+                        bin.number_literal(slot.get_type().get_bit_width(), &BigInt::one(), ns),
+                        "string",
+                    )
                     .unwrap();
 
                 ret.into()
@@ -349,16 +371,23 @@ impl StorageSlot for PolkadotTarget {
             _ => {
                 bin.builder.build_store(slot_ptr, *slot).unwrap();
 
+                let (key, key_len) = self.storage_key(bin, *slot, slot_ptr);
+
                 let ret = self.get_storage_int(
                     bin,
                     function,
-                    slot_ptr,
+                    key,
+                    key_len,
                     bin.llvm_type(ty.deref_any(), ns).into_int_type(),
                 );
 
                 *slot = bin
                     .builder
-                    .build_int_add(*slot, bin.number_literal(256, &BigInt::one(), ns), "int")
+                    .build_int_add(
+                        *slot,
+                        bin.number_literal(slot.get_type().get_bit_width(), &BigInt::one(), ns),
+                        "int",
+                    )
                     .unwrap();
 
                 ret.into()
@@ -478,7 +507,9 @@ impl StorageSlot for PolkadotTarget {
                         )
                         .unwrap();
 
-                    self.set_storage(bin, slot_ptr, new_slot, bin.llvm_type(&slot_ty, ns));
+                    let (key, key_len) = self.storage_key(bin, *slot, slot_ptr);
+
+                    self.set_storage(bin, key, key_len, new_slot, bin.llvm_type(&slot_ty, ns));
 
                     self.keccak256_hash(
                         bin,
@@ -634,15 +665,20 @@ impl StorageSlot for PolkadotTarget {
             Type::String | Type::DynamicBytes => {
                 bin.builder.build_store(slot_ptr, *slot).unwrap();
 
-                self.set_storage_string(bin, function, slot_ptr, dest);
+                let (key, key_len) = self.storage_key(bin, *slot, slot_ptr);
+
+                self.set_storage_string(bin, function, key, key_len, dest);
             }
             Type::ExternalFunction { .. } => {
                 bin.builder.build_store(slot_ptr, *slot).unwrap();
 
+                let (key, key_len) = self.storage_key(bin, *slot, slot_ptr);
+
                 self.set_storage_extfunc(
                     bin,
                     function,
-                    slot_ptr,
+                    key,
+                    key_len,
                     dest.into_pointer_value(),
                     bin.llvm_type(ty, ns),
                 );
@@ -665,15 +701,20 @@ impl StorageSlot for PolkadotTarget {
 
                 bin.builder.build_store(slot_ptr, *slot).unwrap();
 
-                self.set_storage(bin, slot_ptr, m, ptr_ty.as_basic_type_enum());
+                let (key, key_len) = self.storage_key(bin, *slot, slot_ptr);
+
+                self.set_storage(bin, key, key_len, m, ptr_ty.as_basic_type_enum());
             }
             Type::Address(_) | Type::Contract(_) => {
                 if dest.is_pointer_value() {
                     bin.builder.build_store(slot_ptr, *slot).unwrap();
 
+                    let (key, key_len) = self.storage_key(bin, *slot, slot_ptr);
+
                     self.set_storage(
                         bin,
-                        slot_ptr,
+                        key,
+                        key_len,
                         dest.into_pointer_value(),
                         bin.llvm_type(ty, ns),
                     );
@@ -689,9 +730,12 @@ impl StorageSlot for PolkadotTarget {
 
                     bin.builder.build_store(slot_ptr, *slot).unwrap();
 
+                    let (key, key_len) = self.storage_key(bin, *slot, slot_ptr);
+
                     self.set_storage(
                         bin,
-                        slot_ptr,
+                        key,
+                        key_len,
                         address,
                         bin.address_type(ns).as_basic_type_enum(),
                     );
@@ -700,6 +744,8 @@ impl StorageSlot for PolkadotTarget {
             _ => {
                 bin.builder.build_store(slot_ptr, *slot).unwrap();
 
+                let (key, key_len) = self.storage_key(bin, *slot, slot_ptr);
+
                 let dest = if dest.is_int_value() {
                     let m = bin.build_alloca(function, dest.get_type(), "");
                     bin.builder.build_store(m, dest).unwrap();
@@ -712,7 +758,7 @@ impl StorageSlot for PolkadotTarget {
                 // TODO ewasm allocates 32 bytes here, even though we have just
                 // allocated test. This can be folded into one allocation, if llvm
                 // does not already fold it into one.
-                self.set_storage(bin, slot_ptr, dest, bin.llvm_type(ty.deref_any(), ns));
+                self.set_storage(bin, key, key_len, dest, bin.llvm_type(ty.deref_any(), ns));
             }
         }
     }
@@ -760,7 +806,9 @@ impl StorageSlot for PolkadotTarget {
 
                     let buf = bin.builder.build_alloca(slot_ty, "buf").unwrap();
 
-                    let length = self.get_storage_int(bin, function, slot_ptr, slot_ty);
+                    let (key, key_len) = self.storage_key(bin, *slot, slot_ptr);
+
+                    let length = self.get_storage_int(bin, function, key, key_len, slot_ty);
 
                     // we need to hash the length slot in order to get the slot of the first
                     // entry of the array
@@ -830,8 +878,39 @@ impl StorageSlot for PolkadotTarget {
             _ => {
                 bin.builder.build_store(slot_ptr, *slot).unwrap();
 
-                self.storage_delete_single_slot(bin, slot_ptr);
+                let (key, key_len) = self.storage_key(bin, *slot, slot_ptr);
+
+                self.storage_delete_single_slot(bin, key, key_len);
             }
         }
     }
 }
+
+impl PolkadotTarget {
+    /// Get the storage key for `slot`, and its length. This is the 32 byte slot itself, which
+    /// `slot_ptr` points to. With ink! storage keys, the slot of a mapping entry is pointer sized
+    /// instead, and points to its encoded storage key; see [`Builtin::StorageKey`].
+    pub(super) fn storage_key<'a>(
+        &self,
+        bin: &Binary<'a>,
+        slot: IntValue<'a>,
+        slot_ptr: PointerValue<'a>,
+    ) -> (PointerValue<'a>, IntValue<'a>) {
+        emit_context!(bin);
+
+        if slot.get_type().get_bit_width() == 256 {
+            return (slot_ptr, i32_const!(32));
+        }
+
+        let key = bin
+            .builder
+            .build_int_to_ptr(
+                slot,
+                bin.context.i8_type().ptr_type(AddressSpace::default()),
+                "storage_key",
+            )
+            .unwrap();
+
+        (bin.vector_bytes(key.into()), bin.vector_len(key.into()))
+    }
+}
@@ -9,7 +9,7 @@ use crate::emit::polkadot::PolkadotTarget;
 use crate::emit::storage::StorageSlot;
 use crate::emit::{ContractArgs, TargetRuntime, Variable};
 use crate::sema::ast;
-use crate::sema::ast::{Function, Namespace, Type};
+use crate::sema::ast::{Function, Namespace, StorageType, Type};
 use crate::{codegen, emit_context};
 use inkwell::types::{BasicType, BasicTypeEnum, IntType};
 use inkwell::values::BasicValue;
@@ -23,17 +23,18 @@ use std::collections::HashMap;
 impl<'a> TargetRuntime<'a> for PolkadotTarget {
     fn set_storage_extfunc(
         &self,
-        binary: &Binary,
+        binary: &Binary<'a>,
         _function: FunctionValue,
-        slot: PointerValue,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         dest: PointerValue,
         dest_ty: BasicTypeEnum,
     ) {
         emit_context!(binary);
 
         seal_set_storage!(
-            slot.into(),
-            i32_const!(32).into(),
+            key.into(),
+            key_len.into(),
             dest.into(),
             dest_ty
                 .size_of()
@@ -47,7 +48,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         &self,
         binary: &Binary<'a>,
         _function: FunctionValue,
-        slot: PointerValue<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         ns: &ast::Namespace,
     ) -> PointerValue<'a> {
         emit_context!(binary);
@@ -79,12 +81,7 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
 
         call!(
             "get_storage",
-            &[
-                slot.into(),
-                i32_const!(32).into(),
-                ef.into(),
-                scratch_len.into()
-            ]
+            &[key.into(), key_len.into(), ef.into(), scratch_len.into()]
         )
         .try_as_basic_value()
         .left()
@@ -100,7 +97,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         &self,
         binary: &Binary<'a>,
         function: FunctionValue<'a>,
-        slot: PointerValue<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         dest: BasicValueEnum<'a>,
     ) {
         emit_context!(binary);
@@ -126,7 +124,7 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
 
         binary.builder.position_at_end(set_block);
 
-        seal_set_storage!(slot.into(), i32_const!(32).into(), data.into(), len.into());
+        seal_set_storage!(key.into(), key_len.into(), data.into(), len.into());
 
         binary
             .builder
@@ -135,7 +133,7 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
 
         binary.builder.position_at_end(delete_block);
 
-        call!("clear_storage", &[slot.into(), i32_const!(32).into()])
+        call!("clear_storage", &[key.into(), key_len.into()])
             .try_as_basic_value()
             .left()
             .unwrap()
@@ -154,7 +152,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         &self,
         binary: &Binary<'a>,
         function: FunctionValue,
-        slot: PointerValue<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         ty: IntType<'a>,
     ) -> IntValue<'a> {
         emit_context!(binary);
@@ -164,8 +163,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         binary.builder.build_store(scratch_len, ty_len).unwrap();
 
         let exists = seal_get_storage!(
-            slot.into(),
-            i32_const!(32).into(),
+            key.into(),
+            key_len.into(),
             scratch_buf.into(),
             scratch_len.into()
         );
@@ -210,7 +209,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         &self,
         binary: &Binary<'a>,
         function: FunctionValue,
-        slot: PointerValue<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
     ) -> PointerValue<'a> {
         emit_context!(binary);
 
@@ -222,8 +222,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
             .unwrap();
 
         let exists = seal_get_storage!(
-            slot.into(),
-            i32_const!(32).into(),
+            key.into(),
+            key_len.into(),
             scratch_buf.into(),
             scratch_len.into()
         );
@@ -299,6 +299,7 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         binary: &Binary<'a>,
         function: FunctionValue,
         slot: IntValue<'a>,
+        _storage_type: Option<StorageType>,
         index: IntValue<'a>,
         loc: Loc,
         ns: &Namespace,
@@ -311,6 +312,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
             .unwrap();
         binary.builder.build_store(slot_ptr, slot).unwrap();
 
+        let (key, key_len) = self.storage_key(binary, slot, slot_ptr);
+
         let (scratch_buf, scratch_len) = scratch_buf!();
 
         binary
@@ -319,8 +322,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
             .unwrap();
 
         let exists = seal_get_storage!(
-            slot_ptr.into(),
-            i32_const!(32).into(),
+            key.into(),
+            key_len.into(),
             scratch_buf.into(),
             scratch_len.into()
         );
@@ -392,11 +395,12 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
 
     fn set_storage_bytes_subscript(
         &self,
-        binary: &Binary,
+        binary: &Binary<'a>,
         function: FunctionValue,
-        slot: IntValue,
-        index: IntValue,
-        val: IntValue,
+        slot: IntValue<'a>,
+        _storage_type: Option<StorageType>,
+        index: IntValue<'a>,
+        val: IntValue<'a>,
         ns: &Namespace,
         loc: Loc,
     ) {
@@ -408,6 +412,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
             .unwrap();
         binary.builder.build_store(slot_ptr, slot).unwrap();
 
+        let (key, key_len) = self.storage_key(binary, slot, slot_ptr);
+
         let (scratch_buf, scratch_len) = scratch_buf!();
 
         binary
@@ -416,8 +422,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
             .unwrap();
 
         let exists = seal_get_storage!(
-            slot_ptr.into(),
-            i32_const!(32).into(),
+            key.into(),
+            key_len.into(),
             scratch_buf.into(),
             scratch_len.into()
         );
@@ -483,8 +489,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         binary.builder.build_store(offset, val).unwrap();
 
         seal_set_storage!(
-            slot_ptr.into(),
-            i32_const!(32).into(),
+            key.into(),
+            key_len.into(),
             scratch_buf.into(),
             length.into()
         );
@@ -497,6 +503,7 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         _function: FunctionValue,
         _ty: &ast::Type,
         slot: IntValue<'a>,
+        _storage_type: Option<StorageType>,
         val: Option<BasicValueEnum<'a>>,
         _ns: &ast::Namespace,
     ) -> BasicValueEnum<'a> {
@@ -510,6 +517,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
             .unwrap();
         binary.builder.build_store(slot_ptr, slot).unwrap();
 
+        let (key, key_len) = self.storage_key(binary, slot, slot_ptr);
+
         let (scratch_buf, scratch_len) = scratch_buf!();
 
         // Since we are going to add one byte, we set the buffer length to one less. This will
@@ -520,8 +529,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
             .unwrap();
 
         let exists = seal_get_storage!(
-            slot_ptr.into(),
-            i32_const!(32).into(),
+            key.into(),
+            key_len.into(),
             scratch_buf.into(),
             scratch_len.into()
         );
@@ -567,8 +576,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
             .unwrap();
 
         seal_set_storage!(
-            slot_ptr.into(),
-            i32_const!(32).into(),
+            key.into(),
+            key_len.into(),
             scratch_buf.into(),
             length.into()
         );
@@ -583,6 +592,7 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         function: FunctionValue<'a>,
         ty: &ast::Type,
         slot: IntValue<'a>,
+        _storage_type: Option<StorageType>,
         load: bool,
         ns: &ast::Namespace,
         loc: Loc,
@@ -595,6 +605,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
             .unwrap();
         binary.builder.build_store(slot_ptr, slot).unwrap();
 
+        let (key, key_len) = self.storage_key(binary, slot, slot_ptr);
+
         let (scratch_buf, scratch_len) = scratch_buf!();
 
         binary
@@ -603,8 +615,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
             .unwrap();
 
         let exists = seal_get_storage!(
-            slot_ptr.into(),
-            i32_const!(32).into(),
+            key.into(),
+            key_len.into(),
             scratch_buf.into(),
             scratch_len.into()
         );
@@ -684,8 +696,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         };
 
         seal_set_storage!(
-            slot_ptr.into(),
-            i32_const!(32).into(),
+            key.into(),
+            key_len.into(),
             scratch_buf.into(),
             new_length.into()
         );
@@ -699,6 +711,7 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         binary: &Binary<'a>,
         _function: FunctionValue,
         slot: IntValue<'a>,
+        _storage_type: Option<StorageType>,
         _ty: &ast::Type,
         _ns: &ast::Namespace,
     ) -> IntValue<'a> {
@@ -710,6 +723,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
             .unwrap();
         binary.builder.build_store(slot_ptr, slot).unwrap();
 
+        let (key, key_len) = self.storage_key(binary, slot, slot_ptr);
+
         let (scratch_buf, scratch_len) = scratch_buf!();
 
         binary
@@ -718,8 +733,8 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
             .unwrap();
 
         let exists = seal_get_storage!(
-            slot_ptr.into(),
-            i32_const!(32).into(),
+            key.into(),
+            key_len.into(),
             scratch_buf.into(),
             scratch_len.into()
         );
@@ -839,8 +854,6 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
 
         let created_contract = &ns.contracts[contract_no];
 
-        let code = created_contract.emit(ns, binary.options, contract_no);
-
         let (scratch_buf, scratch_len) = scratch_buf!();
 
         // salt
@@ -875,11 +888,15 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         binary.builder.build_store(value_ptr, value).unwrap();
 
         // code hash
-        let codehash = binary.emit_global_string(
-            &format!("binary_{}_codehash", created_contract.id),
-            blake2_rfc::blake2b::blake2b(32, &[], &code).as_bytes(),
-            true,
-        );
+        let codehash = contract_args.code_hash.unwrap_or_else(|| {
+            let code = created_contract.emit(ns, binary.options, contract_no);
+
+            binary.emit_global_string(
+                &format!("binary_{}_codehash", created_contract.id),
+                blake2_rfc::blake2b::blake2b(32, &[], &code).as_bytes(),
+                true,
+            )
+        });
 
         let address_len_ptr = binary
             .builder
@@ -896,11 +913,19 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
             .build_store(scratch_len, i32_const!(SCRATCH_SIZE as u64 * 32))
             .unwrap();
 
+        let proof_size = contract_args
+            .proof_size
+            .unwrap_or_else(|| binary.context.i64_type().const_zero());
+        let deposit_limit_ptr =
+            self.storage_deposit_limit(binary, function, contract_args.storage_deposit_limit, ns);
+
         *success.unwrap() = call!(
             "instantiate",
             &[
                 codehash.into(),
                 contract_args.gas.unwrap().into(),
+                proof_size.into(),
+                deposit_limit_ptr.into(),
                 value_ptr.into(),
                 encoded_args.into(),
                 encoded_args_len.into(),
@@ -952,12 +977,23 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
                     .builder
                     .build_store(value_ptr, contract_args.value.unwrap())
                     .unwrap();
+                let proof_size = contract_args
+                    .proof_size
+                    .unwrap_or_else(|| binary.context.i64_type().const_zero());
+                let deposit_limit_ptr = self.storage_deposit_limit(
+                    binary,
+                    function,
+                    contract_args.storage_deposit_limit,
+                    ns,
+                );
                 call!(
                     "seal_call",
                     &[
                         contract_args.flags.unwrap_or(i32_zero!()).into(),
                         address.unwrap().into(),
                         contract_args.gas.unwrap().into(),
+                        proof_size.into(),
+                        deposit_limit_ptr.into(),
                         value_ptr.into(),
                         payload.into(),
                         payload_len.into(),
@@ -1065,7 +1101,7 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
     fn value_transfer<'b>(
         &self,
         binary: &Binary<'b>,
-        _function: FunctionValue,
+        _function: FunctionValue<'b>,
         success: Option<&mut BasicValueEnum<'b>>,
         address: PointerValue<'b>,
         value: IntValue<'b>,
@@ -1519,6 +1555,13 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
                     ns.value_length as u32 * 8
                 )
             }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::ReentranceCount,
+                ..
+            } => call!("reentrance_count", &[], "seal_reentrance_count")
+                .try_as_basic_value()
+                .left()
+                .unwrap(),
             codegen::Expression::Builtin {
                 kind: codegen::Builtin::GetAddress,
                 ..
@@ -1563,6 +1606,91 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
                     .build_load(binary.value_type(ns), scratch_buf, "balance")
                     .unwrap()
             }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::SignatureVerify,
+                args,
+                ..
+            } => {
+                let public_key =
+                    expression(self, binary, &args[0], vartab, function, ns).into_int_value();
+                let message = expression(self, binary, &args[1], vartab, function, ns);
+                let signature = expression(self, binary, &args[2], vartab, function, ns);
+
+                self.sr25519_verify(binary, function, public_key, message, signature)
+                    .into()
+            }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::ECRecover,
+                args,
+                ..
+            } => {
+                let args = args
+                    .iter()
+                    .map(|arg| expression(self, binary, arg, vartab, function, ns).into_int_value())
+                    .collect::<Vec<_>>();
+
+                self.ecrecover(binary, function, args[0], args[1], args[2], args[3])
+                    .into()
+            }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::XcmExecute,
+                args,
+                ..
+            } => {
+                let msg = expression(self, binary, &args[0], vartab, function, ns);
+
+                call!(
+                    "xcm_execute",
+                    &[
+                        binary.vector_bytes(msg).into(),
+                        binary.vector_len(msg).into()
+                    ],
+                    "seal_xcm_execute"
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+            }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::XcmSend,
+                args,
+                ..
+            } => {
+                let dest = expression(self, binary, &args[0], vartab, function, ns);
+                let msg = expression(self, binary, &args[1], vartab, function, ns);
+                let hash = expression(self, binary, &args[2], vartab, function, ns);
+
+                call!(
+                    "xcm_send",
+                    &[
+                        binary.vector_bytes(dest).into(),
+                        binary.vector_bytes(msg).into(),
+                        binary.vector_len(msg).into(),
+                        binary.vector_bytes(hash).into()
+                    ],
+                    "seal_xcm_send"
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+            }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::StorageKey,
+                args,
+                ..
+            } => {
+                let key = expression(self, binary, &args[0], vartab, function, ns);
+
+                binary
+                    .builder
+                    .build_ptr_to_int(
+                        key.into_pointer_value(),
+                        binary.context.i32_type(),
+                        "storage_key",
+                    )
+                    .unwrap()
+                    .into()
+            }
             _ => unreachable!("{:?}", expr),
         }
     }
@@ -1572,6 +1700,7 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         binary: &Binary<'a>,
         ty: &Type,
         slot: &mut IntValue<'a>,
+        _storage_type: Option<StorageType>,
         function: FunctionValue,
         ns: &Namespace,
     ) -> BasicValueEnum<'a> {
@@ -1591,6 +1720,7 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         ty: &Type,
         _existing: bool,
         slot: &mut IntValue<'a>,
+        _storage_type: Option<StorageType>,
         dest: BasicValueEnum<'a>,
         function: FunctionValue<'a>,
         ns: &Namespace,
@@ -1608,6 +1738,7 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         bin: &Binary<'a>,
         ty: &Type,
         slot: &mut IntValue<'a>,
+        _storage_type: Option<StorageType>,
         function: FunctionValue<'a>,
         ns: &Namespace,
     ) {
@@ -1616,10 +1747,106 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         self.storage_delete_slot(bin, ty, slot, slot_ptr, function, ns);
     }
 
+    /// Read an integer from contract storage with `take_storage`, which also clears the slot
+    fn storage_take(
+        &self,
+        bin: &Binary<'a>,
+        ty: &Type,
+        slot: IntValue<'a>,
+        function: FunctionValue<'a>,
+        ns: &Namespace,
+    ) -> BasicValueEnum<'a> {
+        emit_context!(bin);
+
+        let slot_ptr = bin.builder.build_alloca(slot.get_type(), "slot").unwrap();
+        bin.builder.build_store(slot_ptr, slot).unwrap();
+
+        let (key, key_len) = self.storage_key(bin, slot, slot_ptr);
+
+        let ty = bin.llvm_type(ty.deref_any(), ns).into_int_type();
+
+        let (scratch_buf, scratch_len) = scratch_buf!();
+        let ty_len = ty.size_of().const_cast(bin.context.i32_type(), false);
+        bin.builder.build_store(scratch_len, ty_len).unwrap();
+
+        let ret = call!(
+            "take_storage",
+            &[
+                key.into(),
+                key_len.into(),
+                scratch_buf.into(),
+                scratch_len.into()
+            ],
+            "seal_take_storage"
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        let exists = bin
+            .builder
+            .build_int_compare(IntPredicate::EQ, ret, i32_zero!(), "storage_exists")
+            .unwrap();
+
+        let entry = bin.builder.get_insert_block().unwrap();
+        let retrieve_block = bin.context.append_basic_block(function, "in_storage");
+        let done_storage = bin.context.append_basic_block(function, "done_storage");
+
+        bin.builder
+            .build_conditional_branch(exists, retrieve_block, done_storage)
+            .unwrap();
+
+        bin.builder.position_at_end(retrieve_block);
+
+        let loaded_int = bin.builder.build_load(ty, scratch_buf, "int").unwrap();
+
+        bin.builder
+            .build_unconditional_branch(done_storage)
+            .unwrap();
+
+        bin.builder.position_at_end(done_storage);
+
+        let res = bin.builder.build_phi(ty, "storage_res").unwrap();
+
+        res.add_incoming(&[(&loaded_int, retrieve_block), (&ty.const_zero(), entry)]);
+
+        res.as_basic_value()
+    }
+
+    /// `contains_storage` returns the size of the value, or `u32::MAX` if there is none
+    fn storage_contains(&self, bin: &Binary<'a>, slot: IntValue<'a>) -> IntValue<'a> {
+        emit_context!(bin);
+
+        let slot_ptr = bin.builder.build_alloca(slot.get_type(), "slot").unwrap();
+        bin.builder.build_store(slot_ptr, slot).unwrap();
+
+        let (key, key_len) = self.storage_key(bin, slot, slot_ptr);
+
+        let size = call!(
+            "contains_storage",
+            &[key.into(), key_len.into()],
+            "seal_contains_storage"
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        bin.builder
+            .build_int_compare(
+                IntPredicate::NE,
+                size,
+                bin.context.i32_type().const_all_ones(),
+                "storage_contains",
+            )
+            .unwrap()
+    }
+
     fn builtin_function(
         &self,
         binary: &Binary<'a>,
-        _function: FunctionValue<'a>,
+        function: FunctionValue<'a>,
         builtin_func: &Function,
         args: &[BasicMetadataValueEnum<'a>],
         _first_arg_type: Option<BasicTypeEnum>,
@@ -1710,6 +1937,26 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
                     .unwrap();
                 None
             }
+            "lock_delegate_dependency" | "unlock_delegate_dependency" => {
+                let code_hash = binary.build_array_alloca(
+                    function,
+                    binary.context.i8_type(),
+                    i32_const!(32),
+                    "code_hash",
+                );
+                binary.write_be_bytes(function, args[0].into_int_value(), code_hash);
+                call!(builtin_func.id.name.as_str(), &[code_hash.into()]);
+                None
+            }
+            "ecdsa_to_eth_address" => {
+                let eth_address =
+                    self.ecdsa_to_eth_address(binary, function, args[0].into_pointer_value());
+                binary
+                    .builder
+                    .build_store(args[1].into_pointer_value(), eth_address)
+                    .unwrap();
+                None
+            }
             "caller_is_root" => {
                 let is_root = call!("caller_is_root", &[], "seal_caller_is_root")
                     .try_as_basic_value()
@@ -1722,6 +1969,42 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
                     .unwrap();
                 None
             }
+            "reentrance_count" => {
+                let count = call!("reentrance_count", &[], "seal_reentrance_count")
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_int_value();
+                binary
+                    .builder
+                    .build_store(args[0].into_pointer_value(), count)
+                    .unwrap();
+                None
+            }
+            "account_reentrance_count" => {
+                let account = binary
+                    .builder
+                    .build_alloca(binary.address_type(ns), "account")
+                    .unwrap();
+                binary
+                    .builder
+                    .build_store(account, args[0].into_array_value())
+                    .unwrap();
+                let count = call!(
+                    "account_reentrance_count",
+                    &[account.into()],
+                    "seal_account_reentrance_count"
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+                binary
+                    .builder
+                    .build_store(args[1].into_pointer_value(), count)
+                    .unwrap();
+                None
+            }
             _ => unimplemented!(),
         }
     }
@@ -6,7 +6,10 @@ use crate::sema::ast;
 use crate::Target;
 use std::cmp::Ordering;
 
-use crate::codegen::{cfg::ReturnCode, Options};
+use crate::codegen::{
+    cfg::{HashTy, ReturnCode},
+    Options,
+};
 use crate::sema::ast::{Namespace, StructType, Type};
 use inkwell::module::{Linkage, Module};
 use inkwell::types::BasicType;
@@ -19,6 +22,7 @@ use num_traits::ToPrimitive;
 
 use crate::emit::functions::emit_functions;
 use crate::emit::loop_builder::LoopBuilder;
+
 use crate::emit::{Binary, ContractArgs, TargetRuntime};
 
 pub struct SolanaTarget();
@@ -84,7 +88,13 @@ impl SolanaTarget {
             "sol_try_find_program_address",
             "sol_sha256",
             "sol_keccak256",
+            "sol_secp256k1_recover",
             "sol_log_data",
+            "sol_log_compute_units_",
+            "sol_remaining_compute_units",
+            "sol_get_clock_sysvar",
+            "sol_get_rent_sysvar",
+            "sol_get_epoch_schedule_sysvar",
         ]);
 
         binary
@@ -133,6 +143,41 @@ impl SolanaTarget {
             .as_global_value()
             .set_unnamed_address(UnnamedAddress::Local);
 
+        let function =
+            binary
+                .module
+                .add_function("sol_log_compute_units_", void_ty.fn_type(&[], false), None);
+        function
+            .as_global_value()
+            .set_unnamed_address(UnnamedAddress::Local);
+
+        let function = binary.module.add_function(
+            "sol_remaining_compute_units",
+            u64_ty.fn_type(&[], false),
+            None,
+        );
+        function
+            .as_global_value()
+            .set_unnamed_address(UnnamedAddress::Local);
+
+        let function = binary.module.add_function(
+            "sol_get_clock_sysvar",
+            u64_ty.fn_type(&[u8_ptr.into()], false),
+            None,
+        );
+        function
+            .as_global_value()
+            .set_unnamed_address(UnnamedAddress::Local);
+
+        let function = binary.module.add_function(
+            "sol_get_epoch_schedule_sysvar",
+            u64_ty.fn_type(&[u8_ptr.into()], false),
+            None,
+        );
+        function
+            .as_global_value()
+            .set_unnamed_address(UnnamedAddress::Local);
+
         let function = binary.module.add_function(
             "sol_sha256",
             void_ty.fn_type(&[sol_bytes.into(), u32_ty.into(), u8_ptr.into()], false),
@@ -151,6 +196,18 @@ impl SolanaTarget {
             .as_global_value()
             .set_unnamed_address(UnnamedAddress::Local);
 
+        let function = binary.module.add_function(
+            "sol_secp256k1_recover",
+            u64_ty.fn_type(
+                &[u8_ptr.into(), u64_ty.into(), u8_ptr.into(), u8_ptr.into()],
+                false,
+            ),
+            None,
+        );
+        function
+            .as_global_value()
+            .set_unnamed_address(UnnamedAddress::Local);
+
         let function = binary.module.add_function(
             "sol_set_return_data",
             void_ty.fn_type(&[u8_ptr.into(), u64_ty.into()], false),
@@ -380,7 +437,7 @@ impl SolanaTarget {
 
                     free_array = Some(elem_slot);
 
-                    self.storage_array_length(binary, function, slot, elem_ty, ns)
+                    self.storage_array_length(binary, function, slot, None, elem_ty, ns)
                 };
 
                 let elem_size = elem_ty.solana_storage_size(ns).to_u64().unwrap();
@@ -1187,7 +1244,171 @@ impl SolanaTarget {
             .as_basic_value_enum()
     }
 
-    /// Construct the LLVM-IR to call 'sol_invoke_signed_c'.
+    /// Read a sysvar with its syscall, and load the u64 field at `field_no`. The clock and epoch
+    /// schedule sysvars are 40 bytes, and the fields we read are u64s.
+    fn sysvar_field<'b>(
+        &self,
+        binary: &Binary<'b>,
+        function: FunctionValue<'b>,
+        syscall: &str,
+        field_no: u64,
+    ) -> IntValue<'b> {
+        let u64_ty = binary.context.i64_type();
+        let sysvar_ty = u64_ty.array_type(5);
+
+        let sysvar = binary.build_alloca(function, sysvar_ty, "sysvar");
+
+        binary
+            .builder
+            .build_call(
+                binary.module.get_function(syscall).unwrap(),
+                &[sysvar.into()],
+                "",
+            )
+            .unwrap();
+
+        let field = unsafe {
+            binary
+                .builder
+                .build_gep(
+                    sysvar_ty,
+                    sysvar,
+                    &[u64_ty.const_zero(), u64_ty.const_int(field_no, false)],
+                    "field",
+                )
+                .unwrap()
+        };
+
+        binary
+            .builder
+            .build_load(u64_ty, field, "field")
+            .unwrap()
+            .into_int_value()
+    }
+
+    /// Recover the public key which signed `hash` with `sol_secp256k1_recover`, and return the
+    /// Ethereum address for it. If the signature is not valid, the address is zero.
+    fn ecrecover<'b>(
+        &self,
+        binary: &Binary<'b>,
+        function: FunctionValue<'b>,
+        hash: IntValue<'b>,
+        v: IntValue<'b>,
+        r: IntValue<'b>,
+        s: IntValue<'b>,
+        ns: &Namespace,
+    ) -> IntValue<'b> {
+        let i8_type = binary.context.i8_type();
+        let i32_type = binary.context.i32_type();
+        let u64_ty = binary.context.i64_type();
+
+        let message_hash =
+            binary.build_array_alloca(function, i8_type, i32_type.const_int(32, false), "hash");
+
+        binary.write_be_bytes(function, hash, message_hash);
+
+        let signature = binary.build_array_alloca(
+            function,
+            i8_type,
+            i32_type.const_int(64, false),
+            "signature",
+        );
+
+        binary.write_be_bytes(function, r, signature);
+
+        let signature_s = unsafe {
+            binary
+                .builder
+                .build_gep(
+                    i8_type,
+                    signature,
+                    &[i32_type.const_int(32, false)],
+                    "signature_s",
+                )
+                .unwrap()
+        };
+
+        binary.write_be_bytes(function, s, signature_s);
+
+        // v is the recovery id plus 27 for Ethereum signatures, but accept the plain recovery id too
+        let v = binary.builder.build_int_z_extend(v, u64_ty, "v").unwrap();
+        let has_offset = binary
+            .builder
+            .build_int_compare(
+                IntPredicate::UGE,
+                v,
+                u64_ty.const_int(27, false),
+                "has_offset",
+            )
+            .unwrap();
+        let recovery_id = binary
+            .builder
+            .build_select(
+                has_offset,
+                binary
+                    .builder
+                    .build_int_sub(v, u64_ty.const_int(27, false), "recovery_id")
+                    .unwrap(),
+                v,
+                "recovery_id",
+            )
+            .unwrap();
+
+        let public_key = binary.build_array_alloca(
+            function,
+            i8_type,
+            i32_type.const_int(64, false),
+            "public_key",
+        );
+
+        let ret = binary
+            .builder
+            .build_call(
+                binary.module.get_function("sol_secp256k1_recover").unwrap(),
+                &[
+                    message_hash.into(),
+                    recovery_id.into(),
+                    signature.into(),
+                    public_key.into(),
+                ],
+                "ret",
+            )
+            .unwrap()
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+        // The address is the last 20 bytes of the keccak256 hash of the public key, i.e. the
+        // least significant bytes of the little endian bytes32
+        let key_hash = self.hash(
+            binary,
+            function,
+            HashTy::Keccak256,
+            public_key,
+            i32_type.const_int(64, false),
+            ns,
+        );
+
+        let address_ty = binary.context.custom_width_int_type(160);
+        let address = binary
+            .builder
+            .build_int_truncate(key_hash, address_ty, "eth_address")
+            .unwrap();
+
+        let success = binary
+            .builder
+            .build_int_compare(IntPredicate::EQ, ret, u64_ty.const_zero(), "success")
+            .unwrap();
+
+        binary
+            .builder
+            .build_select(success, address, address_ty.const_zero(), "eth_address")
+            .unwrap()
+            .into_int_value()
+    }
+
+    /// Construct the LLVM-IR to call 'sol_invoke_signed_c'. Returns the return code of the call.
     fn build_invoke_signed_c<'b>(
         &self,
         binary: &Binary<'b>,
@@ -1196,7 +1417,7 @@ impl SolanaTarget {
         payload_len: IntValue<'b>,
         contract_args: ContractArgs<'b>,
         ns: &Namespace,
-    ) {
+    ) -> IntValue<'b> {
         let instruction_ty: BasicTypeEnum = binary
             .context
             .struct_type(
@@ -1371,6 +1592,10 @@ impl SolanaTarget {
                 ],
                 "",
             )
-            .unwrap();
+            .unwrap()
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value()
     }
 }
@@ -7,7 +7,7 @@ use crate::emit::expression::expression;
 use crate::emit::loop_builder::LoopBuilder;
 use crate::emit::solana::SolanaTarget;
 use crate::emit::{ContractArgs, TargetRuntime, Variable};
-use crate::sema::ast::{self, Namespace};
+use crate::sema::ast::{self, Namespace, StorageType};
 use inkwell::types::{BasicType, BasicTypeEnum, IntType};
 use inkwell::values::{
     ArrayValue, BasicMetadataValueEnum, BasicValueEnum, FunctionValue, IntValue, PointerValue,
@@ -24,6 +24,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         binary: &Binary<'a>,
         ty: &ast::Type,
         slot: &mut IntValue<'a>,
+        _storage_type: Option<StorageType>,
         function: FunctionValue<'a>,
         ns: &ast::Namespace,
     ) {
@@ -37,7 +38,8 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         &self,
         _binary: &Binary,
         _function: FunctionValue,
-        _slot: PointerValue,
+        _key: PointerValue<'a>,
+        _key_len: IntValue<'a>,
         _dest: PointerValue,
         _dest_ty: BasicTypeEnum,
     ) {
@@ -47,7 +49,8 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         &self,
         _binary: &Binary<'a>,
         _function: FunctionValue,
-        _slot: PointerValue<'a>,
+        _key: PointerValue<'a>,
+        _key_len: IntValue<'a>,
         _ns: &ast::Namespace,
     ) -> PointerValue<'a> {
         unimplemented!();
@@ -57,7 +60,8 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         &self,
         _binary: &Binary<'a>,
         _function: FunctionValue<'a>,
-        _slot: PointerValue<'a>,
+        _key: PointerValue<'a>,
+        _key_len: IntValue<'a>,
         _dest: BasicValueEnum<'a>,
     ) {
         // unused
@@ -68,7 +72,8 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         &self,
         _binary: &Binary<'a>,
         _function: FunctionValue,
-        _slot: PointerValue<'a>,
+        _key: PointerValue<'a>,
+        _key_len: IntValue<'a>,
     ) -> PointerValue<'a> {
         // unused
         unreachable!();
@@ -79,6 +84,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         binary: &Binary<'a>,
         function: FunctionValue,
         slot: IntValue<'a>,
+        _storage_type: Option<StorageType>,
         index: IntValue<'a>,
         loc: Loc,
         ns: &Namespace,
@@ -169,6 +175,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         binary: &Binary,
         function: FunctionValue,
         slot: IntValue,
+        _storage_type: Option<StorageType>,
         index: IntValue,
         val: IntValue,
         ns: &Namespace,
@@ -340,6 +347,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         function: FunctionValue<'a>,
         ty: &ast::Type,
         slot: IntValue<'a>,
+        _storage_type: Option<StorageType>,
         val: Option<BasicValueEnum<'a>>,
         ns: &ast::Namespace,
     ) -> BasicValueEnum<'a> {
@@ -440,7 +448,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
             .unwrap();
 
         if let Some(val) = val {
-            self.storage_store(binary, ty, false, &mut new_offset, val, function, ns);
+            self.storage_store(binary, ty, false, &mut new_offset, None, val, function, ns);
         }
 
         if ty.is_reference_type(ns) {
@@ -458,6 +466,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         function: FunctionValue<'a>,
         ty: &ast::Type,
         slot: IntValue<'a>,
+        _storage_type: Option<StorageType>,
         load: bool,
         ns: &ast::Namespace,
         loc: Loc,
@@ -545,7 +554,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
             .unwrap();
 
         let val = if load {
-            Some(self.storage_load(binary, ty, &mut old_elem_offset, function, ns))
+            Some(self.storage_load(binary, ty, &mut old_elem_offset, None, function, ns))
         } else {
             None
         };
@@ -576,6 +585,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         binary: &Binary<'a>,
         _function: FunctionValue,
         slot: IntValue<'a>,
+        _storage_type: Option<StorageType>,
         elem_ty: &ast::Type,
         ns: &ast::Namespace,
     ) -> IntValue<'a> {
@@ -623,7 +633,8 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         &self,
         _binary: &Binary<'a>,
         _function: FunctionValue,
-        _slot: PointerValue<'a>,
+        _key: PointerValue<'a>,
+        _key_len: IntValue<'a>,
         _ty: IntType<'a>,
     ) -> IntValue<'a> {
         // unused
@@ -637,6 +648,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         binary: &Binary<'a>,
         ty: &ast::Type,
         slot: &mut IntValue<'a>,
+        _storage_type: Option<StorageType>,
         function: FunctionValue<'a>,
         ns: &ast::Namespace,
     ) -> BasicValueEnum<'a> {
@@ -733,7 +745,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
                         )
                         .unwrap();
 
-                    let val = self.storage_load(binary, &field.ty, &mut offset, function, ns);
+                    let val = self.storage_load(binary, &field.ty, &mut offset, None, function, ns);
 
                     let elem = unsafe {
                         binary
@@ -815,7 +827,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
                         )
                         .unwrap();
 
-                    length = self.storage_array_length(binary, function, slot, elem_ty, ns);
+                    length = self.storage_array_length(binary, function, slot, None, elem_ty, ns);
 
                     slot = binary
                         .builder
@@ -847,6 +859,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
                     binary,
                     elem_ty.deref_memory(),
                     &mut offset_val,
+                    None,
                     function,
                     ns,
                 );
@@ -893,6 +906,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         ty: &ast::Type,
         existing: bool,
         offset: &mut IntValue<'a>,
+        _storage_type: Option<StorageType>,
         val: BasicValueEnum<'a>,
         function: FunctionValue<'a>,
         ns: &ast::Namespace,
@@ -1203,6 +1217,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
                 elem_ty.deref_any(),
                 false, // storage already freed with storage_free
                 &mut offset_val,
+                None,
                 if elem_ty.deref_memory().is_fixed_reference_type(ns) {
                     elem.into()
                 } else {
@@ -1278,6 +1293,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
                     &field.ty,
                     existing,
                     &mut offset,
+                    None,
                     if field.ty.is_fixed_reference_type(ns) {
                         elem.into()
                     } else {
@@ -1323,7 +1339,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
     }
 
     fn assert_failure(&self, binary: &Binary, data: PointerValue, length: IntValue) {
-        // the reason code should be null (and already printed)
+        // the reason code is null for anything but custom errors (and already printed)
         binary
             .builder
             .build_call(
@@ -1340,13 +1356,24 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
             )
             .unwrap();
 
-        // return 1 for failure
-        binary
-            .builder
-            .build_return(Some(
-                &binary.context.i64_type().const_int(1u64 << 32, false),
-            ))
-            .unwrap();
+        // return 1 for failure, unless this is a custom error; its encoding starts
+        // with the error code, which we return as ProgramError::Custom
+        let ret = if length.get_zero_extended_constant() == Some(0) {
+            binary.context.i64_type().const_int(1u64 << 32, false)
+        } else {
+            let code = binary
+                .builder
+                .build_load(binary.context.i32_type(), data, "error_code")
+                .unwrap()
+                .into_int_value();
+
+            binary
+                .builder
+                .build_int_z_extend(code, binary.context.i64_type(), "error_code")
+                .unwrap()
+        };
+
+        binary.builder.build_return(Some(&ret)).unwrap();
     }
 
     fn print(&self, binary: &Binary, string_ptr: PointerValue, string_len: IntValue) {
@@ -1370,7 +1397,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         &mut self,
         binary: &Binary<'b>,
         function: FunctionValue<'b>,
-        _success: Option<&mut BasicValueEnum<'b>>,
+        success: Option<&mut BasicValueEnum<'b>>,
         _contract_no: usize,
         address: PointerValue<'b>,
         encoded_args: BasicValueEnum<'b>,
@@ -1386,7 +1413,12 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
 
         assert!(contract_args.accounts.is_some());
         // The AccountMeta array is always present for Solana contracts
-        self.build_invoke_signed_c(binary, function, payload, payload_len, contract_args, ns);
+        let ret =
+            self.build_invoke_signed_c(binary, function, payload, payload_len, contract_args, ns);
+
+        if let Some(success) = success {
+            *success = ret.into();
+        }
     }
 
     fn builtin_function(
@@ -1484,7 +1516,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         &self,
         binary: &Binary<'b>,
         function: FunctionValue<'b>,
-        _success: Option<&mut BasicValueEnum<'b>>,
+        success: Option<&mut BasicValueEnum<'b>>,
         payload: PointerValue<'b>,
         payload_len: IntValue<'b>,
         address: Option<PointerValue<'b>>,
@@ -1507,7 +1539,12 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         };
 
         contract_args.program_id = Some(address);
-        self.build_invoke_signed_c(binary, function, payload, payload_len, contract_args, ns);
+        let ret =
+            self.build_invoke_signed_c(binary, function, payload, payload_len, contract_args, ns);
+
+        if let Some(success) = success {
+            *success = ret.into();
+        }
     }
 
     /// Get return buffer for external call
@@ -1653,7 +1690,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
     fn value_transfer<'b>(
         &self,
         _binary: &Binary<'b>,
-        _function: FunctionValue,
+        _function: FunctionValue<'b>,
         _success: Option<&mut BasicValueEnum<'b>>,
         _address: PointerValue<'b>,
         _value: IntValue<'b>,
@@ -1764,32 +1801,9 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
             } => {
                 assert_eq!(args.len(), 0);
 
-                let parameters = self.sol_parameters(binary);
-
-                let sol_clock = binary.module.get_function("sol_clock").unwrap();
-
-                let clock = binary
-                    .builder
-                    .build_call(sol_clock, &[parameters.into()], "clock")
-                    .unwrap()
-                    .try_as_basic_value()
-                    .left()
-                    .unwrap()
-                    .into_pointer_value();
-
-                // This is struct.clock_layout
-                let clock_struct = binary
-                    .context
-                    .struct_type(&[binary.context.i64_type().as_basic_type_enum(); 5], false);
-                let timestamp = binary
-                    .builder
-                    .build_struct_gep(clock_struct, clock, 4, "unix_timestamp")
-                    .unwrap();
-
-                binary
-                    .builder
-                    .build_load(binary.context.i64_type(), timestamp, "timestamp")
-                    .unwrap()
+                // unix_timestamp in the clock
+                self.sysvar_field(binary, function, "sol_get_clock_sysvar", 4)
+                    .into()
             }
             codegen::Expression::Builtin {
                 kind: codegen::Builtin::BlockNumber | codegen::Builtin::Slot,
@@ -1798,32 +1812,50 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
             } => {
                 assert_eq!(args.len(), 0);
 
-                let parameters = self.sol_parameters(binary);
+                self.sysvar_field(binary, function, "sol_get_clock_sysvar", 0)
+                    .into()
+            }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::Epoch,
+                args,
+                ..
+            } => {
+                assert_eq!(args.len(), 0);
 
-                let sol_clock = binary.module.get_function("sol_clock").unwrap();
+                self.sysvar_field(binary, function, "sol_get_clock_sysvar", 2)
+                    .into()
+            }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::SlotsPerEpoch,
+                args,
+                ..
+            } => {
+                assert_eq!(args.len(), 0);
 
-                let clock = binary
+                self.sysvar_field(binary, function, "sol_get_epoch_schedule_sysvar", 0)
+                    .into()
+            }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::RentMinimumBalance,
+                args,
+                ..
+            } => {
+                let space = expression(self, binary, &args[0], vartab, function, ns);
+
+                binary
                     .builder
-                    .build_call(sol_clock, &[parameters.into()], "clock")
+                    .build_call(
+                        binary
+                            .module
+                            .get_function("sol_rent_minimum_balance")
+                            .unwrap(),
+                        &[space.into()],
+                        "minimum_balance",
+                    )
                     .unwrap()
                     .try_as_basic_value()
                     .left()
                     .unwrap()
-                    .into_pointer_value();
-
-                // This is struct.clock_layout
-                let clock_struct = binary
-                    .context
-                    .struct_type(&[binary.context.i64_type().as_basic_type_enum(); 5], false);
-                let slot = binary
-                    .builder
-                    .build_struct_gep(clock_struct, clock, 0, "slot")
-                    .unwrap();
-
-                binary
-                    .builder
-                    .build_load(binary.context.i64_type(), slot, "timestamp")
-                    .unwrap()
             }
             codegen::Expression::Builtin {
                 kind: codegen::Builtin::GetAddress,
@@ -2023,6 +2055,54 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
                     .unwrap()
                     .into()
             }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::RemainingComputeUnits,
+                ..
+            } => binary
+                .builder
+                .build_call(
+                    binary
+                        .module
+                        .get_function("sol_remaining_compute_units")
+                        .unwrap(),
+                    &[],
+                    "remaining_compute_units",
+                )
+                .unwrap()
+                .try_as_basic_value()
+                .left()
+                .unwrap(),
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::LogComputeUnits,
+                ..
+            } => {
+                binary
+                    .builder
+                    .build_call(
+                        binary
+                            .module
+                            .get_function("sol_log_compute_units_")
+                            .unwrap(),
+                        &[],
+                        "",
+                    )
+                    .unwrap();
+
+                binary.context.i64_type().const_zero().into()
+            }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::ECRecover,
+                args,
+                ..
+            } => {
+                let args = args
+                    .iter()
+                    .map(|arg| expression(self, binary, arg, vartab, function, ns).into_int_value())
+                    .collect::<Vec<_>>();
+
+                self.ecrecover(binary, function, args[0], args[1], args[2], args[3], ns)
+                    .into()
+            }
             codegen::Expression::Builtin {
                 kind: codegen::Builtin::Accounts,
                 args,
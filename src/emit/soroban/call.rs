@@ -0,0 +1,214 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::emit::binary::Binary;
+use crate::emit::soroban::val::{bytes_new, pointer_to_val, symbol_to_val, u32_to_val, TAG_ERROR};
+use crate::emit::soroban::{
+    SorobanTarget, BYTES_APPEND, CALL, COMPUTE_HASH_SHA256, CONTRACT_ID_TO_ADDRESS,
+    GET_CURRENT_CONTRACT_ADDRESS, GET_LEDGER_NETWORK_ID, TRY_CALL, VEC_NEW_FROM_LINEAR_MEMORY,
+};
+use crate::emit_context;
+use inkwell::module::Linkage;
+use inkwell::values::{BasicValueEnum, FunctionValue, IntValue, PointerValue};
+use inkwell::{AddressSpace, IntPredicate};
+
+/// The global which holds the `Val` returned by the last call to another contract
+const RETURN_VAL: &str = "return_val";
+
+/// XDR of the `HashIdPreimage` discriminant for contract ids, which precedes the network id
+const CONTRACT_ID_PREIMAGE_PREFIX: [u8; 4] = [0, 0, 0, 8];
+
+/// XDR of the contract id preimage for the native asset, which follows the network id
+const NATIVE_ASSET_PREIMAGE: [u8; 8] = [0, 0, 0, 1, 0, 0, 0, 0];
+
+impl SorobanTarget {
+    /// Call a function on another contract. The payload starts with the function name as a
+    /// `Symbol`, followed by the arguments as `Val`s. If `success` is given, the call is
+    /// allowed to fail, and `success` is set to whether it succeeded.
+    pub(super) fn call_contract<'a>(
+        &self,
+        bin: &Binary<'a>,
+        contract: IntValue<'a>,
+        payload: PointerValue<'a>,
+        payload_len: IntValue<'a>,
+        success: Option<&mut BasicValueEnum<'a>>,
+    ) {
+        emit_context!(bin);
+
+        let i64_type = bin.context.i64_type();
+
+        let symbol = bin
+            .builder
+            .build_load(i64_type, payload, "symbol")
+            .unwrap()
+            .into_int_value();
+
+        let args = unsafe {
+            bin.builder
+                .build_gep(i64_type, payload, &[i32_const!(1)], "args")
+                .unwrap()
+        };
+
+        let args_len = bin
+            .builder
+            .build_int_unsigned_div(payload_len, i32_const!(8), "args_len")
+            .unwrap();
+
+        let args_len = bin
+            .builder
+            .build_int_sub(args_len, i32_const!(1), "args_len")
+            .unwrap();
+
+        let args = call!(
+            VEC_NEW_FROM_LINEAR_MEMORY,
+            &[
+                pointer_to_val(bin, args).into(),
+                u32_to_val(bin, args_len).into()
+            ]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        let host_function = if success.is_some() { TRY_CALL } else { CALL };
+
+        let ret = call!(
+            host_function,
+            &[contract.into(), symbol.into(), args.into()]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        if let Some(success) = success {
+            let tag = bin.builder.build_and(ret, i64_const!(0xff), "tag").unwrap();
+
+            *success = bin
+                .builder
+                .build_int_compare(IntPredicate::NE, tag, i64_const!(TAG_ERROR), "success")
+                .unwrap()
+                .into();
+        }
+
+        bin.builder.build_store(self.return_val(bin), ret).unwrap();
+    }
+
+    /// Pointer to the `Val` returned by the last call to another contract
+    pub(super) fn return_val<'a>(&self, bin: &Binary<'a>) -> PointerValue<'a> {
+        if let Some(global) = bin.module.get_global(RETURN_VAL) {
+            return global.as_pointer_value();
+        }
+
+        let ty = bin.context.i64_type();
+
+        let global = bin
+            .module
+            .add_global(ty, Some(AddressSpace::default()), RETURN_VAL);
+
+        global.set_linkage(Linkage::Internal);
+        global.set_initializer(&ty.const_zero());
+
+        global.as_pointer_value()
+    }
+
+    /// The address of the contract which holds the native token. This is the contract id given
+    /// in the options, or else the Stellar Asset Contract for the native asset of the network,
+    /// whose contract id is derived from the network id.
+    pub(super) fn native_token<'a>(&self, bin: &Binary<'a>) -> IntValue<'a> {
+        emit_context!(bin);
+
+        let contract_id = if let Some(contract_id) = &bin.options.soroban_native_token {
+            let contract_id = bin.emit_global_string("native_token", contract_id, true);
+
+            bytes_new(bin, contract_id, i32_const!(32))
+        } else {
+            let prefix = bin.emit_global_string(
+                "contract_id_preimage_prefix",
+                &CONTRACT_ID_PREIMAGE_PREFIX,
+                true,
+            );
+            let asset =
+                bin.emit_global_string("native_asset_preimage", &NATIVE_ASSET_PREIMAGE, true);
+
+            let prefix = bytes_new(
+                bin,
+                prefix,
+                i32_const!(CONTRACT_ID_PREIMAGE_PREFIX.len() as u64),
+            );
+            let asset = bytes_new(bin, asset, i32_const!(NATIVE_ASSET_PREIMAGE.len() as u64));
+
+            let network_id = call!(GET_LEDGER_NETWORK_ID, &[])
+                .try_as_basic_value()
+                .left()
+                .unwrap();
+
+            let preimage = call!(BYTES_APPEND, &[prefix.into(), network_id.into()])
+                .try_as_basic_value()
+                .left()
+                .unwrap();
+
+            let preimage = call!(BYTES_APPEND, &[preimage.into(), asset.into()])
+                .try_as_basic_value()
+                .left()
+                .unwrap();
+
+            call!(COMPUTE_HASH_SHA256, &[preimage.into()])
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value()
+        };
+
+        call!(CONTRACT_ID_TO_ADDRESS, &[contract_id.into()])
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value()
+    }
+
+    /// Transfer `amount` of the native token from the current contract to `to`, by calling
+    /// `transfer` on the native token contract. Both `to` and `amount` are `Val`s.
+    pub(super) fn transfer_native_token<'a>(
+        &self,
+        bin: &Binary<'a>,
+        function: FunctionValue<'a>,
+        to: IntValue<'a>,
+        amount: IntValue<'a>,
+        success: Option<&mut BasicValueEnum<'a>>,
+    ) {
+        emit_context!(bin);
+
+        let i64_type = bin.context.i64_type();
+
+        let from = call!(GET_CURRENT_CONTRACT_ADDRESS, &[])
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+        let vals = [symbol_to_val(bin, b"transfer"), from, to, amount];
+
+        let payload = bin.build_alloca(function, i64_type.array_type(vals.len() as u32), "payload");
+
+        for (i, val) in vals.iter().enumerate() {
+            let dest = unsafe {
+                bin.builder
+                    .build_gep(i64_type, payload, &[i32_const!(i as u64)], "val")
+                    .unwrap()
+            };
+
+            bin.builder.build_store(dest, *val).unwrap();
+        }
+
+        let token = self.native_token(bin);
+
+        self.call_contract(
+            bin,
+            token,
+            payload,
+            i32_const!(vals.len() as u64 * 8),
+            success,
+        );
+    }
+}
@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::codegen::cfg::HashTy;
+use crate::emit::binary::Binary;
+use crate::emit::soroban::val::{bytes_copy, bytes_new, u32_to_val, vector_to_val};
+use crate::emit::soroban::{
+    SorobanTarget, COMPUTE_HASH_KECCAK256, COMPUTE_HASH_SHA256, RECOVER_KEY_ECDSA_SECP256K1,
+    VERIFY_SIG_ED25519,
+};
+use crate::emit_context;
+use inkwell::values::{BasicValueEnum, FunctionValue, IntValue, PointerValue};
+
+/// Length of an uncompressed secp256k1 public key, which starts with a `0x04` byte
+const SECP256K1_PUBLIC_KEY_LEN: u64 = 65;
+
+impl SorobanTarget {
+    /// Hash `length` bytes at `input` and write the digest to `dest`. Returns the length
+    /// of the digest.
+    pub(super) fn compute_hash<'a>(
+        &self,
+        bin: &Binary<'a>,
+        hash: HashTy,
+        input: PointerValue<'a>,
+        length: IntValue<'a>,
+        dest: PointerValue<'a>,
+    ) -> u64 {
+        emit_context!(bin);
+
+        let host_function = match hash {
+            HashTy::Sha256 => COMPUTE_HASH_SHA256,
+            HashTy::Keccak256 => COMPUTE_HASH_KECCAK256,
+            HashTy::Ripemd160 => {
+                // the host does not provide ripemd160, so use the one from the stdlib
+                call!("ripemd160", &[input.into(), length.into(), dest.into()]);
+
+                return 20;
+            }
+            HashTy::Blake2_128 | HashTy::Blake2_256 => {
+                unreachable!("blake2 is not available on Soroban")
+            }
+        };
+
+        let bytes = bytes_new(bin, input, length);
+
+        let digest = call!(host_function, &[bytes.into()])
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+        bytes_copy(bin, digest, dest, i32_const!(32));
+
+        32
+    }
+
+    /// Verify an ed25519 signature. The host aborts the invocation if the signature is
+    /// not valid, so this always returns true.
+    pub(super) fn signature_verify<'a>(
+        &self,
+        bin: &Binary<'a>,
+        function: FunctionValue<'a>,
+        public_key: IntValue<'a>,
+        message: BasicValueEnum<'a>,
+        signature: BasicValueEnum<'a>,
+    ) -> IntValue<'a> {
+        emit_context!(bin);
+
+        let key = bin.build_array_alloca(function, bin.context.i8_type(), i32_const!(32), "key");
+
+        bin.write_be_bytes(function, public_key, key);
+
+        let key = bytes_new(bin, key, i32_const!(32));
+        let message = vector_to_val(bin, message, false);
+        let signature = vector_to_val(bin, signature, false);
+
+        call!(
+            VERIFY_SIG_ED25519,
+            &[key.into(), message.into(), signature.into()]
+        );
+
+        bin.context.bool_type().const_int(1, false)
+    }
+
+    /// Recover the secp256k1 public key which signed `hash`, and return the Ethereum address
+    /// for it: the last 20 bytes of the keccak256 hash of the key, without its first byte.
+    pub(super) fn ecrecover<'a>(
+        &self,
+        bin: &Binary<'a>,
+        function: FunctionValue<'a>,
+        hash: IntValue<'a>,
+        v: IntValue<'a>,
+        r: IntValue<'a>,
+        s: IntValue<'a>,
+    ) -> IntValue<'a> {
+        emit_context!(bin);
+
+        let i8_type = bin.context.i8_type();
+
+        let digest = bin.build_array_alloca(function, i8_type, i32_const!(32), "digest");
+
+        bin.write_be_bytes(function, hash, digest);
+
+        let digest = bytes_new(bin, digest, i32_const!(32));
+
+        // the signature is r followed by s
+        let signature = bin.build_array_alloca(function, i8_type, i32_const!(64), "signature");
+
+        bin.write_be_bytes(function, r, signature);
+
+        let signature_s = unsafe {
+            bin.builder
+                .build_gep(i8_type, signature, &[i32_const!(32)], "signature_s")
+                .unwrap()
+        };
+
+        bin.write_be_bytes(function, s, signature_s);
+
+        let signature = bytes_new(bin, signature, i32_const!(64));
+
+        // v is 27 or 28, the recovery id 0 or 1
+        let recovery_id = bin
+            .builder
+            .build_int_sub(v, v.get_type().const_int(27, false), "recovery_id")
+            .unwrap();
+
+        let recovery_id = bin
+            .builder
+            .build_int_z_extend(recovery_id, bin.context.i32_type(), "recovery_id")
+            .unwrap();
+
+        let public_key = call!(
+            RECOVER_KEY_ECDSA_SECP256K1,
+            &[
+                digest.into(),
+                signature.into(),
+                u32_to_val(bin, recovery_id).into()
+            ]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        let key = bin.build_array_alloca(
+            function,
+            i8_type,
+            i32_const!(SECP256K1_PUBLIC_KEY_LEN),
+            "public_key",
+        );
+
+        bytes_copy(bin, public_key, key, i32_const!(SECP256K1_PUBLIC_KEY_LEN));
+
+        let key = unsafe {
+            bin.builder
+                .build_gep(i8_type, key, &[i32_const!(1)], "public_key")
+                .unwrap()
+        };
+
+        let key_hash = bin.build_array_alloca(function, i8_type, i32_const!(32), "key_hash");
+
+        self.compute_hash(
+            bin,
+            HashTy::Keccak256,
+            key,
+            i32_const!(SECP256K1_PUBLIC_KEY_LEN - 1),
+            key_hash,
+        );
+
+        let address = unsafe {
+            bin.builder
+                .build_gep(i8_type, key_hash, &[i32_const!(12)], "address")
+                .unwrap()
+        };
+
+        let address_ty = bin.context.custom_width_int_type(160);
+        let res = bin.build_alloca(function, address_ty, "res");
+
+        call!(
+            "__beNtoleN",
+            &[address.into(), res.into(), i32_const!(20).into()]
+        );
+
+        bin.builder
+            .build_load(address_ty, res, "address")
+            .unwrap()
+            .into_int_value()
+    }
+}
@@ -1,28 +1,158 @@
 // SPDX-License-Identifier: Apache-2.0
 
+mod call;
+mod crypto;
+mod spec;
+mod storage;
 pub(super) mod target;
-use crate::codegen::{
-    cfg::{ASTFunction, ControlFlowGraph},
-    Options, STORAGE_INITIALIZER,
-};
+mod val;
+
+use crate::codegen::{cfg::ControlFlowGraph, Options};
 
 use crate::emit::cfg::emit_cfg;
 use crate::{emit::Binary, sema::ast};
 use inkwell::{
     context::Context,
     module::{Linkage, Module},
+    values::FunctionValue,
+    AddressSpace,
 };
 use soroban_sdk::xdr::{
     DepthLimitedWrite, ScEnvMetaEntry, ScSpecEntry, ScSpecFunctionInputV0, ScSpecFunctionV0,
-    ScSpecTypeDef, StringM, WriteXdr,
+    StringM, WriteXdr,
 };
-use std::ffi::CString;
-use std::sync;
 
 const SOROBAN_ENV_INTERFACE_VERSION: u64 = 90194313216;
 pub const PUT_CONTRACT_DATA: &str = "l._";
+pub const HAS_CONTRACT_DATA: &str = "l.0";
 pub const GET_CONTRACT_DATA: &str = "l.1";
+pub const DEL_CONTRACT_DATA: &str = "l.2";
+pub const CREATE_CONTRACT: &str = "l.3";
+pub const UPLOAD_WASM: &str = "l.5";
+pub const BUMP_CONTRACT_DATA: &str = "l.7";
+pub const BUMP_CURRENT_CONTRACT_INSTANCE_AND_CODE: &str = "l.8";
 pub const LOG_FROM_LINEAR_MEMORY: &str = "x._";
+pub const OBJ_CMP: &str = "x.1";
+pub const CONTRACT_EVENT: &str = "x.2";
+pub const GET_LEDGER_SEQUENCE: &str = "x.4";
+pub const GET_LEDGER_TIMESTAMP: &str = "x.5";
+pub const FAIL_WITH_ERROR: &str = "x.7";
+pub const GET_LEDGER_NETWORK_ID: &str = "x.8";
+pub const GET_CURRENT_CONTRACT_ADDRESS: &str = "x.9";
+pub const CALL: &str = "d._";
+pub const TRY_CALL: &str = "d.0";
+pub const PRNG_BYTES_NEW: &str = "p.0";
+pub const COMPUTE_HASH_SHA256: &str = "c._";
+pub const VERIFY_SIG_ED25519: &str = "c.0";
+pub const COMPUTE_HASH_KECCAK256: &str = "c.1";
+pub const RECOVER_KEY_ECDSA_SECP256K1: &str = "c.2";
+pub const REQUIRE_AUTH_FOR_ARGS: &str = "a._";
+pub const REQUIRE_AUTH: &str = "a.0";
+pub const CONTRACT_ID_TO_ADDRESS: &str = "a.2";
+pub const OBJ_FROM_U64: &str = "i._";
+pub const OBJ_TO_U64: &str = "i.0";
+pub const OBJ_FROM_I64: &str = "i.1";
+pub const OBJ_TO_I64: &str = "i.2";
+pub const OBJ_FROM_U128_PIECES: &str = "i.3";
+pub const OBJ_TO_U128_LO64: &str = "i.4";
+pub const OBJ_TO_U128_HI64: &str = "i.5";
+pub const OBJ_FROM_I128_PIECES: &str = "i.6";
+pub const OBJ_TO_I128_LO64: &str = "i.7";
+pub const OBJ_TO_I128_HI64: &str = "i.8";
+pub const OBJ_FROM_U256_PIECES: &str = "i.9";
+pub const OBJ_TO_U256_HI_HI: &str = "i.c";
+pub const OBJ_TO_U256_HI_LO: &str = "i.d";
+pub const OBJ_TO_U256_LO_HI: &str = "i.e";
+pub const OBJ_TO_U256_LO_LO: &str = "i.f";
+pub const OBJ_FROM_I256_PIECES: &str = "i.g";
+pub const OBJ_TO_I256_HI_HI: &str = "i.j";
+pub const OBJ_TO_I256_HI_LO: &str = "i.k";
+pub const OBJ_TO_I256_LO_HI: &str = "i.l";
+pub const OBJ_TO_I256_LO_LO: &str = "i.m";
+pub const VEC_PUSH_BACK: &str = "v.6";
+pub const VEC_NEW_FROM_LINEAR_MEMORY: &str = "v.g";
+pub const BYTES_COPY_TO_LINEAR_MEMORY: &str = "b.1";
+pub const BYTES_NEW_FROM_LINEAR_MEMORY: &str = "b.3";
+pub const BYTES_NEW: &str = "b.4";
+pub const BYTES_PUT: &str = "b.5";
+pub const BYTES_GET: &str = "b.6";
+pub const BYTES_LEN: &str = "b.8";
+pub const BYTES_PUSH: &str = "b.9";
+pub const BYTES_POP: &str = "b.a";
+pub const BYTES_BACK: &str = "b.c";
+pub const BYTES_APPEND: &str = "b.e";
+pub const STRING_COPY_TO_LINEAR_MEMORY: &str = "b.g";
+pub const STRING_NEW_FROM_LINEAR_MEMORY: &str = "b.i";
+pub const SYMBOL_NEW_FROM_LINEAR_MEMORY: &str = "b.j";
+pub const STRING_LEN: &str = "b.k";
+
+/// All the host functions we may import, with the number of arguments. The name is the module
+/// and the function name in the module, separated by a dot. Every argument and return value
+/// is a 64 bit `Val`.
+pub const HOST_FUNCTIONS: &[(&str, usize)] = &[
+    (PUT_CONTRACT_DATA, 3),
+    (HAS_CONTRACT_DATA, 2),
+    (GET_CONTRACT_DATA, 2),
+    (DEL_CONTRACT_DATA, 2),
+    (CREATE_CONTRACT, 3),
+    (UPLOAD_WASM, 1),
+    (BUMP_CONTRACT_DATA, 4),
+    (BUMP_CURRENT_CONTRACT_INSTANCE_AND_CODE, 2),
+    (LOG_FROM_LINEAR_MEMORY, 4),
+    (OBJ_CMP, 2),
+    (CONTRACT_EVENT, 2),
+    (GET_LEDGER_SEQUENCE, 0),
+    (GET_LEDGER_TIMESTAMP, 0),
+    (FAIL_WITH_ERROR, 1),
+    (GET_LEDGER_NETWORK_ID, 0),
+    (GET_CURRENT_CONTRACT_ADDRESS, 0),
+    (CALL, 3),
+    (TRY_CALL, 3),
+    (PRNG_BYTES_NEW, 1),
+    (COMPUTE_HASH_SHA256, 1),
+    (VERIFY_SIG_ED25519, 3),
+    (COMPUTE_HASH_KECCAK256, 1),
+    (RECOVER_KEY_ECDSA_SECP256K1, 3),
+    (REQUIRE_AUTH_FOR_ARGS, 2),
+    (REQUIRE_AUTH, 1),
+    (CONTRACT_ID_TO_ADDRESS, 1),
+    (OBJ_FROM_U64, 1),
+    (OBJ_TO_U64, 1),
+    (OBJ_FROM_I64, 1),
+    (OBJ_TO_I64, 1),
+    (OBJ_FROM_U128_PIECES, 2),
+    (OBJ_TO_U128_LO64, 1),
+    (OBJ_TO_U128_HI64, 1),
+    (OBJ_FROM_I128_PIECES, 2),
+    (OBJ_TO_I128_LO64, 1),
+    (OBJ_TO_I128_HI64, 1),
+    (OBJ_FROM_U256_PIECES, 4),
+    (OBJ_TO_U256_HI_HI, 1),
+    (OBJ_TO_U256_HI_LO, 1),
+    (OBJ_TO_U256_LO_HI, 1),
+    (OBJ_TO_U256_LO_LO, 1),
+    (OBJ_FROM_I256_PIECES, 4),
+    (OBJ_TO_I256_HI_HI, 1),
+    (OBJ_TO_I256_HI_LO, 1),
+    (OBJ_TO_I256_LO_HI, 1),
+    (OBJ_TO_I256_LO_LO, 1),
+    (VEC_PUSH_BACK, 2),
+    (VEC_NEW_FROM_LINEAR_MEMORY, 2),
+    (BYTES_COPY_TO_LINEAR_MEMORY, 4),
+    (BYTES_NEW_FROM_LINEAR_MEMORY, 2),
+    (BYTES_NEW, 0),
+    (BYTES_PUT, 3),
+    (BYTES_GET, 2),
+    (BYTES_LEN, 1),
+    (BYTES_PUSH, 2),
+    (BYTES_POP, 1),
+    (BYTES_BACK, 1),
+    (BYTES_APPEND, 2),
+    (STRING_COPY_TO_LINEAR_MEMORY, 4),
+    (STRING_NEW_FROM_LINEAR_MEMORY, 2),
+    (SYMBOL_NEW_FROM_LINEAR_MEMORY, 2),
+    (STRING_LEN, 1),
+];
 
 pub struct SorobanTarget;
 
@@ -46,6 +176,14 @@ impl SorobanTarget {
             None,
         );
 
+        let ptr = binary.context.i8_type().ptr_type(AddressSpace::default());
+
+        binary.vector_init_empty = binary
+            .context
+            .i32_type()
+            .const_all_ones()
+            .const_to_pointer(ptr);
+
         let mut export_list = Vec::new();
         Self::declare_externals(&mut binary);
         Self::emit_functions_with_spec(
@@ -56,10 +194,9 @@ impl SorobanTarget {
             contract_no,
             &mut export_list,
         );
+        export_list.extend(HOST_FUNCTIONS.iter().map(|(name, _)| *name));
         binary.internalize(export_list.as_slice());
 
-        Self::emit_initializer(&mut binary, ns);
-
         Self::emit_env_meta_entries(context, &mut binary, opt);
 
         binary
@@ -76,13 +213,21 @@ impl SorobanTarget {
         export_list: &mut Vec<&'a str>,
     ) {
         let mut defines = Vec::new();
+        let mut udts = Vec::new();
 
         for (cfg_no, cfg) in contract.cfg.iter().enumerate() {
-            let ftype = binary.function_type(
-                &cfg.params.iter().map(|p| p.ty.clone()).collect::<Vec<_>>(),
-                &cfg.returns.iter().map(|p| p.ty.clone()).collect::<Vec<_>>(),
-                ns,
-            );
+            // Exported functions take and return `Val`s, whatever their Solidity types are
+            let ftype = if cfg.public {
+                let val_ty = context.i64_type();
+
+                val_ty.fn_type(&vec![val_ty.into(); cfg.params.len()], false)
+            } else {
+                binary.function_type(
+                    &cfg.params.iter().map(|p| p.ty.clone()).collect::<Vec<_>>(),
+                    &cfg.returns.iter().map(|p| p.ty.clone()).collect::<Vec<_>>(),
+                    ns,
+                )
+            };
 
             // For each function, determine the name and the linkage
             // Soroban has no dispatcher, so all externally addressable functions are exported and should be named the same as the original function name in the source code.
@@ -97,7 +242,14 @@ impl SorobanTarget {
                 } else {
                     &cfg.name
                 };
-                Self::emit_function_spec_entry(context, cfg, name.to_string(), binary);
+                Self::emit_function_spec_entry(
+                    context,
+                    cfg,
+                    name.to_string(),
+                    binary,
+                    ns,
+                    &mut udts,
+                );
                 export_list.push(name);
                 Linkage::External
             } else {
@@ -125,7 +277,34 @@ impl SorobanTarget {
 
         for (func_decl, cfg) in defines {
             emit_cfg(&mut SorobanTarget, binary, contract, cfg, func_decl, ns);
+
+            if cfg.public {
+                Self::emit_heap_init(binary, func_decl);
+            }
         }
+
+        // Events are left out of the spec, since this version of the spec has no entry kind
+        // for them
+        Self::emit_udt_spec_entries(context, binary, ns, udts);
+        Self::emit_error_spec_entry(context, binary, contract, ns);
+    }
+
+    /// Exported functions are entry points into the contract, so the heap must be
+    /// initialized before the function body runs.
+    fn emit_heap_init(binary: &Binary, function: FunctionValue) {
+        let entry = function
+            .get_first_basic_block()
+            .expect("function should have a body");
+
+        match entry.get_first_instruction() {
+            Some(instr) => binary.builder.position_before(&instr),
+            None => binary.builder.position_at_end(entry),
+        }
+
+        binary
+            .builder
+            .build_call(binary.module.get_function("__init_heap").unwrap(), &[], "")
+            .unwrap();
     }
 
     fn emit_env_meta_entries<'a>(context: &'a Context, binary: &mut Binary<'a>, opt: &'a Options) {
@@ -143,9 +322,10 @@ impl SorobanTarget {
         cfg: &ControlFlowGraph,
         name: String,
         binary: &mut Binary<'a>,
+        ns: &ast::Namespace,
+        udts: &mut Vec<ast::Type>,
     ) {
         if cfg.public && !cfg.is_placeholder() {
-            // TODO: Emit custom type spec entries
             let mut spec = DepthLimitedWrite::new(Vec::new(), 10);
             ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
                 name: name
@@ -163,8 +343,8 @@ impl SorobanTarget {
                             .unwrap_or_else(|| i.to_string())
                             .try_into()
                             .expect("function input name exceeds limit"),
-                        type_: ScSpecTypeDef::U64, // TODO: Map type.
-                        doc: StringM::default(),   // TODO: Add doc.
+                        type_: Self::spec_type(&p.ty, ns, udts),
+                        doc: StringM::default(), // TODO: Add doc.
                     })
                     .collect::<Vec<_>>()
                     .try_into()
@@ -172,20 +352,7 @@ impl SorobanTarget {
                 outputs: cfg
                     .returns
                     .iter()
-                    .map(|return_type| {
-                        let ty = return_type.ty.clone();
-                        match ty {
-                            ast::Type::Uint(32) => ScSpecTypeDef::U32,
-                            ast::Type::Uint(64) => ScSpecTypeDef::U64,
-                            ast::Type::Int(_) => ScSpecTypeDef::I32,
-                            ast::Type::Bool => ScSpecTypeDef::Bool,
-                            ast::Type::Address(_) => ScSpecTypeDef::Address,
-                            ast::Type::Bytes(_) => ScSpecTypeDef::Bytes,
-                            ast::Type::String => ScSpecTypeDef::String,
-                            ast::Type::Void => ScSpecTypeDef::Void,
-                            _ => panic!("unsupported return type {:?}", ty),
-                        }
-                    }) // TODO: Map type.
+                    .map(|return_type| Self::spec_type(&return_type.ty, ns, udts))
                     .collect::<Vec<_>>()
                     .try_into()
                     .expect("function output count exceeds limit"),
@@ -223,67 +390,13 @@ impl SorobanTarget {
 
     fn declare_externals(binary: &mut Binary) {
         let ty = binary.context.i64_type();
-        let function_ty_1 = binary
-            .context
-            .i64_type()
-            .fn_type(&[ty.into(), ty.into(), ty.into()], false);
-        let function_ty = binary
-            .context
-            .i64_type()
-            .fn_type(&[ty.into(), ty.into()], false);
 
-        let log_function_ty = binary
-            .context
-            .i64_type()
-            .fn_type(&[ty.into(), ty.into(), ty.into(), ty.into()], false);
-
-        binary
-            .module
-            .add_function(PUT_CONTRACT_DATA, function_ty_1, Some(Linkage::External));
-        binary
-            .module
-            .add_function(GET_CONTRACT_DATA, function_ty, Some(Linkage::External));
-
-        binary.module.add_function(
-            LOG_FROM_LINEAR_MEMORY,
-            log_function_ty,
-            Some(Linkage::External),
-        );
-    }
-
-    fn emit_initializer(binary: &mut Binary, _ns: &ast::Namespace) {
-        let mut cfg = ControlFlowGraph::new("init".to_string(), ASTFunction::None);
+        for (name, args) in HOST_FUNCTIONS {
+            let function_ty = ty.fn_type(&vec![ty.into(); *args], false);
 
-        cfg.public = true;
-        let void_param = ast::Parameter::new_default(ast::Type::Void);
-        cfg.returns = sync::Arc::new(vec![void_param]);
-
-        Self::emit_function_spec_entry(binary.context, &cfg, "init".to_string(), binary);
-
-        let function_name = CString::new(STORAGE_INITIALIZER).unwrap();
-        let mut storage_initializers = binary
-            .functions
-            .values()
-            .filter(|f: &&inkwell::values::FunctionValue| f.get_name() == function_name.as_c_str());
-        let storage_initializer = *storage_initializers
-            .next()
-            .expect("storage initializer is always present");
-        assert!(storage_initializers.next().is_none());
-
-        let void_type = binary.context.i64_type().fn_type(&[], false);
-        let init = binary
-            .module
-            .add_function("init", void_type, Some(Linkage::External));
-        let entry = binary.context.append_basic_block(init, "entry");
-
-        binary.builder.position_at_end(entry);
-        binary
-            .builder
-            .build_call(storage_initializer, &[], "storage_initializer")
-            .unwrap();
-
-        // return zero
-        let zero_val = binary.context.i64_type().const_int(2, false);
-        binary.builder.build_return(Some(&zero_val)).unwrap();
+            binary
+                .module
+                .add_function(name, function_ty, Some(Linkage::External));
+        }
     }
 }
@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::codegen::revert::soroban_error_code;
+use crate::emit::binary::Binary;
+use crate::emit::soroban::SorobanTarget;
+use crate::sema::ast::{self, Namespace, Type};
+use inkwell::context::Context;
+use soroban_sdk::xdr::{
+    DepthLimitedWrite, ScSpecEntry, ScSpecTypeBytesN, ScSpecTypeDef, ScSpecTypeUdt,
+    ScSpecUdtEnumCaseV0, ScSpecUdtEnumV0, ScSpecUdtErrorEnumCaseV0, ScSpecUdtErrorEnumV0, StringM,
+    WriteXdr,
+};
+
+/// Name of the spec entry which lists the custom errors
+const ERROR_ENUM_NAME: &str = "Error";
+
+impl SorobanTarget {
+    /// Map a Solidity type onto its spec type. Enums are referred to by name, and are added to
+    /// `udts` so that their definitions can be written to the spec too. Sema only allows types
+    /// which are passed as a single `Val`, so there are no structs, arrays or mappings here.
+    pub(super) fn spec_type(ty: &Type, ns: &Namespace, udts: &mut Vec<Type>) -> ScSpecTypeDef {
+        match ty {
+            Type::Bool => ScSpecTypeDef::Bool,
+            Type::Void => ScSpecTypeDef::Void,
+            Type::Uint(n) if *n <= 32 => ScSpecTypeDef::U32,
+            Type::Uint(n) if *n <= 64 => ScSpecTypeDef::U64,
+            Type::Uint(n) if *n <= 128 => ScSpecTypeDef::U128,
+            Type::Uint(_) => ScSpecTypeDef::U256,
+            Type::Int(n) if *n <= 32 => ScSpecTypeDef::I32,
+            Type::Int(n) if *n <= 64 => ScSpecTypeDef::I64,
+            Type::Int(n) if *n <= 128 => ScSpecTypeDef::I128,
+            Type::Int(_) => ScSpecTypeDef::I256,
+            Type::Address(_) | Type::Contract(_) => ScSpecTypeDef::Address,
+            Type::String => ScSpecTypeDef::String,
+            Type::DynamicBytes => ScSpecTypeDef::Bytes,
+            Type::Bytes(n) => ScSpecTypeDef::BytesN(ScSpecTypeBytesN { n: *n as u32 }),
+            Type::Enum(enum_no) => {
+                if !udts.contains(ty) {
+                    udts.push(ty.clone());
+                }
+
+                Self::udt(&ns.enums[*enum_no].id.name)
+            }
+            Type::UserType(user_type_no) => {
+                Self::spec_type(&ns.user_types[*user_type_no].ty, ns, udts)
+            }
+            Type::Ref(ty) | Type::StorageRef(_, ty) => Self::spec_type(ty, ns, udts),
+            _ => panic!("{} is not supported in the spec", ty.to_string(ns)),
+        }
+    }
+
+    fn udt(name: &str) -> ScSpecTypeDef {
+        ScSpecTypeDef::Udt(ScSpecTypeUdt {
+            name: name
+                .to_string()
+                .try_into()
+                .unwrap_or_else(|_| panic!("type name {:?} exceeds limit", name)),
+        })
+    }
+
+    /// Write the definitions of the enums used by the contract interface
+    pub(super) fn emit_udt_spec_entries<'a>(
+        context: &'a Context,
+        binary: &mut Binary<'a>,
+        ns: &Namespace,
+        udts: Vec<Type>,
+    ) {
+        for udt in udts {
+            let entry = match udt {
+                Type::Enum(enum_no) => {
+                    let decl = &ns.enums[enum_no];
+
+                    ScSpecEntry::UdtEnumV0(ScSpecUdtEnumV0 {
+                        doc: StringM::default(),
+                        lib: StringM::default(),
+                        name: decl.id.name.clone().try_into().unwrap(),
+                        cases: decl
+                            .values
+                            .keys()
+                            .enumerate()
+                            .map(|(value, name)| ScSpecUdtEnumCaseV0 {
+                                doc: StringM::default(),
+                                name: name
+                                    .clone()
+                                    .try_into()
+                                    .expect("enum value name exceeds limit"),
+                                value: value as u32,
+                            })
+                            .collect::<Vec<_>>()
+                            .try_into()
+                            .expect("enum value count exceeds limit"),
+                    })
+                }
+                _ => unreachable!(),
+            };
+
+            let mut spec = DepthLimitedWrite::new(Vec::new(), 10);
+            entry
+                .write_xdr(&mut spec)
+                .expect("writing type spec to xdr");
+
+            Self::add_custom_section(context, &binary.module, "contractspecv0", spec.inner);
+        }
+    }
+
+    /// The custom errors of the contract are listed as the cases of a single error enum, with
+    /// the code of the contract error the invocation fails with, see `soroban_error_code`.
+    pub(super) fn emit_error_spec_entry<'a>(
+        context: &'a Context,
+        binary: &mut Binary<'a>,
+        contract: &ast::Contract,
+        ns: &Namespace,
+    ) {
+        if contract.errors.is_empty() {
+            return;
+        }
+
+        let mut spec = DepthLimitedWrite::new(Vec::new(), 10);
+        ScSpecEntry::UdtErrorEnumV0(ScSpecUdtErrorEnumV0 {
+            doc: StringM::default(),
+            lib: StringM::default(),
+            name: ERROR_ENUM_NAME.try_into().unwrap(),
+            cases: contract
+                .errors
+                .iter()
+                .map(|error_no| ScSpecUdtErrorEnumCaseV0 {
+                    doc: StringM::default(),
+                    name: ns.errors[*error_no]
+                        .name
+                        .clone()
+                        .try_into()
+                        .expect("error name exceeds limit"),
+                    value: soroban_error_code(contract, *error_no),
+                })
+                .collect::<Vec<_>>()
+                .try_into()
+                .expect("error count exceeds limit"),
+        })
+        .write_xdr(&mut spec)
+        .expect("writing error spec to xdr");
+
+        Self::add_custom_section(context, &binary.module, "contractspecv0", spec.inner);
+    }
+}
@@ -0,0 +1,454 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::codegen::revert::PanicCode;
+use crate::emit::binary::Binary;
+use crate::emit::soroban::val::{
+    pointer_to_val, u32_to_val, val_to_u32, val_to_vector, vector_to_val, TAG_U64_SMALL,
+};
+use crate::emit::soroban::{
+    SorobanTarget, BUMP_CONTRACT_DATA, BYTES_LEN, BYTES_NEW, DEL_CONTRACT_DATA, GET_CONTRACT_DATA,
+    HAS_CONTRACT_DATA, PUT_CONTRACT_DATA, VEC_NEW_FROM_LINEAR_MEMORY, VEC_PUSH_BACK,
+};
+use crate::emit::TargetRuntime;
+use crate::emit_context;
+use crate::sema::ast::{Namespace, StorageType};
+use inkwell::values::{BasicValueEnum, FunctionValue, IntValue, PointerValue};
+use inkwell::{AddressSpace, IntPredicate};
+use solang_parser::pt::Loc;
+
+/// Storage keys on Soroban are `Val`s. The key for a state variable is a small `U64Val`,
+/// which holds its slot number. Elements of mappings and arrays are keyed by a `Vec` of the
+/// key of the state variable, followed by the indices. The storage type of an access is
+/// known at compile time, and is passed to the host functions.
+impl SorobanTarget {
+    /// Returns true if the key is for an element of a mapping or array
+    fn is_element_key<'a>(&self, bin: &Binary<'a>, key: IntValue<'a>) -> IntValue<'a> {
+        emit_context!(bin);
+
+        let tag = bin.builder.build_and(key, i64_const!(0xff), "tag").unwrap();
+
+        bin.builder
+            .build_int_compare(
+                IntPredicate::NE,
+                tag,
+                i64_const!(TAG_U64_SMALL),
+                "is_element",
+            )
+            .unwrap()
+    }
+
+    /// Returns true if the key exists in contract data
+    pub(super) fn has_contract_data<'a>(
+        &self,
+        bin: &Binary<'a>,
+        key: IntValue<'a>,
+        storage_type: StorageType,
+    ) -> IntValue<'a> {
+        emit_context!(bin);
+
+        let storage_type = i64_const!(storage_type as u64);
+
+        let exists = call!(HAS_CONTRACT_DATA, &[key.into(), storage_type.into()])
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+        // The result is a boolean Val, which is 1 for true
+        bin.builder
+            .build_int_compare(IntPredicate::EQ, exists, i64_const!(1), "exists")
+            .unwrap()
+    }
+
+    /// Fetch the value for a key from contract data. The key must exist.
+    pub(super) fn get_contract_data<'a>(
+        &self,
+        bin: &Binary<'a>,
+        key: IntValue<'a>,
+        storage_type: StorageType,
+    ) -> IntValue<'a> {
+        emit_context!(bin);
+
+        let storage_type = i64_const!(storage_type as u64);
+
+        call!(GET_CONTRACT_DATA, &[key.into(), storage_type.into()])
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value()
+    }
+
+    /// Set the value for a key in contract data
+    pub(super) fn put_contract_data<'a>(
+        &self,
+        bin: &Binary<'a>,
+        key: IntValue<'a>,
+        value: IntValue<'a>,
+        storage_type: StorageType,
+    ) {
+        emit_context!(bin);
+
+        let storage_type = i64_const!(storage_type as u64);
+
+        call!(
+            PUT_CONTRACT_DATA,
+            &[key.into(), value.into(), storage_type.into()]
+        );
+    }
+
+    /// Remove a key from contract data
+    pub(super) fn del_contract_data<'a>(
+        &self,
+        bin: &Binary<'a>,
+        key: IntValue<'a>,
+        storage_type: StorageType,
+    ) {
+        emit_context!(bin);
+
+        let storage_type = i64_const!(storage_type as u64);
+
+        call!(DEL_CONTRACT_DATA, &[key.into(), storage_type.into()]);
+    }
+
+    /// Fetch the value for a key from contract data, or use the given default if the key
+    /// does not exist. The `fetched` closure converts the `Val` from storage.
+    pub(super) fn get_contract_data_or_default<'a, F>(
+        &self,
+        bin: &Binary<'a>,
+        function: FunctionValue<'a>,
+        key: IntValue<'a>,
+        storage_type: StorageType,
+        default: BasicValueEnum<'a>,
+        fetched: F,
+    ) -> BasicValueEnum<'a>
+    where
+        F: FnOnce(IntValue<'a>) -> BasicValueEnum<'a>,
+    {
+        let exists = self.has_contract_data(bin, key, storage_type);
+
+        let entry = bin.builder.get_insert_block().unwrap();
+        let retrieve_block = bin.context.append_basic_block(function, "in_storage");
+        let done_storage = bin.context.append_basic_block(function, "done_storage");
+
+        bin.builder
+            .build_conditional_branch(exists, retrieve_block, done_storage)
+            .unwrap();
+
+        bin.builder.position_at_end(retrieve_block);
+
+        let val = self.get_contract_data(bin, key, storage_type);
+        let value = fetched(val);
+
+        // the conversion may have added basic blocks
+        let retrieve_block = bin.builder.get_insert_block().unwrap();
+
+        bin.builder
+            .build_unconditional_branch(done_storage)
+            .unwrap();
+
+        bin.builder.position_at_end(done_storage);
+
+        let res = bin
+            .builder
+            .build_phi(default.get_type(), "storage_res")
+            .unwrap();
+
+        res.add_incoming(&[(&value, retrieve_block), (&default, entry)]);
+
+        res.as_basic_value()
+    }
+
+    /// Store a string or bytes as a Bytes object. An empty value is deleted from storage.
+    pub(super) fn set_storage_vector<'a>(
+        &self,
+        bin: &Binary<'a>,
+        function: FunctionValue<'a>,
+        key: IntValue<'a>,
+        storage_type: StorageType,
+        dest: BasicValueEnum<'a>,
+    ) {
+        emit_context!(bin);
+
+        let len = bin.vector_len(dest);
+
+        let exists = bin
+            .builder
+            .build_int_compare(IntPredicate::NE, len, i32_zero!(), "exists")
+            .unwrap();
+
+        let delete_block = bin.context.append_basic_block(function, "delete_block");
+        let set_block = bin.context.append_basic_block(function, "set_block");
+        let done_storage = bin.context.append_basic_block(function, "done_storage");
+
+        bin.builder
+            .build_conditional_branch(exists, set_block, delete_block)
+            .unwrap();
+
+        bin.builder.position_at_end(set_block);
+
+        let value = vector_to_val(bin, dest, false);
+
+        self.put_contract_data(bin, key, value, storage_type);
+
+        bin.builder
+            .build_unconditional_branch(done_storage)
+            .unwrap();
+
+        bin.builder.position_at_end(delete_block);
+
+        self.del_contract_data(bin, key, storage_type);
+
+        bin.builder
+            .build_unconditional_branch(done_storage)
+            .unwrap();
+
+        bin.builder.position_at_end(done_storage);
+    }
+
+    /// Load a string or bytes from storage. A missing key reads as empty.
+    pub(super) fn get_storage_vector<'a>(
+        &self,
+        bin: &Binary<'a>,
+        function: FunctionValue<'a>,
+        key: IntValue<'a>,
+        storage_type: StorageType,
+    ) -> PointerValue<'a> {
+        let default = bin
+            .module
+            .get_struct_type("struct.vector")
+            .unwrap()
+            .ptr_type(AddressSpace::default())
+            .const_null()
+            .into();
+
+        self.get_contract_data_or_default(bin, function, key, storage_type, default, |val| {
+            val_to_vector(bin, val, false).into()
+        })
+        .into_pointer_value()
+    }
+
+    /// Derive the storage key for an element of a mapping or array
+    pub(super) fn storage_element_key<'a>(
+        &self,
+        bin: &Binary<'a>,
+        function: FunctionValue<'a>,
+        key: IntValue<'a>,
+        index: IntValue<'a>,
+    ) -> IntValue<'a> {
+        emit_context!(bin);
+
+        let nested_block = bin.context.append_basic_block(function, "nested_key");
+        let new_block = bin.context.append_basic_block(function, "new_key");
+        let done_block = bin.context.append_basic_block(function, "element_key");
+
+        bin.builder
+            .build_conditional_branch(self.is_element_key(bin, key), nested_block, new_block)
+            .unwrap();
+
+        // the key of an element of an element gets the index appended
+        bin.builder.position_at_end(nested_block);
+
+        let nested_key = call!(VEC_PUSH_BACK, &[key.into(), index.into()])
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+        bin.builder.build_unconditional_branch(done_block).unwrap();
+
+        bin.builder.position_at_end(new_block);
+
+        let vals_ty = bin.context.i64_type().array_type(2);
+
+        let vals = bin.build_alloca(function, vals_ty, "key_vals");
+
+        for (no, val) in [key, index].into_iter().enumerate() {
+            let elem = unsafe {
+                bin.builder
+                    .build_gep(
+                        vals_ty,
+                        vals,
+                        &[i32_zero!(), i32_const!(no as u64)],
+                        "key_val",
+                    )
+                    .unwrap()
+            };
+
+            bin.builder.build_store(elem, val).unwrap();
+        }
+
+        let new_key = call!(
+            VEC_NEW_FROM_LINEAR_MEMORY,
+            &[
+                pointer_to_val(bin, vals).into(),
+                u32_to_val(bin, i32_const!(2)).into()
+            ]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        bin.builder.build_unconditional_branch(done_block).unwrap();
+
+        bin.builder.position_at_end(done_block);
+
+        let element_key = bin
+            .builder
+            .build_phi(bin.context.i64_type(), "element_key")
+            .unwrap();
+
+        element_key.add_incoming(&[(&nested_key, nested_block), (&new_key, new_block)]);
+
+        element_key.as_basic_value().into_int_value()
+    }
+
+    /// Extend the TTL of the contract data at the given key, if it exists. Entries which were
+    /// never written or have been deleted read as the default value.
+    pub(super) fn extend_ttl<'a>(
+        &self,
+        bin: &Binary<'a>,
+        function: FunctionValue<'a>,
+        key: IntValue<'a>,
+        storage_type: IntValue<'a>,
+        threshold: IntValue<'a>,
+        extend_to: IntValue<'a>,
+    ) {
+        emit_context!(bin);
+
+        let exists = call!(HAS_CONTRACT_DATA, &[key.into(), storage_type.into()])
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+        let exists = bin
+            .builder
+            .build_int_compare(IntPredicate::EQ, exists, i64_const!(1), "exists")
+            .unwrap();
+
+        let exists_block = bin.context.append_basic_block(function, "extend_exists");
+        let done_block = bin.context.append_basic_block(function, "extend_done");
+
+        bin.builder
+            .build_conditional_branch(exists, exists_block, done_block)
+            .unwrap();
+
+        bin.builder.position_at_end(exists_block);
+
+        call!(
+            BUMP_CONTRACT_DATA,
+            &[
+                key.into(),
+                storage_type.into(),
+                u32_to_val(bin, threshold).into(),
+                u32_to_val(bin, extend_to).into()
+            ]
+        );
+
+        bin.builder.build_unconditional_branch(done_block).unwrap();
+
+        bin.builder.position_at_end(done_block);
+    }
+
+    /// Fetch the bytes object stored at the given key, or a new empty one if there is none
+    pub(super) fn get_storage_bytes<'a>(
+        &self,
+        bin: &Binary<'a>,
+        function: FunctionValue<'a>,
+        key: IntValue<'a>,
+        storage_type: StorageType,
+    ) -> IntValue<'a> {
+        emit_context!(bin);
+
+        let exists = self.has_contract_data(bin, key, storage_type);
+
+        let retrieve_block = bin.context.append_basic_block(function, "in_storage");
+        let new_block = bin.context.append_basic_block(function, "new_bytes");
+        let done_storage = bin.context.append_basic_block(function, "done_storage");
+
+        bin.builder
+            .build_conditional_branch(exists, retrieve_block, new_block)
+            .unwrap();
+
+        bin.builder.position_at_end(retrieve_block);
+
+        let stored = self.get_contract_data(bin, key, storage_type);
+
+        bin.builder
+            .build_unconditional_branch(done_storage)
+            .unwrap();
+
+        bin.builder.position_at_end(new_block);
+
+        let empty = call!(BYTES_NEW, &[])
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+        bin.builder
+            .build_unconditional_branch(done_storage)
+            .unwrap();
+
+        bin.builder.position_at_end(done_storage);
+
+        let bytes = bin
+            .builder
+            .build_phi(bin.context.i64_type(), "bytes")
+            .unwrap();
+
+        bytes.add_incoming(&[(&stored, retrieve_block), (&empty, new_block)]);
+
+        bytes.as_basic_value().into_int_value()
+    }
+
+    /// Abort if the index is not within the bytes object
+    pub(super) fn storage_bytes_bounds_check<'a>(
+        &self,
+        bin: &Binary<'a>,
+        function: FunctionValue<'a>,
+        bytes: IntValue<'a>,
+        index: IntValue<'a>,
+        loc: Loc,
+        ns: &Namespace,
+    ) {
+        emit_context!(bin);
+
+        let length = call!(BYTES_LEN, &[bytes.into()])
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+        let in_range = bin
+            .builder
+            .build_int_compare(
+                IntPredicate::ULT,
+                index,
+                val_to_u32(bin, length),
+                "index_in_range",
+            )
+            .unwrap();
+
+        let in_range_block = bin.context.append_basic_block(function, "in_range");
+        let bang_block = bin.context.append_basic_block(function, "bang_block");
+
+        bin.builder
+            .build_conditional_branch(in_range, in_range_block, bang_block)
+            .unwrap();
+
+        bin.builder.position_at_end(bang_block);
+
+        bin.log_runtime_error(
+            self,
+            "storage array index out of bounds".to_string(),
+            Some(loc),
+            ns,
+        );
+        let (revert_out, revert_out_len) = bin.panic_data_const(ns, PanicCode::ArrayIndexOob);
+        self.assert_failure(bin, revert_out, revert_out_len);
+
+        bin.builder.position_at_end(in_range_block);
+    }
+}
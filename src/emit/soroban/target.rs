@@ -1,24 +1,35 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::codegen::cfg::HashTy;
-use crate::codegen::Expression;
+use crate::codegen::revert::PanicCode;
+use crate::codegen::{Builtin, Expression};
 use crate::emit::binary::Binary;
+use crate::emit::expression::expression;
+use crate::emit::soroban::val::{
+    from_val, pointer_to_val, symbol_to_val, to_val, u32_to_val, val_to_u32, vals_to_vec,
+    TAG_ERROR, TAG_U32, TAG_VOID,
+};
 use crate::emit::soroban::{
-    SorobanTarget, GET_CONTRACT_DATA, LOG_FROM_LINEAR_MEMORY, PUT_CONTRACT_DATA,
+    SorobanTarget, BUMP_CURRENT_CONTRACT_INSTANCE_AND_CODE, BYTES_BACK, BYTES_GET, BYTES_LEN,
+    BYTES_NEW_FROM_LINEAR_MEMORY, BYTES_POP, BYTES_PUSH, BYTES_PUT, CONTRACT_EVENT,
+    CREATE_CONTRACT, FAIL_WITH_ERROR, GET_CURRENT_CONTRACT_ADDRESS, GET_LEDGER_SEQUENCE,
+    GET_LEDGER_TIMESTAMP, LOG_FROM_LINEAR_MEMORY, OBJ_CMP, PRNG_BYTES_NEW, REQUIRE_AUTH,
+    REQUIRE_AUTH_FOR_ARGS, UPLOAD_WASM, VEC_NEW_FROM_LINEAR_MEMORY,
 };
 use crate::emit::ContractArgs;
 use crate::emit::{TargetRuntime, Variable};
 use crate::emit_context;
 use crate::sema::ast;
 use crate::sema::ast::CallTy;
-use crate::sema::ast::{Function, Namespace, Type};
+use crate::sema::ast::{ArrayLength, Function, Namespace, RetrieveType, StorageType, Type};
 
 use inkwell::types::{BasicTypeEnum, IntType};
 use inkwell::values::{
-    ArrayValue, BasicMetadataValueEnum, BasicValue, BasicValueEnum, FunctionValue, IntValue,
-    PointerValue,
+    ArrayValue, BasicMetadataValueEnum, BasicValueEnum, FunctionValue, IntValue, PointerValue,
 };
 
+use inkwell::IntPredicate;
+use num_traits::ToPrimitive;
 use solang_parser::pt::Loc;
 
 use std::collections::HashMap;
@@ -30,7 +41,8 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         &self,
         binary: &Binary<'a>,
         function: FunctionValue,
-        slot: PointerValue<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         ty: IntType<'a>,
     ) -> IntValue<'a> {
         todo!()
@@ -41,26 +53,34 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         binary: &Binary<'a>,
         ty: &ast::Type,
         slot: &mut IntValue<'a>,
+        storage_type: Option<StorageType>,
         function: FunctionValue<'a>,
         ns: &ast::Namespace,
     ) -> BasicValueEnum<'a> {
-        emit_context!(binary);
-        let ret = call!(
-            GET_CONTRACT_DATA,
-            &[
-                slot.as_basic_value_enum()
-                    .into_int_value()
-                    .const_cast(binary.context.i64_type(), false)
-                    .into(),
-                i64_const!(2).into()
-            ]
-        )
-        .try_as_basic_value()
-        .left()
-        .unwrap()
-        .into_int_value();
+        match ty {
+            Type::String | Type::DynamicBytes => self
+                .get_storage_vector(binary, function, *slot, storage_type.unwrap())
+                .into(),
+            Type::Ref(ty) | Type::StorageRef(_, ty) => {
+                self.storage_load(binary, ty, slot, storage_type, function, ns)
+            }
+            _ if !ty.is_reference_type(ns) => {
+                let default = binary.llvm_type(ty, ns).const_zero();
 
-        ret.into()
+                self.get_contract_data_or_default(
+                    binary,
+                    function,
+                    *slot,
+                    storage_type.unwrap(),
+                    default,
+                    |val| from_val(binary, function, ty, val, ns),
+                )
+            }
+            _ => unimplemented!(
+                "loading {} from storage is not supported on Soroban",
+                ty.to_string(ns)
+            ),
+        }
     }
 
     /// Recursively store a type to storage
@@ -70,44 +90,111 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         ty: &ast::Type,
         existing: bool,
         slot: &mut IntValue<'a>,
+        storage_type: Option<StorageType>,
         dest: BasicValueEnum<'a>,
         function: FunctionValue<'a>,
         ns: &ast::Namespace,
     ) {
-        emit_context!(binary);
-        let function_value = binary.module.get_function(PUT_CONTRACT_DATA).unwrap();
+        match ty {
+            Type::String | Type::DynamicBytes => {
+                self.set_storage_vector(binary, function, *slot, storage_type.unwrap(), dest);
+            }
+            Type::Ref(ty) | Type::StorageRef(_, ty) => {
+                self.storage_store(binary, ty, existing, slot, storage_type, dest, function, ns);
+            }
+            _ if !ty.is_reference_type(ns) => {
+                let value = to_val(binary, function, ty, dest, ns);
 
-        let value = binary
-            .builder
-            .build_call(
-                function_value,
-                &[
-                    slot.as_basic_value_enum()
-                        .into_int_value()
-                        .const_cast(binary.context.i64_type(), false)
-                        .into(),
-                    dest.into(),
-                    binary.context.i64_type().const_int(2, false).into(),
-                ],
-                PUT_CONTRACT_DATA,
-            )
-            .unwrap()
-            .try_as_basic_value()
-            .left()
-            .unwrap()
-            .into_int_value();
+                self.put_contract_data(binary, *slot, value, storage_type.unwrap());
+            }
+            _ => unimplemented!(
+                "storing {} in storage is not supported on Soroban",
+                ty.to_string(ns)
+            ),
+        }
     }
 
-    /// Recursively clear storage. The default implementation is for slot-based storage
+    /// Recursively clear storage. The elements of dynamic arrays each have their own key,
+    /// so these are removed one by one.
     fn storage_delete(
         &self,
         bin: &Binary<'a>,
         ty: &Type,
         slot: &mut IntValue<'a>,
+        storage_type: Option<StorageType>,
         function: FunctionValue<'a>,
         ns: &Namespace,
     ) {
-        unimplemented!()
+        emit_context!(bin);
+
+        match ty.deref_any() {
+            Type::Array(_, dim) => {
+                let length = match dim.last().unwrap() {
+                    ArrayLength::Fixed(length) => i64_const!(length.to_u64().unwrap()),
+                    _ => self
+                        .storage_load(bin, &ns.storage_type(), slot, storage_type, function, ns)
+                        .into_int_value(),
+                };
+
+                let elem_ty = ty.storage_array_elem().deref_into();
+
+                let entry = bin.builder.get_insert_block().unwrap();
+                let body = bin.context.append_basic_block(function, "delete_elem");
+                let done = bin.context.append_basic_block(function, "delete_done");
+
+                let is_empty = bin
+                    .builder
+                    .build_int_compare(IntPredicate::EQ, length, i64_zero!(), "is_empty")
+                    .unwrap();
+
+                bin.builder
+                    .build_conditional_branch(is_empty, done, body)
+                    .unwrap();
+
+                bin.builder.position_at_end(body);
+
+                let index = bin
+                    .builder
+                    .build_phi(bin.context.i64_type(), "index")
+                    .unwrap();
+
+                index.add_incoming(&[(&i64_zero!(), entry)]);
+
+                let index_val = to_val(
+                    bin,
+                    function,
+                    &ns.storage_type(),
+                    index.as_basic_value(),
+                    ns,
+                );
+
+                let mut elem_slot = self.storage_element_key(bin, function, *slot, index_val);
+
+                self.storage_delete(bin, &elem_ty, &mut elem_slot, storage_type, function, ns);
+
+                let next = bin
+                    .builder
+                    .build_int_add(index.as_basic_value().into_int_value(), i64_const!(1), "")
+                    .unwrap();
+
+                index.add_incoming(&[(&next, bin.builder.get_insert_block().unwrap())]);
+
+                let more = bin
+                    .builder
+                    .build_int_compare(IntPredicate::ULT, next, length, "more")
+                    .unwrap();
+
+                bin.builder
+                    .build_conditional_branch(more, body, done)
+                    .unwrap();
+
+                bin.builder.position_at_end(done);
+
+                self.del_contract_data(bin, *slot, storage_type.unwrap());
+            }
+            Type::Struct(_) => unreachable!("structs in storage are rejected by sema on Soroban"),
+            _ => self.del_contract_data(bin, *slot, storage_type.unwrap()),
+        }
     }
 
     // Bytes and string have special storage layout
@@ -115,7 +202,8 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         &self,
         bin: &Binary<'a>,
         function: FunctionValue<'a>,
-        slot: PointerValue<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         dest: BasicValueEnum<'a>,
     ) {
         unimplemented!()
@@ -125,7 +213,8 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         &self,
         bin: &Binary<'a>,
         function: FunctionValue,
-        slot: PointerValue<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
     ) -> PointerValue<'a> {
         unimplemented!()
     }
@@ -134,7 +223,8 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         &self,
         bin: &Binary<'a>,
         function: FunctionValue,
-        slot: PointerValue,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         dest: PointerValue,
         dest_ty: BasicTypeEnum,
     ) {
@@ -145,7 +235,8 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         &self,
         bin: &Binary<'a>,
         function: FunctionValue,
-        slot: PointerValue<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         ns: &Namespace,
     ) -> PointerValue<'a> {
         unimplemented!()
@@ -156,11 +247,33 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         bin: &Binary<'a>,
         function: FunctionValue,
         slot: IntValue<'a>,
+        storage_type: Option<StorageType>,
         index: IntValue<'a>,
         loc: Loc,
         ns: &Namespace,
     ) -> IntValue<'a> {
-        unimplemented!()
+        emit_context!(bin);
+
+        let function = bin
+            .builder
+            .get_insert_block()
+            .unwrap()
+            .get_parent()
+            .unwrap();
+
+        let bytes = self.get_storage_bytes(bin, function, slot, storage_type.unwrap());
+
+        self.storage_bytes_bounds_check(bin, function, bytes, index, loc, ns);
+
+        let value = call!(BYTES_GET, &[bytes.into(), u32_to_val(bin, index).into()])
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+        bin.builder
+            .build_int_truncate(val_to_u32(bin, value), bin.context.i8_type(), "byte")
+            .unwrap()
     }
 
     fn set_storage_bytes_subscript(
@@ -168,12 +281,40 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         bin: &Binary<'a>,
         function: FunctionValue,
         slot: IntValue<'a>,
+        storage_type: Option<StorageType>,
         index: IntValue<'a>,
         value: IntValue<'a>,
         ns: &Namespace,
         loc: Loc,
     ) {
-        unimplemented!()
+        emit_context!(bin);
+
+        let function = bin
+            .builder
+            .get_insert_block()
+            .unwrap()
+            .get_parent()
+            .unwrap();
+
+        let bytes = self.get_storage_bytes(bin, function, slot, storage_type.unwrap());
+
+        self.storage_bytes_bounds_check(bin, function, bytes, index, loc, ns);
+
+        // host objects are immutable, so this creates a new bytes object
+        let bytes = call!(
+            BYTES_PUT,
+            &[
+                bytes.into(),
+                u32_to_val(bin, index).into(),
+                u32_to_val(bin, value).into()
+            ]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        self.put_contract_data(bin, slot, bytes, storage_type.unwrap());
     }
 
     fn storage_subscript(
@@ -185,7 +326,14 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         index: BasicValueEnum<'a>,
         ns: &Namespace,
     ) -> IntValue<'a> {
-        unimplemented!()
+        let index_ty = match ty.deref_any() {
+            Type::Mapping(mapping) => mapping.key.as_ref().clone(),
+            _ => ns.storage_type(),
+        };
+
+        let index = to_val(bin, function, &index_ty, index, ns);
+
+        self.storage_element_key(bin, function, slot, index)
     }
 
     fn storage_push(
@@ -194,10 +342,29 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         function: FunctionValue<'a>,
         ty: &Type,
         slot: IntValue<'a>,
+        storage_type: Option<StorageType>,
         val: Option<BasicValueEnum<'a>>,
         ns: &Namespace,
     ) -> BasicValueEnum<'a> {
-        unimplemented!()
+        emit_context!(bin);
+
+        // only storage bytes are pushed here, elements of arrays have their own keys
+        let value = val.unwrap_or_else(|| bin.context.i8_type().const_zero().into());
+
+        let bytes = self.get_storage_bytes(bin, function, slot, storage_type.unwrap());
+
+        let bytes = call!(
+            BYTES_PUSH,
+            &[bytes.into(), u32_to_val(bin, value.into_int_value()).into()]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        self.put_contract_data(bin, slot, bytes, storage_type.unwrap());
+
+        value
     }
 
     fn storage_pop(
@@ -206,111 +373,215 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         function: FunctionValue<'a>,
         ty: &Type,
         slot: IntValue<'a>,
+        storage_type: Option<StorageType>,
         load: bool,
         ns: &Namespace,
         loc: Loc,
     ) -> Option<BasicValueEnum<'a>> {
-        unimplemented!()
+        emit_context!(bin);
+
+        let bytes = self.get_storage_bytes(bin, function, slot, storage_type.unwrap());
+
+        let length = call!(BYTES_LEN, &[bytes.into()])
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+        let is_empty = bin
+            .builder
+            .build_int_compare(
+                IntPredicate::EQ,
+                val_to_u32(bin, length),
+                i32_zero!(),
+                "is_empty",
+            )
+            .unwrap();
+
+        let pop_block = bin.context.append_basic_block(function, "pop");
+        let empty_block = bin.context.append_basic_block(function, "empty");
+
+        bin.builder
+            .build_conditional_branch(is_empty, empty_block, pop_block)
+            .unwrap();
+
+        bin.builder.position_at_end(empty_block);
+
+        bin.log_runtime_error(
+            self,
+            "pop from empty storage array".to_string(),
+            Some(loc),
+            ns,
+        );
+        let (revert_out, revert_out_len) = bin.panic_data_const(ns, PanicCode::EmptyArrayPop);
+        self.assert_failure(bin, revert_out, revert_out_len);
+
+        bin.builder.position_at_end(pop_block);
+
+        let value = if load {
+            let value = call!(BYTES_BACK, &[bytes.into()])
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+
+            Some(
+                bin.builder
+                    .build_int_truncate(val_to_u32(bin, value), bin.context.i8_type(), "byte")
+                    .unwrap()
+                    .into(),
+            )
+        } else {
+            None
+        };
+
+        let bytes = call!(BYTES_POP, &[bytes.into()])
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+        self.put_contract_data(bin, slot, bytes, storage_type.unwrap());
+
+        value
     }
 
     fn storage_array_length(
         &self,
-        _bin: &Binary<'a>,
+        bin: &Binary<'a>,
         _function: FunctionValue,
-        _slot: IntValue<'a>,
+        slot: IntValue<'a>,
+        storage_type: Option<StorageType>,
         _elem_ty: &Type,
         _ns: &Namespace,
     ) -> IntValue<'a> {
-        unimplemented!()
+        emit_context!(bin);
+
+        // only called for storage bytes; the length of arrays is stored under their own key
+        let function = bin
+            .builder
+            .get_insert_block()
+            .unwrap()
+            .get_parent()
+            .unwrap();
+
+        let bytes = self.get_storage_bytes(bin, function, slot, storage_type.unwrap());
+
+        let length = call!(BYTES_LEN, &[bytes.into()])
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+        val_to_u32(bin, length)
     }
 
     /// keccak256 hash
     fn keccak256_hash(
         &self,
         bin: &Binary<'a>,
-        src: PointerValue,
-        length: IntValue,
-        dest: PointerValue,
+        src: PointerValue<'a>,
+        length: IntValue<'a>,
+        dest: PointerValue<'a>,
         ns: &Namespace,
     ) {
-        unimplemented!()
+        self.compute_hash(bin, HashTy::Keccak256, src, length, dest);
     }
 
-    /// Prints a string
-    /// TODO: Implement this function, with a call to the `log` function in the Soroban runtime.
+    /// Prints a string with the `log_from_linear_memory` host function
     fn print(&self, bin: &Binary, string: PointerValue, length: IntValue) {
-        if string.is_const() && length.is_const() {
-            let msg_pos = bin
-                .builder
-                .build_ptr_to_int(string, bin.context.i64_type(), "msg_pos")
-                .unwrap();
-            let msg_pos = msg_pos.const_cast(bin.context.i64_type(), false);
-
-            let length = length.const_cast(bin.context.i64_type(), false);
-
-            let eight = bin.context.i64_type().const_int(8, false);
-            let four = bin.context.i64_type().const_int(4, false);
-            let zero = bin.context.i64_type().const_int(0, false);
-            let thirty_two = bin.context.i64_type().const_int(32, false);
-
-            // XDR encode msg_pos and length
-            let msg_pos_encoded = bin
-                .builder
-                .build_left_shift(msg_pos, thirty_two, "temp")
-                .unwrap();
-            let msg_pos_encoded = bin
-                .builder
-                .build_int_add(msg_pos_encoded, four, "msg_pos_encoded")
-                .unwrap();
-
-            let length_encoded = bin
-                .builder
-                .build_left_shift(length, thirty_two, "temp")
-                .unwrap();
-            let length_encoded = bin
-                .builder
-                .build_int_add(length_encoded, four, "length_encoded")
-                .unwrap();
+        emit_context!(bin);
 
-            let zero_encoded = bin.builder.build_left_shift(zero, eight, "temp").unwrap();
+        // The arguments are `U32Val`s. This is done inline rather than with `u32_to_val`,
+        // since the string may not share the lifetime of the binary.
+        let msg_pos = bin
+            .builder
+            .build_ptr_to_int(string, bin.context.i64_type(), "msg_pos")
+            .unwrap();
+        let msg_pos = bin
+            .builder
+            .build_left_shift(msg_pos, i64_const!(32), "msg_pos")
+            .unwrap();
+        let msg_pos = bin
+            .builder
+            .build_or(msg_pos, i64_const!(TAG_U32), "msg_pos")
+            .unwrap();
 
-            let eight_encoded = bin.builder.build_left_shift(eight, eight, "temp").unwrap();
-            let eight_encoded = bin
-                .builder
-                .build_int_add(eight_encoded, four, "eight_encoded")
-                .unwrap();
+        let msg_len = bin
+            .builder
+            .build_int_z_extend_or_bit_cast(length, bin.context.i64_type(), "msg_len")
+            .unwrap();
+        let msg_len = bin
+            .builder
+            .build_left_shift(msg_len, i64_const!(32), "msg_len")
+            .unwrap();
+        let msg_len = bin
+            .builder
+            .build_or(msg_len, i64_const!(TAG_U32), "msg_len")
+            .unwrap();
 
-            let call_res = bin
-                .builder
-                .build_call(
-                    bin.module.get_function(LOG_FROM_LINEAR_MEMORY).unwrap(),
-                    &[
-                        msg_pos_encoded.into(),
-                        length_encoded.into(),
-                        msg_pos_encoded.into(),
-                        four.into(),
-                    ],
-                    "log",
-                )
-                .unwrap();
-        } else {
-            todo!("Dynamic String printing is not yet supported")
-        }
+        // no values are passed, so the message is not formatted by the host
+        call!(
+            LOG_FROM_LINEAR_MEMORY,
+            &[
+                msg_pos.into(),
+                msg_len.into(),
+                msg_pos.into(),
+                i64_const!(TAG_U32).into()
+            ]
+        );
     }
 
     /// Return success without any result
     fn return_empty_abi(&self, bin: &Binary) {
-        unimplemented!()
+        emit_context!(bin);
+
+        bin.builder
+            .build_return(Some(&i64_const!(TAG_VOID)))
+            .unwrap();
     }
 
-    /// Return failure code
+    /// Return failure code. Soroban functions cannot return a code, so the invocation fails
+    /// with a contract error carrying the code instead.
     fn return_code<'b>(&self, bin: &'b Binary, ret: IntValue<'b>) {
-        unimplemented!()
+        emit_context!(bin);
+
+        let error = bin
+            .builder
+            .build_int_z_extend(ret, bin.context.i64_type(), "code")
+            .unwrap();
+
+        let error = bin
+            .builder
+            .build_left_shift(error, i64_const!(32), "code")
+            .unwrap();
+
+        let error = bin
+            .builder
+            .build_or(error, i64_const!(TAG_ERROR), "error")
+            .unwrap();
+
+        call!(FAIL_WITH_ERROR, &[error.into()]);
+
+        bin.builder.build_unreachable().unwrap();
     }
 
-    /// Return failure without any result
+    /// Return failure without any result. There is no revert data on Soroban; the reason is
+    /// logged with `print` when runtime errors are logged. The only data is the code of a
+    /// custom error, which fails the invocation with a contract error carrying the code.
     fn assert_failure(&self, bin: &Binary, data: PointerValue, length: IntValue) {
-        bin.builder.build_unreachable().unwrap();
+        if length.get_zero_extended_constant() == Some(0) {
+            bin.builder.build_unreachable().unwrap();
+            return;
+        }
+
+        let code = bin
+            .builder
+            .build_load(bin.context.i32_type(), data, "code")
+            .unwrap()
+            .into_int_value();
+
+        self.return_code(bin, code);
     }
 
     fn builtin_function(
@@ -339,7 +610,74 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         ns: &Namespace,
         loc: Loc,
     ) {
-        unimplemented!()
+        emit_context!(bin);
+
+        let created_contract = &ns.contracts[contract_no];
+
+        let code = created_contract.emit(ns, bin.options, contract_no);
+
+        let wasm =
+            bin.emit_global_string(&format!("binary_{}_code", created_contract.id), &code, true);
+
+        let wasm = call!(
+            BYTES_NEW_FROM_LINEAR_MEMORY,
+            &[
+                pointer_to_val(bin, wasm).into(),
+                u32_to_val(bin, i32_const!(code.len() as u64)).into()
+            ]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap();
+
+        // uploading the same code again is a no-op, it just returns the hash
+        let wasm_hash = call!(UPLOAD_WASM, &[wasm.into()])
+            .try_as_basic_value()
+            .left()
+            .unwrap();
+
+        let deployer = call!(GET_CURRENT_CONTRACT_ADDRESS, &[])
+            .try_as_basic_value()
+            .left()
+            .unwrap();
+
+        let salt = if let Some(salt) = contract_args.salt {
+            let salt_buf = bin.build_alloca(function, salt.get_type(), "salt");
+            bin.builder.build_store(salt_buf, salt).unwrap();
+
+            call!(
+                BYTES_NEW_FROM_LINEAR_MEMORY,
+                &[
+                    pointer_to_val(bin, salt_buf).into(),
+                    u32_to_val(bin, i32_const!(32)).into()
+                ]
+            )
+        } else {
+            call!(PRNG_BYTES_NEW, &[u32_to_val(bin, i32_const!(32)).into()])
+        }
+        .try_as_basic_value()
+        .left()
+        .unwrap();
+
+        let contract = call!(
+            CREATE_CONTRACT,
+            &[deployer.into(), wasm_hash.into(), salt.into()]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        let address_val = from_val(bin, function, &Type::Address(false), contract, ns);
+        bin.builder.build_store(address, address_val).unwrap();
+
+        self.call_contract(
+            bin,
+            contract,
+            bin.vector_bytes(encoded_args),
+            encoded_args_len.into_int_value(),
+            success,
+        );
     }
 
     /// call external function
@@ -356,21 +694,62 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         ns: &Namespace,
         loc: Loc,
     ) {
-        unimplemented!()
+        emit_context!(bin);
+
+        if ty == CallTy::Delegate {
+            unreachable!("delegatecall is rejected by sema on Soroban");
+        }
+
+        let contract = bin
+            .builder
+            .build_load(bin.context.i64_type(), address.unwrap(), "contract")
+            .unwrap()
+            .into_int_value();
+
+        self.call_contract(bin, contract, payload, payload_len, success);
     }
 
-    /// send value to address
+    /// Send value to address. Value is the native token, so this is a transfer on the native
+    /// token contract.
     fn value_transfer<'b>(
         &self,
-        _bin: &Binary<'b>,
-        _function: FunctionValue,
-        _success: Option<&mut BasicValueEnum<'b>>,
-        _address: PointerValue<'b>,
-        _value: IntValue<'b>,
-        _ns: &Namespace,
+        bin: &Binary<'b>,
+        function: FunctionValue<'b>,
+        success: Option<&mut BasicValueEnum<'b>>,
+        address: PointerValue<'b>,
+        value: IntValue<'b>,
+        ns: &Namespace,
         loc: Loc,
     ) {
-        unimplemented!()
+        emit_context!(bin);
+
+        let to = bin
+            .builder
+            .build_load(bin.context.i64_type(), address, "to")
+            .unwrap()
+            .into_int_value();
+
+        // token amounts are i128
+        let amount = to_val(bin, function, &Type::Int(128), value.into(), ns);
+
+        if let Some(success) = success {
+            let mut transferred = bin.context.bool_type().const_zero().into();
+
+            self.transfer_native_token(bin, function, to, amount, Some(&mut transferred));
+
+            // like the return code on Polkadot, zero means success
+            *success = bin
+                .builder
+                .build_select(
+                    transferred.into_int_value(),
+                    i32_zero!(),
+                    i32_const!(1),
+                    "success",
+                )
+                .unwrap();
+        } else {
+            self.transfer_native_token(bin, function, to, amount, None);
+        }
     }
 
     /// builtin expressions
@@ -382,17 +761,218 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         function: FunctionValue<'b>,
         ns: &Namespace,
     ) -> BasicValueEnum<'b> {
-        unimplemented!()
+        match expr {
+            Expression::Builtin {
+                kind: Builtin::ToVal,
+                args,
+                ..
+            } => {
+                let value = expression(self, bin, &args[0], vartab, function, ns);
+
+                to_val(bin, function, &args[0].ty(), value, ns).into()
+            }
+            Expression::Builtin {
+                kind: Builtin::FromVal,
+                tys,
+                args,
+                ..
+            } => {
+                let val = expression(self, bin, &args[0], vartab, function, ns).into_int_value();
+
+                from_val(bin, function, &tys[0], val, ns)
+            }
+            Expression::Builtin {
+                kind: Builtin::Symbol,
+                args,
+                ..
+            } => match &args[0] {
+                Expression::BytesLiteral { value, .. } => symbol_to_val(bin, value).into(),
+                _ => unreachable!("symbol must be a literal"),
+            },
+            Expression::Builtin {
+                kind: Builtin::RequireAuth,
+                args,
+                ..
+            } => {
+                emit_context!(bin);
+
+                let address = expression(self, bin, &args[0], vartab, function, ns);
+
+                call!(REQUIRE_AUTH, &[address.into()])
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+            }
+            Expression::Builtin {
+                kind: Builtin::RequireAuthForArgs,
+                args,
+                ..
+            } => {
+                emit_context!(bin);
+
+                let address = expression(self, bin, &args[0], vartab, function, ns);
+                let vals = expression(self, bin, &args[1], vartab, function, ns);
+
+                call!(
+                    REQUIRE_AUTH_FOR_ARGS,
+                    &[address.into(), vals_to_vec(bin, vals).into()]
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+            }
+            Expression::Builtin {
+                kind: Builtin::ExtendTtl,
+                args,
+                ..
+            } => {
+                let key = expression(self, bin, &args[0], vartab, function, ns).into_int_value();
+                let storage_type =
+                    expression(self, bin, &args[1], vartab, function, ns).into_int_value();
+                let threshold =
+                    expression(self, bin, &args[2], vartab, function, ns).into_int_value();
+                let extend_to =
+                    expression(self, bin, &args[3], vartab, function, ns).into_int_value();
+
+                self.extend_ttl(bin, function, key, storage_type, threshold, extend_to);
+
+                bin.context.i64_type().const_zero().into()
+            }
+            Expression::Builtin {
+                kind: Builtin::ExtendInstanceTtl,
+                args,
+                ..
+            } => {
+                emit_context!(bin);
+
+                let threshold =
+                    expression(self, bin, &args[0], vartab, function, ns).into_int_value();
+                let extend_to =
+                    expression(self, bin, &args[1], vartab, function, ns).into_int_value();
+
+                call!(
+                    BUMP_CURRENT_CONTRACT_INSTANCE_AND_CODE,
+                    &[
+                        u32_to_val(bin, threshold).into(),
+                        u32_to_val(bin, extend_to).into()
+                    ]
+                );
+
+                bin.context.i64_type().const_zero().into()
+            }
+            Expression::Builtin {
+                kind: Builtin::SignatureVerify,
+                args,
+                ..
+            } => {
+                let public_key =
+                    expression(self, bin, &args[0], vartab, function, ns).into_int_value();
+                let message = expression(self, bin, &args[1], vartab, function, ns);
+                let signature = expression(self, bin, &args[2], vartab, function, ns);
+
+                self.signature_verify(bin, function, public_key, message, signature)
+                    .into()
+            }
+            Expression::Builtin {
+                kind: Builtin::ECRecover,
+                args,
+                ..
+            } => {
+                let args = args
+                    .iter()
+                    .map(|arg| expression(self, bin, arg, vartab, function, ns).into_int_value())
+                    .collect::<Vec<_>>();
+
+                self.ecrecover(bin, function, args[0], args[1], args[2], args[3])
+                    .into()
+            }
+            Expression::Builtin {
+                kind: Builtin::Timestamp,
+                ..
+            } => {
+                emit_context!(bin);
+
+                let timestamp = call!(GET_LEDGER_TIMESTAMP, &[])
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_int_value();
+
+                from_val(bin, function, &Type::Uint(64), timestamp, ns)
+            }
+            Expression::Builtin {
+                kind: Builtin::BlockNumber,
+                ..
+            } => {
+                emit_context!(bin);
+
+                let sequence = call!(GET_LEDGER_SEQUENCE, &[])
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_int_value();
+
+                bin.builder
+                    .build_int_z_extend(
+                        val_to_u32(bin, sequence),
+                        bin.context.i64_type(),
+                        "block_number",
+                    )
+                    .unwrap()
+                    .into()
+            }
+            Expression::Builtin {
+                kind: Builtin::GetAddress,
+                ..
+            } => {
+                emit_context!(bin);
+
+                let contract = call!(GET_CURRENT_CONTRACT_ADDRESS, &[])
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_int_value();
+
+                let address = bin.build_alloca(function, bin.address_type(ns), "address");
+
+                bin.builder
+                    .build_store(
+                        address,
+                        from_val(bin, function, &Type::Address(false), contract, ns),
+                    )
+                    .unwrap();
+
+                address.into()
+            }
+            Expression::Builtin {
+                kind: Builtin::Value,
+                ..
+            } => self.value_transferred(bin, ns).into(),
+            _ => unreachable!("{:?} is rejected by sema on Soroban", expr),
+        }
     }
 
     /// Return the return data from an external call (either revert error or return values)
     fn return_data<'b>(&self, bin: &Binary<'b>, function: FunctionValue<'b>) -> PointerValue<'b> {
-        unimplemented!()
+        emit_context!(bin);
+
+        let data = bin.vector_new(i32_const!(8), i32_const!(1), None);
+
+        let ret = bin
+            .builder
+            .build_load(bin.context.i64_type(), self.return_val(bin), "return_val")
+            .unwrap();
+
+        bin.builder
+            .build_store(bin.vector_bytes(data.into()), ret)
+            .unwrap();
+
+        data
     }
 
-    /// Return the value we received
+    /// Value is never sent along with a call on Soroban; tokens are transferred explicitly
     fn value_transferred<'b>(&self, binary: &Binary<'b>, ns: &Namespace) -> IntValue<'b> {
-        unimplemented!()
+        binary.value_type(ns).const_zero()
     }
 
     /// Terminate execution, destroy bin and send remaining funds to addr
@@ -400,6 +980,32 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         unimplemented!()
     }
 
+    /// Addresses hold the handle of a host object, so compare the objects they refer to
+    fn address_equal(
+        &self,
+        bin: &Binary<'a>,
+        function: FunctionValue<'a>,
+        left: ArrayValue<'a>,
+        right: ArrayValue<'a>,
+        ns: &Namespace,
+    ) -> IntValue<'a> {
+        emit_context!(bin);
+
+        let ty = Type::Address(false);
+        let left = to_val(bin, function, &ty, left.into(), ns);
+        let right = to_val(bin, function, &ty, right.into(), ns);
+
+        let cmp = call!(OBJ_CMP, &[left.into(), right.into()])
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+        bin.builder
+            .build_int_compare(IntPredicate::EQ, cmp, i64_const!(0), "address_equal")
+            .unwrap()
+    }
+
     /// Crypto Hash
     fn hash<'b>(
         &self,
@@ -410,7 +1016,26 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         length: IntValue<'b>,
         ns: &Namespace,
     ) -> IntValue<'b> {
-        unimplemented!()
+        emit_context!(bin);
+
+        let digest =
+            bin.build_array_alloca(function, bin.context.i8_type(), i32_const!(32), "digest");
+
+        let hashlen = self.compute_hash(bin, hash, string, length, digest);
+
+        // bytesN values are stored little endian
+        let ty = bin.context.custom_width_int_type(hashlen as u32 * 8);
+        let res = bin.build_alloca(function, ty, "hash");
+
+        call!(
+            "__beNtoleN",
+            &[digest.into(), res.into(), i32_const!(hashlen).into()]
+        );
+
+        bin.builder
+            .build_load(ty, res, "hash")
+            .unwrap()
+            .into_int_value()
     }
 
     /// Emit event
@@ -421,7 +1046,85 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         data: BasicValueEnum<'b>,
         topics: &[BasicValueEnum<'b>],
     ) {
-        unimplemented!()
+        emit_context!(bin);
+
+        let i64_type = bin.context.i64_type();
+
+        let topics_buf =
+            bin.build_alloca(function, i64_type.array_type(topics.len() as u32), "topics");
+
+        for (i, topic) in topics.iter().enumerate() {
+            let dest = unsafe {
+                bin.builder
+                    .build_gep(i64_type, topics_buf, &[i32_const!(i as u64)], "topic")
+                    .unwrap()
+            };
+
+            bin.builder.build_store(dest, *topic).unwrap();
+        }
+
+        let topics = call!(
+            VEC_NEW_FROM_LINEAR_MEMORY,
+            &[
+                pointer_to_val(bin, topics_buf).into(),
+                u32_to_val(bin, i32_const!(topics.len() as u64)).into()
+            ]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        // A single data field is published as is, more than one as a Vec
+        let data_ptr = bin.vector_bytes(data);
+        let data_len = bin
+            .builder
+            .build_int_unsigned_div(bin.vector_len(data), i32_const!(8), "data_len")
+            .unwrap();
+
+        let single = bin.context.append_basic_block(function, "single_data");
+        let vec = bin.context.append_basic_block(function, "vec_data");
+        let done = bin.context.append_basic_block(function, "publish");
+
+        let is_single = bin
+            .builder
+            .build_int_compare(IntPredicate::EQ, data_len, i32_const!(1), "is_single")
+            .unwrap();
+
+        bin.builder
+            .build_conditional_branch(is_single, single, vec)
+            .unwrap();
+
+        bin.builder.position_at_end(single);
+        let single_val = bin
+            .builder
+            .build_load(i64_type, data_ptr, "data")
+            .unwrap()
+            .into_int_value();
+        bin.builder.build_unconditional_branch(done).unwrap();
+
+        bin.builder.position_at_end(vec);
+        let vec_val = call!(
+            VEC_NEW_FROM_LINEAR_MEMORY,
+            &[
+                pointer_to_val(bin, data_ptr).into(),
+                u32_to_val(bin, data_len).into()
+            ]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+        bin.builder.build_unconditional_branch(done).unwrap();
+
+        bin.builder.position_at_end(done);
+        let data = bin.builder.build_phi(i64_type, "data").unwrap();
+        data.add_incoming(&[(&single_val, single), (&vec_val, vec)]);
+
+        call!(
+            CONTRACT_EVENT,
+            &[topics.into(), data.as_basic_value().into()]
+        );
     }
 
     /// Return ABI encoded data
@@ -0,0 +1,846 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversion between Solidity values and Soroban `Val`s. A `Val` is a 64 bit value, where
+//! the lowest 8 bits are the tag which describes what is stored in the upper 56 bits (the body).
+//! Small values are stored in the body, larger ones live in the host and the body is a handle
+//! to the host object.
+
+use crate::emit::binary::Binary;
+use crate::emit::soroban::{
+    BYTES_COPY_TO_LINEAR_MEMORY, BYTES_LEN, BYTES_NEW_FROM_LINEAR_MEMORY, OBJ_FROM_I128_PIECES,
+    OBJ_FROM_I256_PIECES, OBJ_FROM_I64, OBJ_FROM_U128_PIECES, OBJ_FROM_U256_PIECES, OBJ_FROM_U64,
+    OBJ_TO_I128_HI64, OBJ_TO_I128_LO64, OBJ_TO_I256_HI_HI, OBJ_TO_I256_HI_LO, OBJ_TO_I256_LO_HI,
+    OBJ_TO_I256_LO_LO, OBJ_TO_I64, OBJ_TO_U128_HI64, OBJ_TO_U128_LO64, OBJ_TO_U256_HI_HI,
+    OBJ_TO_U256_HI_LO, OBJ_TO_U256_LO_HI, OBJ_TO_U256_LO_LO, OBJ_TO_U64,
+    STRING_COPY_TO_LINEAR_MEMORY, STRING_LEN, STRING_NEW_FROM_LINEAR_MEMORY,
+    SYMBOL_NEW_FROM_LINEAR_MEMORY, VEC_NEW_FROM_LINEAR_MEMORY,
+};
+use crate::emit_context;
+use crate::sema::ast::{Namespace, Type};
+use inkwell::values::{
+    BasicMetadataValueEnum, BasicValueEnum, FunctionValue, IntValue, PointerValue,
+};
+use inkwell::IntPredicate;
+
+pub(super) const TAG_TRUE: u64 = 1;
+pub(super) const TAG_VOID: u64 = 2;
+pub(super) const TAG_ERROR: u64 = 3;
+pub(super) const TAG_U32: u64 = 4;
+pub(super) const TAG_I32: u64 = 5;
+pub(super) const TAG_U64_SMALL: u64 = 6;
+pub(super) const TAG_I64_SMALL: u64 = 7;
+pub(super) const TAG_U128_SMALL: u64 = 10;
+pub(super) const TAG_I128_SMALL: u64 = 11;
+pub(super) const TAG_U256_SMALL: u64 = 12;
+pub(super) const TAG_I256_SMALL: u64 = 13;
+pub(super) const TAG_SYMBOL_SMALL: u64 = 14;
+
+/// The maximum number of characters in a small symbol; each character takes 6 bits of the body
+const SYMBOL_SMALL_MAX_LEN: usize = 9;
+
+/// Encode a 32 bit value as a `U32Val`
+pub(super) fn u32_to_val<'a>(bin: &Binary<'a>, value: IntValue<'a>) -> IntValue<'a> {
+    emit_context!(bin);
+
+    let value = bin
+        .builder
+        .build_int_z_extend_or_bit_cast(value, bin.context.i64_type(), "value")
+        .unwrap();
+
+    let value = bin
+        .builder
+        .build_left_shift(value, i64_const!(32), "value")
+        .unwrap();
+
+    bin.builder
+        .build_or(value, i64_const!(TAG_U32), "u32_val")
+        .unwrap()
+}
+
+/// Decode a `U32Val` into a 32 bit value
+pub(super) fn val_to_u32<'a>(bin: &Binary<'a>, val: IntValue<'a>) -> IntValue<'a> {
+    emit_context!(bin);
+
+    let value = bin
+        .builder
+        .build_right_shift(val, i64_const!(32), false, "value")
+        .unwrap();
+
+    bin.builder
+        .build_int_truncate(value, bin.context.i32_type(), "u32")
+        .unwrap()
+}
+
+/// Encode a pointer into linear memory as a `U32Val`, as expected by the `*_linear_memory`
+/// host functions.
+pub(super) fn pointer_to_val<'a>(bin: &Binary<'a>, ptr: PointerValue<'a>) -> IntValue<'a> {
+    let pos = bin
+        .builder
+        .build_ptr_to_int(ptr, bin.context.i32_type(), "pos")
+        .unwrap();
+
+    u32_to_val(bin, pos)
+}
+
+/// Convert a value of the given type to a `Val`
+pub(super) fn to_val<'a>(
+    bin: &Binary<'a>,
+    function: FunctionValue<'a>,
+    ty: &Type,
+    value: BasicValueEnum<'a>,
+    ns: &Namespace,
+) -> IntValue<'a> {
+    emit_context!(bin);
+
+    match ty {
+        Type::Bool => bin
+            .builder
+            .build_int_z_extend(value.into_int_value(), bin.context.i64_type(), "bool_val")
+            .unwrap(),
+        Type::Uint(n) if *n <= 32 => u32_to_val(bin, value.into_int_value()),
+        Type::Enum(_) => u32_to_val(bin, value.into_int_value()),
+        Type::Int(n) if *n <= 32 => {
+            let value = bin
+                .builder
+                .build_int_s_extend_or_bit_cast(value.into_int_value(), bin.context.i32_type(), "")
+                .unwrap();
+
+            let value = u32_to_val(bin, value);
+
+            bin.builder
+                .build_xor(value, i64_const!(TAG_U32 ^ TAG_I32), "i32_val")
+                .unwrap()
+        }
+        Type::Uint(n) if *n <= 64 => {
+            let value = bin
+                .builder
+                .build_int_z_extend_or_bit_cast(value.into_int_value(), bin.context.i64_type(), "")
+                .unwrap();
+
+            let fits = bin
+                .builder
+                .build_int_compare(IntPredicate::ULT, value, i64_const!(1 << 56), "fits")
+                .unwrap();
+
+            small_or_object(
+                bin,
+                function,
+                value,
+                fits,
+                TAG_U64_SMALL,
+                OBJ_FROM_U64,
+                &[value.into()],
+            )
+        }
+        Type::Int(n) if *n <= 64 => {
+            let value = bin
+                .builder
+                .build_int_s_extend_or_bit_cast(value.into_int_value(), bin.context.i64_type(), "")
+                .unwrap();
+
+            // the value fits in the body if no bits are lost by shifting it up and back down
+            let shifted = bin
+                .builder
+                .build_left_shift(value, i64_const!(8), "")
+                .unwrap();
+            let shifted = bin
+                .builder
+                .build_right_shift(shifted, i64_const!(8), true, "")
+                .unwrap();
+
+            let fits = bin
+                .builder
+                .build_int_compare(IntPredicate::EQ, value, shifted, "fits")
+                .unwrap();
+
+            small_or_object(
+                bin,
+                function,
+                value,
+                fits,
+                TAG_I64_SMALL,
+                OBJ_FROM_I64,
+                &[value.into()],
+            )
+        }
+        Type::Uint(n) | Type::Int(n) if *n <= 256 => {
+            wide_int_to_val(bin, function, value.into_int_value(), &WideInt::new(ty))
+        }
+        Type::String | Type::DynamicBytes => vector_to_val(bin, value, *ty == Type::String),
+        Type::Bytes(n) => bytes_n_to_val(bin, function, value.into_int_value(), *n),
+        Type::Address(_) | Type::Contract(_) => {
+            // the address holds the handle of the host object in its first 8 bytes
+            let address = bin.build_alloca(function, bin.address_type(ns), "address");
+
+            bin.builder.build_store(address, value).unwrap();
+
+            bin.builder
+                .build_load(bin.context.i64_type(), address, "address_val")
+                .unwrap()
+                .into_int_value()
+        }
+        Type::UserType(no) => to_val(bin, function, &ns.user_types[*no].ty, value, ns),
+        _ => unreachable!("{} cannot be converted to a Soroban Val", ty.to_string(ns)),
+    }
+}
+
+/// Convert a `Val` to a value of the given type
+pub(super) fn from_val<'a>(
+    bin: &Binary<'a>,
+    function: FunctionValue<'a>,
+    ty: &Type,
+    val: IntValue<'a>,
+    ns: &Namespace,
+) -> BasicValueEnum<'a> {
+    emit_context!(bin);
+
+    match ty {
+        Type::Bool => {
+            // false is the zero `Val`, and true has no body either
+            let valid = bin
+                .builder
+                .build_int_compare(IntPredicate::ULE, val, i64_const!(TAG_TRUE), "valid")
+                .unwrap();
+
+            trap_unless(bin, function, valid);
+
+            bin.builder
+                .build_int_compare(IntPredicate::EQ, val, i64_const!(TAG_TRUE), "bool")
+                .unwrap()
+                .into()
+        }
+        Type::Uint(n) | Type::Int(n) if *n <= 32 => {
+            let signed = matches!(ty, Type::Int(_));
+
+            check_tag(bin, function, val, if signed { TAG_I32 } else { TAG_U32 });
+
+            let value = bin
+                .builder
+                .build_right_shift(val, i64_const!(32), signed, "")
+                .unwrap();
+
+            narrow(bin, function, value, *n as u32, signed).into()
+        }
+        Type::Enum(_) => {
+            check_tag(bin, function, val, TAG_U32);
+
+            let value = val_to_u32(bin, val);
+
+            bin.builder
+                .build_int_truncate(value, bin.llvm_type(ty, ns).into_int_type(), "enum")
+                .unwrap()
+                .into()
+        }
+        Type::Uint(n) | Type::Int(n) if *n <= 64 => {
+            let signed = matches!(ty, Type::Int(_));
+
+            let (small_tag, host_function) = if signed {
+                (TAG_I64_SMALL, OBJ_TO_I64)
+            } else {
+                (TAG_U64_SMALL, OBJ_TO_U64)
+            };
+
+            let tag = bin.builder.build_and(val, i64_const!(0xff), "tag").unwrap();
+
+            let is_small = bin
+                .builder
+                .build_int_compare(IntPredicate::EQ, tag, i64_const!(small_tag), "is_small")
+                .unwrap();
+
+            let entry = bin.builder.get_insert_block().unwrap();
+            let object = bin.context.append_basic_block(function, "object");
+            let done = bin.context.append_basic_block(function, "done");
+
+            let small = bin
+                .builder
+                .build_right_shift(val, i64_const!(8), signed, "small")
+                .unwrap();
+
+            bin.builder
+                .build_conditional_branch(is_small, done, object)
+                .unwrap();
+
+            bin.builder.position_at_end(object);
+
+            let large = call!(host_function, &[val.into()])
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+
+            bin.builder.build_unconditional_branch(done).unwrap();
+
+            bin.builder.position_at_end(done);
+
+            let value = bin
+                .builder
+                .build_phi(bin.context.i64_type(), "value")
+                .unwrap();
+
+            value.add_incoming(&[(&small, entry), (&large, object)]);
+
+            narrow(
+                bin,
+                function,
+                value.as_basic_value().into_int_value(),
+                *n as u32,
+                signed,
+            )
+            .into()
+        }
+        Type::Uint(n) | Type::Int(n) if *n <= 256 => {
+            let wide = WideInt::new(ty);
+            let value = val_to_wide_int(bin, function, val, &wide);
+
+            narrow(bin, function, value, *n as u32, wide.signed).into()
+        }
+        Type::String | Type::DynamicBytes => val_to_vector(bin, val, *ty == Type::String).into(),
+        Type::Bytes(n) => val_to_bytes_n(bin, function, val, *n).into(),
+        Type::Address(_) | Type::Contract(_) => {
+            let address = bin.build_alloca(function, bin.address_type(ns), "address");
+
+            bin.builder
+                .build_store(address, bin.address_type(ns).const_zero())
+                .unwrap();
+            bin.builder.build_store(address, val).unwrap();
+
+            bin.builder
+                .build_load(bin.address_type(ns), address, "address")
+                .unwrap()
+        }
+        Type::UserType(no) => from_val(bin, function, &ns.user_types[*no].ty, val, ns),
+        _ => unreachable!(
+            "{} cannot be converted from a Soroban Val",
+            ty.to_string(ns)
+        ),
+    }
+}
+
+/// Create a `Symbol` for the given name. Short names fit in the body of the `Val`, longer ones
+/// are created by the host.
+pub(super) fn symbol_to_val<'a>(bin: &Binary<'a>, name: &[u8]) -> IntValue<'a> {
+    emit_context!(bin);
+
+    if name.len() <= SYMBOL_SMALL_MAX_LEN {
+        let body = name.iter().fold(0u64, |body, ch| {
+            let code = match ch {
+                b'_' => 1,
+                b'0'..=b'9' => ch - b'0' + 2,
+                b'A'..=b'Z' => ch - b'A' + 12,
+                b'a'..=b'z' => ch - b'a' + 38,
+                // names which are not valid symbols are rejected by sema
+                _ => unreachable!("invalid character in symbol"),
+            };
+
+            (body << 6) | code as u64
+        });
+
+        return i64_const!((body << 8) | TAG_SYMBOL_SMALL);
+    }
+
+    let data = bin.emit_global_string("symbol", name, true);
+
+    call!(
+        SYMBOL_NEW_FROM_LINEAR_MEMORY,
+        &[
+            pointer_to_val(bin, data).into(),
+            u32_to_val(bin, i32_const!(name.len() as u64)).into()
+        ]
+    )
+    .try_as_basic_value()
+    .left()
+    .unwrap()
+    .into_int_value()
+}
+
+/// Create a `VecObject` from a buffer of `Val`s
+pub(super) fn vals_to_vec<'a>(bin: &Binary<'a>, buffer: BasicValueEnum<'a>) -> IntValue<'a> {
+    emit_context!(bin);
+
+    let len = bin
+        .builder
+        .build_int_unsigned_div(bin.vector_len(buffer), i32_const!(8), "len")
+        .unwrap();
+
+    call!(
+        VEC_NEW_FROM_LINEAR_MEMORY,
+        &[
+            pointer_to_val(bin, bin.vector_bytes(buffer)).into(),
+            u32_to_val(bin, len).into()
+        ]
+    )
+    .try_as_basic_value()
+    .left()
+    .unwrap()
+    .into_int_value()
+}
+
+/// Create a `StringObject` or `BytesObject` from the contents of a vector
+pub(super) fn vector_to_val<'a>(
+    bin: &Binary<'a>,
+    value: BasicValueEnum<'a>,
+    string: bool,
+) -> IntValue<'a> {
+    emit_context!(bin);
+
+    let host_function = if string {
+        STRING_NEW_FROM_LINEAR_MEMORY
+    } else {
+        BYTES_NEW_FROM_LINEAR_MEMORY
+    };
+
+    let len = u32_to_val(bin, bin.vector_len(value));
+    let data = pointer_to_val(bin, bin.vector_bytes(value));
+
+    call!(host_function, &[data.into(), len.into()])
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value()
+}
+
+/// Copy the contents of a `StringObject` or `BytesObject` into a new vector
+pub(super) fn val_to_vector<'a>(
+    bin: &Binary<'a>,
+    val: IntValue<'a>,
+    string: bool,
+) -> PointerValue<'a> {
+    emit_context!(bin);
+
+    let (len_function, copy_function) = if string {
+        (STRING_LEN, STRING_COPY_TO_LINEAR_MEMORY)
+    } else {
+        (BYTES_LEN, BYTES_COPY_TO_LINEAR_MEMORY)
+    };
+
+    let len = call!(len_function, &[val.into()])
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+    let len = val_to_u32(bin, len);
+
+    let vector = bin.vector_new(len, i32_const!(1), None);
+
+    let data = pointer_to_val(bin, bin.vector_bytes(vector.into()));
+
+    call!(
+        copy_function,
+        &[
+            val.into(),
+            u32_to_val(bin, i32_zero!()).into(),
+            data.into(),
+            u32_to_val(bin, len).into()
+        ]
+    );
+
+    vector
+}
+
+/// Create a `BytesObject` from `length` bytes at `data`
+pub(super) fn bytes_new<'a>(
+    bin: &Binary<'a>,
+    data: PointerValue<'a>,
+    length: IntValue<'a>,
+) -> IntValue<'a> {
+    emit_context!(bin);
+
+    call!(
+        BYTES_NEW_FROM_LINEAR_MEMORY,
+        &[
+            pointer_to_val(bin, data).into(),
+            u32_to_val(bin, length).into()
+        ]
+    )
+    .try_as_basic_value()
+    .left()
+    .unwrap()
+    .into_int_value()
+}
+
+/// Copy the first `length` bytes of a `BytesObject` to `dest`
+pub(super) fn bytes_copy<'a>(
+    bin: &Binary<'a>,
+    bytes: IntValue<'a>,
+    dest: PointerValue<'a>,
+    length: IntValue<'a>,
+) {
+    emit_context!(bin);
+
+    call!(
+        BYTES_COPY_TO_LINEAR_MEMORY,
+        &[
+            bytes.into(),
+            u32_to_val(bin, i32_zero!()).into(),
+            pointer_to_val(bin, dest).into(),
+            u32_to_val(bin, length).into()
+        ]
+    );
+}
+
+/// Convert a `bytesN` value to a `BytesObject` of length `n`
+fn bytes_n_to_val<'a>(
+    bin: &Binary<'a>,
+    function: FunctionValue<'a>,
+    value: IntValue<'a>,
+    n: u8,
+) -> IntValue<'a> {
+    emit_context!(bin);
+
+    let data = bin.build_array_alloca(
+        function,
+        bin.context.i8_type(),
+        i32_const!(n as u64),
+        "data",
+    );
+
+    bin.write_be_bytes(function, value, data);
+
+    bytes_new(bin, data, i32_const!(n as u64))
+}
+
+/// Read a `bytesN` value from a `BytesObject`
+fn val_to_bytes_n<'a>(
+    bin: &Binary<'a>,
+    function: FunctionValue<'a>,
+    val: IntValue<'a>,
+    n: u8,
+) -> IntValue<'a> {
+    emit_context!(bin);
+
+    let data = bin.build_array_alloca(
+        function,
+        bin.context.i8_type(),
+        i32_const!(n as u64),
+        "data",
+    );
+
+    bytes_copy(bin, val, data, i32_const!(n as u64));
+
+    let ty = bin.context.custom_width_int_type(n as u32 * 8);
+    let res = bin.build_alloca(function, ty, "bytes");
+
+    call!(
+        "__beNtoleN",
+        &[data.into(), res.into(), i32_const!(n as u64).into()]
+    );
+
+    bin.builder
+        .build_load(ty, res, "bytes")
+        .unwrap()
+        .into_int_value()
+}
+
+/// Integers wider than 64 bits are stored in the body of a `Val` if they fit in 56 bits, else
+/// they are host objects which are created from and read as 64 bit pieces.
+struct WideInt {
+    /// Width of the host type, either 128 or 256 bits
+    width: u32,
+    signed: bool,
+    small_tag: u64,
+    from_pieces: &'static str,
+    /// The host functions which read the pieces, most significant first
+    to_pieces: &'static [&'static str],
+}
+
+impl WideInt {
+    fn new(ty: &Type) -> Self {
+        match ty {
+            Type::Uint(n) if *n <= 128 => WideInt {
+                width: 128,
+                signed: false,
+                small_tag: TAG_U128_SMALL,
+                from_pieces: OBJ_FROM_U128_PIECES,
+                to_pieces: &[OBJ_TO_U128_HI64, OBJ_TO_U128_LO64],
+            },
+            Type::Int(n) if *n <= 128 => WideInt {
+                width: 128,
+                signed: true,
+                small_tag: TAG_I128_SMALL,
+                from_pieces: OBJ_FROM_I128_PIECES,
+                to_pieces: &[OBJ_TO_I128_HI64, OBJ_TO_I128_LO64],
+            },
+            Type::Uint(_) => WideInt {
+                width: 256,
+                signed: false,
+                small_tag: TAG_U256_SMALL,
+                from_pieces: OBJ_FROM_U256_PIECES,
+                to_pieces: &[
+                    OBJ_TO_U256_HI_HI,
+                    OBJ_TO_U256_HI_LO,
+                    OBJ_TO_U256_LO_HI,
+                    OBJ_TO_U256_LO_LO,
+                ],
+            },
+            Type::Int(_) => WideInt {
+                width: 256,
+                signed: true,
+                small_tag: TAG_I256_SMALL,
+                from_pieces: OBJ_FROM_I256_PIECES,
+                to_pieces: &[
+                    OBJ_TO_I256_HI_HI,
+                    OBJ_TO_I256_HI_LO,
+                    OBJ_TO_I256_LO_HI,
+                    OBJ_TO_I256_LO_LO,
+                ],
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn wide_int_to_val<'a>(
+    bin: &Binary<'a>,
+    function: FunctionValue<'a>,
+    value: IntValue<'a>,
+    wide: &WideInt,
+) -> IntValue<'a> {
+    let ty = bin.context.custom_width_int_type(wide.width);
+    let i64_type = bin.context.i64_type();
+
+    let value = if wide.signed {
+        bin.builder
+            .build_int_s_extend_or_bit_cast(value, ty, "")
+            .unwrap()
+    } else {
+        bin.builder
+            .build_int_z_extend_or_bit_cast(value, ty, "")
+            .unwrap()
+    };
+
+    // the value fits in the body if no bits are lost by shifting it up and back down
+    let shift = ty.const_int(wide.width as u64 - 56, false);
+    let shifted = bin.builder.build_left_shift(value, shift, "").unwrap();
+    let shifted = bin
+        .builder
+        .build_right_shift(shifted, shift, wide.signed, "")
+        .unwrap();
+
+    let fits = bin
+        .builder
+        .build_int_compare(IntPredicate::EQ, value, shifted, "fits")
+        .unwrap();
+
+    let pieces = (0..wide.width / 64)
+        .rev()
+        .map(|i| {
+            let piece = bin
+                .builder
+                .build_right_shift(value, ty.const_int(i as u64 * 64, false), false, "")
+                .unwrap();
+
+            bin.builder
+                .build_int_truncate(piece, i64_type, "piece")
+                .unwrap()
+                .into()
+        })
+        .collect::<Vec<BasicMetadataValueEnum>>();
+
+    let low = bin
+        .builder
+        .build_int_truncate(value, i64_type, "low")
+        .unwrap();
+
+    small_or_object(
+        bin,
+        function,
+        low,
+        fits,
+        wide.small_tag,
+        wide.from_pieces,
+        &pieces,
+    )
+}
+
+fn val_to_wide_int<'a>(
+    bin: &Binary<'a>,
+    function: FunctionValue<'a>,
+    val: IntValue<'a>,
+    wide: &WideInt,
+) -> IntValue<'a> {
+    emit_context!(bin);
+
+    let ty = bin.context.custom_width_int_type(wide.width);
+
+    let tag = bin.builder.build_and(val, i64_const!(0xff), "tag").unwrap();
+
+    let is_small = bin
+        .builder
+        .build_int_compare(
+            IntPredicate::EQ,
+            tag,
+            i64_const!(wide.small_tag),
+            "is_small",
+        )
+        .unwrap();
+
+    let entry = bin.builder.get_insert_block().unwrap();
+    let object = bin.context.append_basic_block(function, "object");
+    let done = bin.context.append_basic_block(function, "done");
+
+    let small = bin
+        .builder
+        .build_right_shift(val, i64_const!(8), wide.signed, "small")
+        .unwrap();
+
+    let small = if wide.signed {
+        bin.builder.build_int_s_extend(small, ty, "").unwrap()
+    } else {
+        bin.builder.build_int_z_extend(small, ty, "").unwrap()
+    };
+
+    bin.builder
+        .build_conditional_branch(is_small, done, object)
+        .unwrap();
+
+    bin.builder.position_at_end(object);
+
+    let large = wide
+        .to_pieces
+        .iter()
+        .fold(ty.const_zero(), |large, host_function| {
+            let piece = call!(*host_function, &[val.into()])
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+
+            let piece = bin.builder.build_int_z_extend(piece, ty, "").unwrap();
+
+            let large = bin
+                .builder
+                .build_left_shift(large, ty.const_int(64, false), "")
+                .unwrap();
+
+            bin.builder.build_or(large, piece, "large").unwrap()
+        });
+
+    bin.builder.build_unconditional_branch(done).unwrap();
+
+    bin.builder.position_at_end(done);
+
+    let value = bin.builder.build_phi(ty, "value").unwrap();
+
+    value.add_incoming(&[(&small, entry), (&large, object)]);
+
+    value.as_basic_value().into_int_value()
+}
+
+/// Truncate a value to the given number of bits. If the value does not fit, the contract traps.
+fn narrow<'a>(
+    bin: &Binary<'a>,
+    function: FunctionValue<'a>,
+    value: IntValue<'a>,
+    bits: u32,
+    signed: bool,
+) -> IntValue<'a> {
+    if value.get_type().get_bit_width() == bits {
+        return value;
+    }
+
+    let narrowed = bin
+        .builder
+        .build_int_truncate(value, bin.context.custom_width_int_type(bits), "")
+        .unwrap();
+
+    let extended = if signed {
+        bin.builder
+            .build_int_s_extend(narrowed, value.get_type(), "")
+            .unwrap()
+    } else {
+        bin.builder
+            .build_int_z_extend(narrowed, value.get_type(), "")
+            .unwrap()
+    };
+
+    let fits = bin
+        .builder
+        .build_int_compare(IntPredicate::EQ, value, extended, "fits")
+        .unwrap();
+
+    trap_unless(bin, function, fits);
+
+    narrowed
+}
+
+/// Trap unless the `Val` has the given tag
+fn check_tag<'a>(bin: &Binary<'a>, function: FunctionValue<'a>, val: IntValue<'a>, tag: u64) {
+    emit_context!(bin);
+
+    let actual = bin.builder.build_and(val, i64_const!(0xff), "tag").unwrap();
+
+    let matches = bin
+        .builder
+        .build_int_compare(IntPredicate::EQ, actual, i64_const!(tag), "tag_matches")
+        .unwrap();
+
+    trap_unless(bin, function, matches);
+}
+
+/// Trap unless `cond` is true
+fn trap_unless<'a>(bin: &Binary<'a>, function: FunctionValue<'a>, cond: IntValue<'a>) {
+    let trap = bin.context.append_basic_block(function, "trap");
+    let success = bin.context.append_basic_block(function, "success");
+
+    bin.builder
+        .build_conditional_branch(cond, success, trap)
+        .unwrap();
+
+    bin.builder.position_at_end(trap);
+    bin.builder.build_unreachable().unwrap();
+
+    bin.builder.position_at_end(success);
+}
+
+/// Store a value in the body of a `Val` if it fits, else create a host object for it by
+/// calling `host_function` with `args`. `value` holds the lower 64 bits of the value.
+fn small_or_object<'a>(
+    bin: &Binary<'a>,
+    function: FunctionValue<'a>,
+    value: IntValue<'a>,
+    fits: IntValue<'a>,
+    small_tag: u64,
+    host_function: &str,
+    args: &[BasicMetadataValueEnum<'a>],
+) -> IntValue<'a> {
+    emit_context!(bin);
+
+    let entry = bin.builder.get_insert_block().unwrap();
+    let object = bin.context.append_basic_block(function, "object");
+    let done = bin.context.append_basic_block(function, "done");
+
+    let small = bin
+        .builder
+        .build_left_shift(value, i64_const!(8), "")
+        .unwrap();
+    let small = bin
+        .builder
+        .build_or(small, i64_const!(small_tag), "small")
+        .unwrap();
+
+    bin.builder
+        .build_conditional_branch(fits, done, object)
+        .unwrap();
+
+    bin.builder.position_at_end(object);
+
+    let large = call!(host_function, args)
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+    bin.builder.build_unconditional_branch(done).unwrap();
+
+    bin.builder.position_at_end(done);
+
+    let val = bin
+        .builder
+        .build_phi(bin.context.i64_type(), "val")
+        .unwrap();
+
+    val.add_incoming(&[(&small, entry), (&large, object)]);
+
+    val.as_basic_value().into_int_value()
+}
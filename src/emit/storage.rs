@@ -7,10 +7,11 @@ use inkwell::values::{ArrayValue, BasicValueEnum, FunctionValue, IntValue, Point
 
 /// This trait specifies the methods for managing storage on slot based environments
 pub(super) trait StorageSlot {
-    fn set_storage(
+    fn set_storage<'a>(
         &self,
-        binary: &Binary,
-        slot: PointerValue,
+        binary: &Binary<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         dest: PointerValue,
         dest_ty: BasicTypeEnum,
     );
@@ -18,12 +19,18 @@ pub(super) trait StorageSlot {
     fn get_storage_address<'a>(
         &self,
         binary: &Binary<'a>,
-        slot: PointerValue<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
         ns: &Namespace,
     ) -> ArrayValue<'a>;
 
     /// Clear a particlar storage slot (slot-based storage chains should implement)
-    fn storage_delete_single_slot(&self, binary: &Binary, slot: PointerValue);
+    fn storage_delete_single_slot<'a>(
+        &self,
+        binary: &Binary<'a>,
+        key: PointerValue<'a>,
+        key_len: IntValue<'a>,
+    );
 
     /// Recursively load a type from storage for slot based storage
     fn storage_load_slot<'a>(
@@ -29,6 +29,8 @@ pub enum Target {
     Polkadot {
         address_length: usize,
         value_length: usize,
+        /// Store mapping entries under the same keys as an ink! `Mapping`
+        ink_storage_keys: bool,
     },
     /// Ethereum EVM, see <https://ethereum.org/en/developers/docs/evm/>
     EVM,
@@ -70,9 +72,21 @@ impl Target {
         Target::Polkadot {
             address_length: 32,
             value_length: 16,
+            ink_storage_keys: false,
         }
     }
 
+    /// Are mapping entries stored under ink! storage keys
+    pub fn ink_storage_keys(&self) -> bool {
+        matches!(
+            self,
+            Target::Polkadot {
+                ink_storage_keys: true,
+                ..
+            }
+        )
+    }
+
     /// Creates a target from a string
     pub fn from(name: &str) -> Option<Self> {
         match name {
@@ -100,9 +100,8 @@ fn generate_import_section(section: SectionLimited<Import>, module: &mut Module)
         };
         let module_name = match import.name {
             "memory" => import.module,
-            "set_storage" => "seal2",
-            "clear_storage" | "contains_storage" | "get_storage" | "instantiate" | "terminate"
-            | "seal_call" => "seal1",
+            "set_storage" | "instantiate" | "seal_call" => "seal2",
+            "clear_storage" | "contains_storage" | "get_storage" | "terminate" => "seal1",
             _ => "seal0",
         };
         imports.import(module_name, import.name, import_type);
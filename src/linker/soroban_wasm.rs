@@ -11,7 +11,7 @@ use wasm_encoder::{
 };
 use wasmparser::{Global, Import, Parser, Payload::*, SectionLimited, TypeRef};
 
-use crate::emit::soroban::{GET_CONTRACT_DATA, LOG_FROM_LINEAR_MEMORY, PUT_CONTRACT_DATA};
+use crate::emit::soroban::HOST_FUNCTIONS;
 
 pub fn link(input: &[u8], name: &str) -> Vec<u8> {
     let dir = tempdir().expect("failed to create temp directory for linking");
@@ -95,13 +95,11 @@ fn generate_import_section(section: SectionLimited<Import>, module: &mut Module)
             }),
             _ => panic!("unexpected WASM import section {:?}", import),
         };
-        let module_name = match import.name {
-            GET_CONTRACT_DATA | PUT_CONTRACT_DATA => "l",
-            LOG_FROM_LINEAR_MEMORY => "x",
-            _ => panic!("got func {:?}", import),
-        };
-        // parse the import name to all string after the the first dot
-        let import_name = import.name.split('.').nth(1).unwrap();
+        if !HOST_FUNCTIONS.iter().any(|(name, _)| *name == import.name) {
+            panic!("got func {:?}", import);
+        }
+        // host functions are named after their module and function, separated by a dot
+        let (module_name, import_name) = import.name.split_once('.').unwrap();
         imports.import(module_name, import_name, import_type);
     }
     module.section(&imports);
@@ -114,6 +112,6 @@ fn generate_global_section(_section: SectionLimited<Global>, module: &mut Module
         val_type: ValType::I32,
         mutable: true,
     };
-    globals.global(global_type, &ConstExpr::i32_const(1048576));
+    globals.global(global_type, &ConstExpr::i32_const(0x10000));
     module.section(&globals);
 }
@@ -149,6 +149,22 @@ impl Converter<'_> {
                     storage: storage_op,
                 });
             }
+            Instr::TakeStorage { res, storage, .. } => {
+                let storage_op = self.to_operand_and_insns(storage, vartable, results);
+                results.push(Instruction::TakeStorage {
+                    loc: /*missing from cfg*/ Loc::Codegen,
+                    res: *res,
+                    storage: storage_op,
+                });
+            }
+            Instr::ContainsStorage { res, storage } => {
+                let storage_op = self.to_operand_and_insns(storage, vartable, results);
+                results.push(Instruction::ContainsStorage {
+                    loc: /*missing from cfg*/ Loc::Codegen,
+                    res: *res,
+                    storage: storage_op,
+                });
+            }
             Instr::SetStorage { value, storage, .. } => {
                 let storage_op = self.to_operand_and_insns(storage, vartable, results);
                 let value_op = self.to_operand_and_insns(value, vartable, results);
@@ -162,6 +178,7 @@ impl Converter<'_> {
                 value,
                 storage,
                 offset,
+                ..
             } => {
                 let value_op = self.to_operand_and_insns(value, vartable, results);
                 let storage_op = self.to_operand_and_insns(storage, vartable, results);
@@ -205,6 +222,8 @@ impl Converter<'_> {
                 payload,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
                 callty,
                 contract_function_no,
                 flags,
@@ -216,6 +235,9 @@ impl Converter<'_> {
                 let payload_op = self.to_operand_and_insns(payload, vartable, results);
                 let value_op = self.to_operand_and_insns(value, vartable, results);
                 let gas_op = self.to_operand_and_insns(gas, vartable, results);
+                let proof_size_op = self.to_operand_option_and_insns(proof_size, vartable, results);
+                let storage_deposit_limit_op =
+                    self.to_operand_option_and_insns(storage_deposit_limit, vartable, results);
                 let flags_op = self.to_operand_option_and_insns(flags, vartable, results);
 
                 results.push(Instruction::ExternalCall {
@@ -227,6 +249,8 @@ impl Converter<'_> {
                     payload: payload_op,
                     value: value_op,
                     gas: gas_op,
+                    proof_size: proof_size_op,
+                    storage_deposit_limit: storage_deposit_limit_op,
                     callty: callty.clone(),
                     contract_function_no: *contract_function_no,
                     flags: flags_op,
@@ -345,6 +369,9 @@ impl Converter<'_> {
                 encoded_args,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
+                code_hash,
                 salt,
                 address,
                 seeds,
@@ -354,6 +381,10 @@ impl Converter<'_> {
                 let args_op = self.to_operand_and_insns(encoded_args, vartable, results);
                 let value_op = self.to_operand_option_and_insns(value, vartable, results);
                 let gas_op = self.to_operand_and_insns(gas, vartable, results);
+                let proof_size_op = self.to_operand_option_and_insns(proof_size, vartable, results);
+                let storage_deposit_limit_op =
+                    self.to_operand_option_and_insns(storage_deposit_limit, vartable, results);
+                let code_hash_op = self.to_operand_option_and_insns(code_hash, vartable, results);
                 let salt_op = self.to_operand_option_and_insns(salt, vartable, results);
                 let address_op = self.to_operand_option_and_insns(address, vartable, results);
                 let seeds_op = self.to_operand_option_and_insns(seeds, vartable, results);
@@ -369,6 +400,9 @@ impl Converter<'_> {
                     encoded_args: args_op,
                     value: value_op,
                     gas: gas_op,
+                    proof_size: proof_size_op,
+                    storage_deposit_limit: storage_deposit_limit_op,
+                    code_hash: code_hash_op,
                     salt: salt_op,
                     address: address_op,
                     seeds: seeds_op,
@@ -10,6 +10,7 @@ use super::lir_type::PhiInput;
 
 /// Instructions using three-address code format
 #[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
 pub enum Instruction {
     Nop,
 
@@ -54,6 +55,12 @@ pub enum Instruction {
         encoded_args: Operand,
         value: Option<Operand>,
         gas: Operand,
+        /// Polkadot specific
+        proof_size: Option<Operand>,
+        /// Polkadot specific
+        storage_deposit_limit: Option<Operand>,
+        /// Polkadot specific
+        code_hash: Option<Operand>,
         salt: Option<Operand>,
         address: Option<Operand>,
         seeds: Option<Operand>,
@@ -69,6 +76,16 @@ pub enum Instruction {
         loc: Loc,
         storage: Operand,
     },
+    TakeStorage {
+        loc: Loc,
+        res: usize,
+        storage: Operand,
+    },
+    ContainsStorage {
+        loc: Loc,
+        res: usize,
+        storage: Operand,
+    },
     SetStorage {
         loc: Loc,
         value: Operand,
@@ -126,6 +143,10 @@ pub enum Instruction {
         /// Polkadot specific.
         /// On Solana, charged by transaction
         gas: Operand,
+        /// Polkadot specific
+        proof_size: Option<Operand>,
+        /// Polkadot specific
+        storage_deposit_limit: Option<Operand>,
         /// CallTy is polkadot specific:
         /// It involves difference code generation in emit.
         callty: CallTy,
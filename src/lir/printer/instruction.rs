@@ -175,6 +175,20 @@ impl Printer<'_> {
                 self.print_rhs_operand(f, storage);
                 write!(f, ";").unwrap();
             }
+            Instruction::TakeStorage { res, storage, .. } => {
+                let res_op = self.get_var_operand(res);
+                self.print_lhs_operand(f, &res_op);
+                write!(f, " = take_storage ").unwrap();
+                self.print_rhs_operand(f, storage);
+                write!(f, ";").unwrap();
+            }
+            Instruction::ContainsStorage { res, storage, .. } => {
+                let res_op = self.get_var_operand(res);
+                self.print_lhs_operand(f, &res_op);
+                write!(f, " = contains_storage ").unwrap();
+                self.print_rhs_operand(f, storage);
+                write!(f, ";").unwrap();
+            }
             Instruction::SetStorage { value, storage, .. } => {
                 write!(f, "set_storage ").unwrap();
                 self.print_rhs_operand(f, storage);
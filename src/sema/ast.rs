@@ -340,6 +340,10 @@ pub struct Function {
     pub is_override: Option<(pt::Loc, Vec<usize>)>,
     /// The selector (known as discriminator on Solana/Anchor)
     pub selector: Option<(pt::Loc, Vec<u8>)>,
+    /// Polkadot functions annotated with `@nonReentrant` reject calls which re-enter the contract
+    pub nonreentrant: Option<pt::Loc>,
+    /// The function id set with `@function_id` on a function of a Polkadot chain extension
+    pub chain_extension_id: Option<(pt::Loc, u16)>,
     /// Was the function declared with a body
     pub has_body: bool,
     /// The resolved body (if any)
@@ -454,6 +458,8 @@ impl Function {
             bases: BTreeMap::new(),
             modifiers: Vec::new(),
             selector: None,
+            nonreentrant: None,
+            chain_extension_id: None,
             is_virtual: false,
             is_accessor: false,
             has_body: false,
@@ -590,6 +596,31 @@ pub struct Variable {
     pub initializer: Option<Expression>,
     pub assigned: bool,
     pub read: bool,
+    /// The durability on Soroban, if given with an annotation
+    pub storage_type: Option<StorageType>,
+}
+
+/// The durability of contract data on Soroban. The values match the `StorageType` passed
+/// to the host functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StorageType {
+    /// Cheap storage which is deleted once its TTL expires
+    Temporary = 0,
+    /// Storage which is archived once its TTL expires, and can be restored
+    Persistent = 1,
+    /// Storage which shares its TTL with the contract instance
+    #[default]
+    Instance = 2,
+}
+
+impl StorageType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageType::Temporary => "temporary",
+            StorageType::Persistent => "persistent",
+            StorageType::Instance => "instance",
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -825,6 +856,10 @@ pub struct Contract {
     pub creates: Vec<usize>,
     /// List of events this contract may emit
     pub emits_events: Vec<usize>,
+    /// List of custom errors of this contract: the errors declared in it or its bases, and any other
+    /// errors it may revert with. On Solana and Soroban, errors are numbered by their position in
+    /// this list
+    pub errors: Vec<usize>,
     pub initializer: Option<usize>,
     pub default_constructor: Option<(Function, usize)>,
     pub cfg: Vec<ControlFlowGraph>,
@@ -834,6 +869,8 @@ pub struct Contract {
     pub instantiable: bool,
     /// Account of deployed program code on Solana
     pub program_id: Option<Vec<u8>>,
+    /// The extension id of an interface declared with `@chain_extension` on Polkadot
+    pub chain_extension: Option<u16>,
 }
 
 impl Contract {
@@ -1276,6 +1313,9 @@ pub struct CallArgs {
     pub seeds: Option<Box<Expression>>,
     pub flags: Option<Box<Expression>>,
     pub program_id: Option<Box<Expression>>,
+    pub proof_size: Option<Box<Expression>>,
+    pub storage_deposit_limit: Option<Box<Expression>>,
+    pub code_hash: Option<Box<Expression>>,
 }
 
 /// This enum manages the accounts in an external call on Solana. There can be three options:
@@ -1353,6 +1393,15 @@ impl Recurse for CallArgs {
         if let Some(flags) = &self.flags {
             flags.recurse(cx, f);
         }
+        if let Some(proof_size) = &self.proof_size {
+            proof_size.recurse(cx, f);
+        }
+        if let Some(storage_deposit_limit) = &self.storage_deposit_limit {
+            storage_deposit_limit.recurse(cx, f);
+        }
+        if let Some(code_hash) = &self.code_hash {
+            code_hash.recurse(cx, f);
+        }
     }
 }
 
@@ -1633,6 +1682,8 @@ impl CodeLocation for Instr {
             | Instr::PopStorage { storage: expr, .. }
             | Instr::LoadStorage { storage: expr, .. }
             | Instr::ClearStorage { storage: expr, .. }
+            | Instr::TakeStorage { storage: expr, .. }
+            | Instr::ContainsStorage { storage: expr, .. }
             | Instr::ExternalCall { value: expr, .. }
             | Instr::SetStorage { value: expr, .. }
             | Instr::Constructor { gas: expr, .. }
@@ -1712,11 +1763,17 @@ pub enum Builtin {
     BaseFee,
     PrevRandao,
     Gasleft,
+    RemainingComputeUnits,
+    LogComputeUnits,
+    RentMinimumBalance,
+    RentExempt,
     BlockCoinbase,
     BlockDifficulty,
     GasLimit,
     BlockNumber,
     Slot,
+    Epoch,
+    SlotsPerEpoch,
     Timestamp,
     Calldata,
     Sender,
@@ -1778,6 +1835,11 @@ pub enum Builtin {
     TypeInterfaceId,
     TypeRuntimeCode,
     TypeCreatorCode,
+    RequireAuth,
+    RequireAuthForArgs,
+    ExtendTtl,
+    XcmExecute,
+    XcmSend,
 }
 
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -36,7 +36,7 @@ pub struct Prototype {
 }
 
 // A list of all Solidity builtins functions
-pub static BUILTIN_FUNCTIONS: Lazy<[Prototype; 27]> = Lazy::new(|| {
+pub static BUILTIN_FUNCTIONS: Lazy<[Prototype; 36]> = Lazy::new(|| {
     [
         Prototype {
             builtin: Builtin::Assert,
@@ -159,6 +159,50 @@ pub static BUILTIN_FUNCTIONS: Lazy<[Prototype; 27]> = Lazy::new(|| {
             doc: "Return remaining gas left in current call",
             constant: false,
         },
+        Prototype {
+            builtin: Builtin::RemainingComputeUnits,
+            namespace: None,
+            method: vec![],
+            name: "remainingComputeUnits",
+            params: vec![],
+            ret: vec![Type::Uint(64)],
+            target: vec![Target::Solana],
+            doc: "Return the compute units left in the current transaction",
+            constant: false,
+        },
+        Prototype {
+            builtin: Builtin::LogComputeUnits,
+            namespace: None,
+            method: vec![],
+            name: "logComputeUnits",
+            params: vec![],
+            ret: vec![Type::Void],
+            target: vec![Target::Solana],
+            doc: "Log the compute units left in the current transaction",
+            constant: false,
+        },
+        Prototype {
+            builtin: Builtin::RentMinimumBalance,
+            namespace: None,
+            method: vec![],
+            name: "minimumBalance",
+            params: vec![Type::Uint(64)],
+            ret: vec![Type::Uint(64)],
+            target: vec![Target::Solana],
+            doc: "The lamports an account with the given size of data needs to be rent exempt",
+            constant: false,
+        },
+        Prototype {
+            builtin: Builtin::RentExempt,
+            namespace: None,
+            method: vec![],
+            name: "isRentExempt",
+            params: vec![Type::Uint(64), Type::Uint(64)],
+            ret: vec![Type::Bool],
+            target: vec![Target::Solana],
+            doc: "Is an account with the given lamports and size of data rent exempt",
+            constant: false,
+        },
         Prototype {
             builtin: Builtin::BlockHash,
             namespace: None,
@@ -248,7 +292,7 @@ pub static BUILTIN_FUNCTIONS: Lazy<[Prototype; 27]> = Lazy::new(|| {
             name: "gasprice",
             params: vec![Type::Uint(64)],
             ret: vec![Type::Value],
-            target: vec![],
+            target: vec![Target::EVM, Target::default_polkadot(), Target::Solana],
             doc: "Calculate price of given gas units",
             constant: false,
         },
@@ -287,6 +331,50 @@ pub static BUILTIN_FUNCTIONS: Lazy<[Prototype; 27]> = Lazy::new(|| {
             doc: "ed25519 signature verification",
             constant: false,
         },
+        Prototype {
+            builtin: Builtin::SignatureVerify,
+            namespace: None,
+            method: vec![],
+            name: "signatureVerify",
+            params: vec![Type::Bytes(32), Type::DynamicBytes, Type::DynamicBytes],
+            ret: vec![Type::Bool],
+            target: vec![Target::Soroban],
+            doc: "ed25519 signature verification, which aborts if the signature is not valid",
+            constant: false,
+        },
+        Prototype {
+            builtin: Builtin::SignatureVerify,
+            namespace: None,
+            method: vec![],
+            name: "signatureVerify",
+            params: vec![Type::Bytes(32), Type::DynamicBytes, Type::DynamicBytes],
+            ret: vec![Type::Bool],
+            target: vec![Target::default_polkadot()],
+            doc: "sr25519 signature verification",
+            constant: false,
+        },
+        Prototype {
+            builtin: Builtin::XcmExecute,
+            namespace: Some("xcm"),
+            method: vec![],
+            name: "execute",
+            params: vec![Type::DynamicBytes],
+            ret: vec![Type::Void],
+            target: vec![Target::default_polkadot()],
+            doc: "Execute a SCALE encoded XCM message locally",
+            constant: false,
+        },
+        Prototype {
+            builtin: Builtin::XcmSend,
+            namespace: Some("xcm"),
+            method: vec![],
+            name: "send",
+            params: vec![Type::DynamicBytes, Type::DynamicBytes],
+            ret: vec![Type::Bytes(32)],
+            target: vec![Target::default_polkadot()],
+            doc: "Send a SCALE encoded XCM message to a SCALE encoded destination, returning the message hash",
+            constant: false,
+        },
         Prototype {
             builtin: Builtin::UserTypeWrap,
             namespace: None,
@@ -325,6 +413,22 @@ pub static BUILTIN_FUNCTIONS: Lazy<[Prototype; 27]> = Lazy::new(|| {
             doc: "Recover the address associated with the public key from elliptic curve signature",
             constant: false,
         },
+        Prototype {
+            builtin: Builtin::ECRecover,
+            namespace: None,
+            method: vec![],
+            name: "ecrecover",
+            params: vec![
+                Type::Bytes(32),
+                Type::Uint(8),
+                Type::Bytes(32),
+                Type::Bytes(32),
+            ],
+            ret: vec![Type::Bytes(20)],
+            target: vec![Target::default_polkadot(), Target::Soroban, Target::Solana],
+            doc: "Recover the Ethereum address associated with the public key from elliptic curve signature",
+            constant: false,
+        },
         Prototype {
             builtin: Builtin::StringConcat,
             namespace: Some("string"),
@@ -351,7 +455,7 @@ pub static BUILTIN_FUNCTIONS: Lazy<[Prototype; 27]> = Lazy::new(|| {
 });
 
 // A list of all Solidity builtins variables
-pub static BUILTIN_VARIABLE: Lazy<[Prototype; 17]> = Lazy::new(|| {
+pub static BUILTIN_VARIABLE: Lazy<[Prototype; 19]> = Lazy::new(|| {
     [
         Prototype {
             builtin: Builtin::BlockCoinbase,
@@ -408,6 +512,28 @@ pub static BUILTIN_VARIABLE: Lazy<[Prototype; 17]> = Lazy::new(|| {
             doc: "Current slot number",
             constant: false,
         },
+        Prototype {
+            builtin: Builtin::Epoch,
+            namespace: Some("block"),
+            method: vec![],
+            name: "epoch",
+            params: vec![],
+            ret: vec![Type::Uint(64)],
+            target: vec![Target::Solana],
+            doc: "Current epoch number",
+            constant: false,
+        },
+        Prototype {
+            builtin: Builtin::SlotsPerEpoch,
+            namespace: Some("block"),
+            method: vec![],
+            name: "slots_per_epoch",
+            params: vec![],
+            ret: vec![Type::Uint(64)],
+            target: vec![Target::Solana],
+            doc: "The number of slots in each epoch",
+            constant: false,
+        },
         Prototype {
             builtin: Builtin::Timestamp,
             namespace: Some("block"),
@@ -470,7 +596,7 @@ pub static BUILTIN_VARIABLE: Lazy<[Prototype; 17]> = Lazy::new(|| {
             name: "data",
             params: vec![],
             ret: vec![Type::DynamicBytes],
-            target: vec![],
+            target: vec![Target::EVM, Target::default_polkadot(), Target::Solana],
             doc: "Raw input bytes to current call",
             constant: false,
         },
@@ -481,7 +607,7 @@ pub static BUILTIN_VARIABLE: Lazy<[Prototype; 17]> = Lazy::new(|| {
             name: "sender",
             params: vec![],
             ret: vec![Type::Address(true)],
-            target: vec![],
+            target: vec![Target::EVM, Target::default_polkadot(), Target::Solana],
             constant: false,
             doc: "Address of caller",
         },
@@ -547,7 +673,7 @@ pub static BUILTIN_VARIABLE: Lazy<[Prototype; 17]> = Lazy::new(|| {
 });
 
 // A list of all Solidity builtins methods
-pub static BUILTIN_METHODS: Lazy<[Prototype; 27]> = Lazy::new(|| {
+pub static BUILTIN_METHODS: Lazy<[Prototype; 29]> = Lazy::new(|| {
     [
         Prototype {
             builtin: Builtin::ReadInt8,
@@ -846,6 +972,30 @@ pub static BUILTIN_METHODS: Lazy<[Prototype; 27]> = Lazy::new(|| {
             doc: "Write the contents of a bytes array (without its length) to the specified offset",
             constant: false,
         },
+        Prototype {
+            builtin: Builtin::RequireAuth,
+            namespace: None,
+            method: vec![Type::Address(false), Type::Address(true)],
+            name: "requireAuth",
+            params: vec![],
+            ret: vec![],
+            target: vec![Target::Soroban],
+            doc: "Abort execution unless the address has authorized the current invocation",
+            constant: false,
+        },
+        Prototype {
+            builtin: Builtin::RequireAuthForArgs,
+            namespace: None,
+            method: vec![Type::Address(false), Type::Address(true)],
+            name: "requireAuthForArgs",
+            // any number of arguments of any type which can be converted to a Soroban Val
+            params: vec![],
+            ret: vec![],
+            target: vec![Target::Soroban],
+            doc:
+                "Abort execution unless the address has authorized a call with the given arguments",
+            constant: false,
+        },
     ]
 });
 
@@ -953,7 +1103,12 @@ pub(super) fn resolve_call(
 ) -> Result<Expression, ()> {
     let funcs = BUILTIN_FUNCTIONS
         .iter()
-        .filter(|p| p.name == id && p.namespace == namespace && p.method.is_empty())
+        .filter(|p| {
+            p.name == id
+                && p.namespace == namespace
+                && p.method.is_empty()
+                && (p.target.is_empty() || p.target.contains(&ns.target))
+        })
         .collect::<Vec<&Prototype>>();
 
     // try to resolve the arguments, give up if there are any errors
@@ -1377,7 +1532,11 @@ pub(super) fn resolve_method_call(
     let deref_ty = expr_ty.deref_memory();
     let funcs: Vec<_> = BUILTIN_METHODS
         .iter()
-        .filter(|func| func.name == id.name && func.method.contains(deref_ty))
+        .filter(|func| {
+            func.name == id.name
+                && func.method.contains(deref_ty)
+                && (func.target.is_empty() || func.target.contains(&ns.target))
+        })
         .collect();
 
     // try to resolve the arguments, give up if there are any errors
@@ -1401,6 +1560,30 @@ pub(super) fn resolve_method_call(
                     func.name
                 ),
             ));
+        } else if func.builtin == Builtin::RequireAuthForArgs {
+            for arg in args {
+                if let Ok(expr) = expression(
+                    arg,
+                    context,
+                    ns,
+                    symtable,
+                    &mut candidate_diagnostics,
+                    ResolveTo::Unknown,
+                ) {
+                    let ty = expr.ty().deref_any().clone();
+
+                    if !ty.is_soroban_val(ns) {
+                        candidate_diagnostics.push(Diagnostic::error(
+                            arg.loc(),
+                            format!("'{}' cannot be passed to '{}'", ty.to_string(ns), func.name),
+                        ));
+                    } else if let Ok(expr) =
+                        expr.cast(&arg.loc(), &ty, true, ns, &mut candidate_diagnostics)
+                    {
+                        cast_args.push(expr);
+                    }
+                }
+            }
         } else if func.params.len() != args.len() {
             candidate_diagnostics.push(Diagnostic::cast_error(
                 id.loc,
@@ -1826,6 +2009,97 @@ impl Namespace {
                 }],
                 self,
             ),
+            // lock_delegate_dependency API
+            Function::new(
+                loc,
+                loc,
+                pt::Identifier {
+                    name: "lock_delegate_dependency".to_string(),
+                    loc,
+                },
+                None,
+                Vec::new(),
+                pt::FunctionTy::Function,
+                None,
+                pt::Visibility::Public(Some(loc)),
+                vec![Parameter {
+                    loc,
+                    id: Some(identifier("code_hash")),
+                    ty: Type::UserType(type_no),
+                    ty_loc: Some(loc),
+                    readonly: false,
+                    indexed: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                }],
+                vec![],
+                self,
+            ),
+            // unlock_delegate_dependency API
+            Function::new(
+                loc,
+                loc,
+                pt::Identifier {
+                    name: "unlock_delegate_dependency".to_string(),
+                    loc,
+                },
+                None,
+                Vec::new(),
+                pt::FunctionTy::Function,
+                None,
+                pt::Visibility::Public(Some(loc)),
+                vec![Parameter {
+                    loc,
+                    id: Some(identifier("code_hash")),
+                    ty: Type::UserType(type_no),
+                    ty_loc: Some(loc),
+                    readonly: false,
+                    indexed: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                }],
+                vec![],
+                self,
+            ),
+            // ecdsa_to_eth_address API
+            Function::new(
+                loc,
+                loc,
+                pt::Identifier {
+                    name: "ecdsa_to_eth_address".to_string(),
+                    loc,
+                },
+                None,
+                Vec::new(),
+                pt::FunctionTy::Function,
+                Some(pt::Mutability::Pure(loc)),
+                pt::Visibility::Public(Some(loc)),
+                vec![Parameter {
+                    loc,
+                    id: Some(identifier("public_key")),
+                    ty: Type::Array(Type::Uint(8).into(), vec![ArrayLength::Fixed(33.into())]),
+                    ty_loc: Some(loc),
+                    readonly: false,
+                    indexed: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                }],
+                vec![Parameter {
+                    loc,
+                    id: Some(identifier("eth_address")),
+                    ty: Type::Bytes(20),
+                    ty_loc: Some(loc),
+                    readonly: false,
+                    indexed: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                }],
+                self,
+            ),
             // caller_is_root API
             Function::new(
                 loc,
@@ -1853,6 +2127,70 @@ impl Namespace {
                 }],
                 self,
             ),
+            // reentrance_count API
+            Function::new(
+                loc,
+                loc,
+                pt::Identifier {
+                    name: "reentrance_count".to_string(),
+                    loc,
+                },
+                None,
+                Vec::new(),
+                pt::FunctionTy::Function,
+                Some(pt::Mutability::View(loc)),
+                pt::Visibility::Public(Some(loc)),
+                vec![],
+                vec![Parameter {
+                    loc,
+                    id: Some(identifier("count")),
+                    ty: Type::Uint(32),
+                    ty_loc: Some(loc),
+                    readonly: false,
+                    indexed: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                }],
+                self,
+            ),
+            // account_reentrance_count API
+            Function::new(
+                loc,
+                loc,
+                pt::Identifier {
+                    name: "account_reentrance_count".to_string(),
+                    loc,
+                },
+                None,
+                Vec::new(),
+                pt::FunctionTy::Function,
+                Some(pt::Mutability::View(loc)),
+                pt::Visibility::Public(Some(loc)),
+                vec![Parameter {
+                    loc,
+                    id: Some(identifier("account")),
+                    ty: Type::Address(false),
+                    ty_loc: Some(loc),
+                    readonly: false,
+                    indexed: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                }],
+                vec![Parameter {
+                    loc,
+                    id: Some(identifier("count")),
+                    ty: Type::Uint(32),
+                    ty_loc: Some(loc),
+                    readonly: false,
+                    indexed: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                }],
+                self,
+            ),
         ] {
             func.has_body = true;
             let func_no = self.functions.len();
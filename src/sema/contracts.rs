@@ -49,12 +49,14 @@ impl ast::Contract {
             variables: Vec::new(),
             creates: Vec::new(),
             emits_events: Vec::new(),
+            errors: Vec::new(),
             initializer: None,
             default_constructor: None,
             cfg: Vec::new(),
             code: OnceCell::new(),
             instantiable,
             program_id: None,
+            chain_extension: None,
         }
     }
 
@@ -92,6 +94,7 @@ pub fn resolve(contracts: &[ContractDefinition], file_no: usize, ns: &mut ast::N
         check_inheritance(contract_no, ns);
         mangle_function_names(contract_no, ns);
         verify_unique_selector(contract_no, ns);
+        verify_chain_extension_ids(contract_no, ns);
         polkadot_requires_public_functions(contract_no, ns);
         unique_constructor_names(contract_no, ns);
         check_mangled_function_names(contract_no, ns);
@@ -713,6 +716,47 @@ fn check_mangled_function_names(contract_no: usize, ns: &mut ast::Namespace) {
 }
 
 /// A contract on the contracts pallet requires at least one public message
+/// Every function of a chain extension needs a unique function id
+fn verify_chain_extension_ids(contract_no: usize, ns: &mut Namespace) {
+    if ns.contracts[contract_no].chain_extension.is_none() {
+        return;
+    }
+
+    let mut ids: HashMap<u16, usize> = HashMap::new();
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+
+    for func_no in ns.contracts[contract_no].all_functions.keys() {
+        let func = &ns.functions[*func_no];
+
+        let Some((_, id)) = func.chain_extension_id else {
+            diagnostics.push(ast::Diagnostic::error(
+                func.loc_prototype,
+                format!(
+                    "function '{}' of chain extension '{}' requires a '@function_id' annotation",
+                    func.id, ns.contracts[contract_no].id
+                ),
+            ));
+            continue;
+        };
+
+        if let Some(other_func_no) = ids.insert(id, *func_no) {
+            let other = &ns.functions[other_func_no];
+
+            diagnostics.push(ast::Diagnostic::error_with_note(
+                func.loc_prototype,
+                format!(
+                    "function '{}' has the same function id {} as function '{}'",
+                    func.id, id, other.id
+                ),
+                other.loc_prototype,
+                format!("definition of function '{}'", other.id),
+            ));
+        }
+    }
+
+    ns.diagnostics.append(&mut diagnostics);
+}
+
 fn polkadot_requires_public_functions(contract_no: usize, ns: &mut ast::Namespace) {
     let contract = &mut ns.contracts[contract_no];
 
@@ -1455,6 +1455,21 @@ impl Dot {
         if let Some(flags) = &call_args.flags {
             self.add_expression(flags, func, ns, node, String::from("flags"));
         }
+        if let Some(proof_size) = &call_args.proof_size {
+            self.add_expression(proof_size, func, ns, node, String::from("proofSize"));
+        }
+        if let Some(storage_deposit_limit) = &call_args.storage_deposit_limit {
+            self.add_expression(
+                storage_deposit_limit,
+                func,
+                ns,
+                node,
+                String::from("storageDepositLimit"),
+            );
+        }
+        if let Some(code_hash) = &call_args.code_hash {
+            self.add_expression(code_hash, func, ns, node, String::from("codeHash"));
+        }
     }
 
     fn add_string_location(
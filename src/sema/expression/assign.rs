@@ -4,7 +4,7 @@ use crate::sema::ast::{Expression, Namespace, RetrieveType, Type};
 use crate::sema::diagnostics::Diagnostics;
 use crate::sema::expression::integers::type_bits_and_sign;
 use crate::sema::expression::resolve_expression::expression;
-use crate::sema::expression::{ExprContext, ResolveTo};
+use crate::sema::expression::{soroban_storage_array_copy, ExprContext, ResolveTo};
 use crate::sema::symtable::Symtable;
 use crate::sema::unused_variable::{assigned_variable, used_variable};
 use solang_parser::diagnostics::Diagnostic;
@@ -105,6 +105,8 @@ pub(super) fn assign_single(
 
             let ty = ty.deref_any();
 
+            soroban_storage_array_copy(loc, ty, ns, diagnostics)?;
+
             Ok(Expression::Assign {
                 loc: *loc,
                 ty: ty.clone(),
@@ -151,6 +153,8 @@ pub(super) fn assign_single(
                     }
                 }
 
+                soroban_storage_array_copy(loc, r_ty, ns, diagnostics)?;
+
                 Ok(Expression::Assign {
                     loc: *loc,
                     ty: *r_ty.clone(),
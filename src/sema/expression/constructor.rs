@@ -39,9 +39,32 @@ fn constructor(
         return Err(());
     }
 
-    // The current contract cannot be constructed with new. In order to create
-    // the contract, we need the code hash of the contract. Part of that code
-    // will be code we're emitted here. So we end up with a crypto puzzle.
+    // With a code hash, the code of the contract is not needed
+    if call_args.code_hash.is_none() {
+        creates_contract(loc, no, context, ns, diagnostics)?;
+    }
+
+    match match_constructor_to_args(loc, args, no, context, ns, symtable, diagnostics) {
+        Ok((constructor_no, cast_args)) => Ok(Expression::Constructor {
+            loc: *loc,
+            contract_no: no,
+            constructor_no,
+            args: cast_args,
+            call_args,
+        }),
+        Err(()) => Err(()),
+    }
+}
+
+/// Record that the contract being resolved creates contract `no`. The code hash of the created
+/// contract is needed, so it cannot be the current contract or a contract which creates it.
+fn creates_contract(
+    loc: &pt::Loc,
+    no: usize,
+    context: &mut ExprContext,
+    ns: &mut Namespace,
+    diagnostics: &mut Diagnostics,
+) -> Result<(), ()> {
     if let Some(context_contract_no) = context.contract_no {
         if context_contract_no == no {
             diagnostics.push(Diagnostic::error(
@@ -79,16 +102,7 @@ fn constructor(
         ns.functions[function_no].creates.push((*loc, no));
     }
 
-    match match_constructor_to_args(loc, args, no, context, ns, symtable, diagnostics) {
-        Ok((constructor_no, cast_args)) => Ok(Expression::Constructor {
-            loc: *loc,
-            contract_no: no,
-            constructor_no,
-            args: cast_args,
-            call_args,
-        }),
-        Err(()) => Err(()),
-    }
+    Ok(())
 }
 
 /// Try and find constructor for arguments
@@ -275,45 +289,9 @@ pub fn constructor_named_args(
         return Err(());
     }
 
-    // The current contract cannot be constructed with new. In order to create
-    // the contract, we need the code hash of the contract. Part of that code
-    // will be code we're emitted here. So we end up with a crypto puzzle.
-
-    if let Some(context_contract_no) = context.contract_no {
-        if context_contract_no == no {
-            diagnostics.push(Diagnostic::error(
-                *loc,
-                format!(
-                    "new cannot construct current contract '{}'",
-                    ns.contracts[no].id
-                ),
-            ));
-            return Err(());
-        }
-
-        // check for circular references
-        if circular_reference(no, context_contract_no, ns) {
-            diagnostics.push(Diagnostic::error(
-                *loc,
-                format!(
-                    "circular reference creating contract '{}'",
-                    ns.contracts[no].id
-                ),
-            ));
-            return Err(());
-        }
-
-        if !ns.contracts[context_contract_no].creates.contains(&no) {
-            ns.contracts[context_contract_no].creates.push(no);
-        }
-    }
-
-    // This is not always in a function: e.g. contract variable:
-    // contract C {
-    //      D code = new D({});
-    // }
-    if let Some(function_no) = context.function_no {
-        ns.functions[function_no].creates.push((*loc, no));
+    // With a code hash, the code of the contract is not needed
+    if call_args.code_hash.is_none() {
+        creates_contract(loc, no, context, ns, diagnostics)?;
     }
 
     let mut arguments: BTreeMap<&str, &pt::Expression> = BTreeMap::new();
@@ -11,7 +11,7 @@ use crate::sema::expression::constructor::{
 };
 use crate::sema::expression::literals::{named_struct_literal, struct_literal};
 use crate::sema::expression::resolve_expression::expression;
-use crate::sema::expression::{ExprContext, ResolveTo};
+use crate::sema::expression::{soroban_storage_array_copy, ExprContext, ResolveTo};
 use crate::sema::format::string_format;
 use crate::sema::namespace::ResolveTypeContext;
 use crate::sema::symtable::Symtable;
@@ -149,6 +149,15 @@ pub(super) fn call_function_type(
             return Err(());
         }
 
+        // Soroban functions return a single Val
+        if ns.target == Target::Soroban && returns.len() > 1 {
+            diagnostics.push(Diagnostic::error(
+                *loc,
+                "function types returning more than one value are not supported for external calls on Soroban".to_string(),
+            ));
+            return Err(());
+        }
+
         let mut cast_args = Vec::new();
 
         // check if arguments can be implicitly casted
@@ -762,6 +771,37 @@ fn try_namespace(
                 )?));
             }
 
+            // call to a chain extension function on Polkadot
+            if ns.contracts[call_contract_no].chain_extension.is_some() {
+                if let Some(loc) = call_args_loc {
+                    diagnostics.push(Diagnostic::error(
+                        loc,
+                        "call arguments not allowed on chain extension calls".to_string(),
+                    ));
+                    return Err(());
+                }
+
+                return Ok(Some(function_call_pos_args(
+                    loc,
+                    &id_path,
+                    pt::FunctionTy::Function,
+                    args,
+                    available_functions(
+                        &func.name,
+                        false,
+                        context.file_no,
+                        Some(call_contract_no),
+                        ns,
+                    ),
+                    false,
+                    context,
+                    ns,
+                    resolve_to,
+                    symtable,
+                    diagnostics,
+                )?));
+            }
+
             // is a base contract of us
             if let Some(contract_no) = context.contract_no {
                 if is_base(call_contract_no, contract_no, ns) {
@@ -856,6 +896,21 @@ fn try_storage_reference(
     resolve_to: &ResolveTo,
 ) -> Result<Option<Expression>, ()> {
     if let Type::StorageRef(immutable, ty) = &var_expr.ty() {
+        if ns.target == Target::Soroban && func.name == "extendTtl" {
+            return extend_ttl(
+                var_expr,
+                ty,
+                func,
+                args,
+                call_args_loc,
+                context,
+                ns,
+                symtable,
+                diagnostics,
+            )
+            .map(Some);
+        }
+
         match ty.as_ref() {
             Type::Array(_, dim) => {
                 if *immutable {
@@ -893,6 +948,8 @@ fn try_storage_reference(
 
                     let ret_ty = match args.len() {
                         1 => {
+                            soroban_storage_array_copy(&args[0].loc(), &elem_ty, ns, diagnostics)?;
+
                             let expr = expression(
                                 &args[0],
                                 context,
@@ -959,6 +1016,8 @@ fn try_storage_reference(
                     let return_ty = if *resolve_to == ResolveTo::Discard {
                         Type::Void
                     } else {
+                        soroban_storage_array_copy(&func.loc, elem_ty, ns, diagnostics)?;
+
                         elem_ty.clone()
                     };
 
@@ -1059,6 +1118,73 @@ fn try_storage_reference(
     Ok(None)
 }
 
+/// Resolve `extendTtl(threshold, extendTo)` on a storage reference. On Soroban, each state
+/// variable and each element of a mapping or array is a separate contract data entry,
+/// with its own TTL.
+fn extend_ttl(
+    var_expr: &Expression,
+    ty: &Type,
+    func: &pt::Identifier,
+    args: &[pt::Expression],
+    call_args_loc: Option<pt::Loc>,
+    context: &mut ExprContext,
+    ns: &mut Namespace,
+    symtable: &mut Symtable,
+    diagnostics: &mut Diagnostics,
+) -> Result<Expression, ()> {
+    if let Some(loc) = call_args_loc {
+        diagnostics.push(Diagnostic::error(
+            loc,
+            "call arguments not allowed on 'extendTtl'".to_string(),
+        ));
+        return Err(());
+    }
+
+    if matches!(ty, Type::Mapping(..) | Type::Array(..) | Type::Struct(..)) {
+        diagnostics.push(Diagnostic::error(
+            func.loc,
+            format!(
+                "cannot extend the TTL of '{}', extend the TTL of its elements instead",
+                ty.to_string(ns)
+            ),
+        ));
+        return Err(());
+    }
+
+    if args.len() != 2 {
+        diagnostics.push(Diagnostic::error(
+            func.loc,
+            format!(
+                "method 'extendTtl()' expects 2 arguments, {} provided",
+                args.len()
+            ),
+        ));
+        return Err(());
+    }
+
+    let mut builtin_args = vec![var_expr.clone()];
+
+    for arg in args {
+        let expr = expression(
+            arg,
+            context,
+            ns,
+            symtable,
+            diagnostics,
+            ResolveTo::Type(&Type::Uint(32)),
+        )?;
+
+        builtin_args.push(expr.cast(&arg.loc(), &Type::Uint(32), true, ns, diagnostics)?);
+    }
+
+    Ok(Expression::Builtin {
+        loc: func.loc,
+        tys: vec![Type::Void],
+        kind: Builtin::ExtendTtl,
+        args: builtin_args,
+    })
+}
+
 /// Check if we can resolve the call with ns.resolve_type
 /// Returns:
 /// 1. Err, when there is an error
@@ -1369,6 +1495,14 @@ fn try_type_method(
                 };
             }
 
+            if func.name == "delegatecall" && ns.target == Target::Soroban {
+                diagnostics.push(Diagnostic::error(
+                    *loc,
+                    "'delegatecall' is not supported on Soroban".to_string(),
+                ));
+                return Err(());
+            }
+
             let ty = match func.name.as_str() {
                 "call" => Some(CallTy::Regular),
                 "delegatecall" if ns.target != Target::Solana => Some(CallTy::Delegate),
@@ -1397,11 +1531,19 @@ fn try_type_method(
                     return Err(());
                 }
 
-                if ty == CallTy::Delegate && ns.target.is_polkadot() && call_args.gas.is_some() {
-                    diagnostics.push(Diagnostic::warning(
-                        *loc,
-                        "'gas' specified on 'delegatecall' will be ignored".into(),
-                    ));
+                if ty == CallTy::Delegate && ns.target.is_polkadot() {
+                    for (name, arg) in [
+                        ("gas", &call_args.gas),
+                        ("proofSize", &call_args.proof_size),
+                        ("storageDepositLimit", &call_args.storage_deposit_limit),
+                    ] {
+                        if arg.is_some() {
+                            diagnostics.push(Diagnostic::warning(
+                                *loc,
+                                format!("'{name}' specified on 'delegatecall' will be ignored"),
+                            ));
+                        }
+                    }
                 }
 
                 if args.len() != 1 {
@@ -1743,6 +1885,36 @@ pub(super) fn method_call_named_args(
                 );
             }
 
+            // call to a chain extension function on Polkadot
+            if ns.contracts[call_contract_no].chain_extension.is_some() {
+                if let Some(loc) = call_args_loc {
+                    diagnostics.push(Diagnostic::error(
+                        loc,
+                        "call arguments not allowed on chain extension calls".to_string(),
+                    ));
+                    return Err(());
+                }
+
+                return function_call_named_args(
+                    loc,
+                    &id_path,
+                    args,
+                    available_functions(
+                        &func_name.name,
+                        false,
+                        context.file_no,
+                        Some(call_contract_no),
+                        ns,
+                    ),
+                    false,
+                    context,
+                    resolve_to,
+                    ns,
+                    symtable,
+                    diagnostics,
+                );
+            }
+
             // is a base contract of us
             if let Some(contract_no) = context.contract_no {
                 if is_base(call_contract_no, contract_no, ns) {
@@ -1970,10 +2142,30 @@ pub(super) fn parse_call_args(
         args.insert(&arg.name.name, arg);
     }
 
+    if let (Some(gas), Some(ref_time)) = (
+        args.get(&String::from("gas")),
+        args.get(&String::from("refTime")),
+    ) {
+        diagnostics.push(Diagnostic::error_with_note(
+            ref_time.loc,
+            "'refTime' cannot be specified together with 'gas'".to_string(),
+            gas.loc,
+            "location of 'gas'".to_string(),
+        ));
+        return Err(());
+    }
+
     let mut res = CallArgs::default();
 
     for arg in args.values() {
         match arg.name.name.as_str() {
+            "value" if ns.target == Target::Soroban => {
+                diagnostics.push(Diagnostic::error(
+                    arg.loc,
+                    "'value' not permitted for external calls or constructors on Soroban. Transfer the native token explicitly instead".to_string(),
+                ));
+                return Err(());
+            }
             "value" => {
                 if ns.target == Target::Solana {
                     diagnostics.push(Diagnostic::error(
@@ -2011,7 +2203,7 @@ pub(super) fn parse_call_args(
                 }
             }
             "gas" => {
-                if ns.target == Target::Solana {
+                if ns.target == Target::Solana || ns.target == Target::Soroban {
                     diagnostics.push(Diagnostic::error(
                         arg.loc,
                         format!(
@@ -2211,6 +2403,84 @@ pub(super) fn parse_call_args(
                 let flags = expr.cast(&arg.expr.loc(), &ty, true, ns, diagnostics)?;
                 res.flags = Some(flags.into());
             }
+            "refTime" | "proofSize" | "storageDepositLimit" => {
+                if !ns.target.is_polkadot() {
+                    diagnostics.push(Diagnostic::error(
+                        arg.loc,
+                        format!(
+                            "'{}' is only permitted for external calls or constructors on polkadot",
+                            arg.name.name
+                        ),
+                    ));
+                    return Err(());
+                }
+
+                let ty = if arg.name.name == "storageDepositLimit" {
+                    Type::Value
+                } else {
+                    Type::Uint(64)
+                };
+
+                let expr = expression(
+                    &arg.expr,
+                    context,
+                    ns,
+                    symtable,
+                    diagnostics,
+                    ResolveTo::Type(&ty),
+                )?;
+
+                let expr = Some(Box::new(expr.cast(
+                    &arg.expr.loc(),
+                    &ty,
+                    true,
+                    ns,
+                    diagnostics,
+                )?));
+
+                // 'gas' is the ref time limit on Polkadot
+                match arg.name.name.as_str() {
+                    "refTime" => res.gas = expr,
+                    "proofSize" => res.proof_size = expr,
+                    _ => res.storage_deposit_limit = expr,
+                }
+            }
+            "codeHash" => {
+                if !ns.target.is_polkadot() {
+                    diagnostics.push(Diagnostic::error(
+                        arg.loc,
+                        "'codeHash' is only permitted for constructors on polkadot".to_string(),
+                    ));
+                    return Err(());
+                }
+
+                if external_call {
+                    diagnostics.push(Diagnostic::error(
+                        arg.loc,
+                        "'codeHash' not valid for external calls".to_string(),
+                    ));
+                    return Err(());
+                }
+
+                let ty = Type::Bytes(32);
+
+                let expr = expression(
+                    &arg.expr,
+                    context,
+                    ns,
+                    symtable,
+                    diagnostics,
+                    ResolveTo::Type(&ty),
+                )?;
+
+                res.code_hash = Some(Box::new(expr.cast(
+                    &arg.expr.loc(),
+                    &ty,
+                    true,
+                    ns,
+                    diagnostics,
+                )?));
+            }
             _ => {
                 diagnostics.push(Diagnostic::error(
                     arg.loc,
@@ -3255,6 +3525,19 @@ fn contract_call_match(
     }
 
     let func = &ns.functions[function_no];
+
+    // Soroban functions return a single Val
+    if ns.target == Target::Soroban && func.returns.len() > 1 {
+        diagnostics.push(Diagnostic::error(
+            *loc,
+            format!(
+                "function '{}' returns more than one value, which is not supported for external calls on Soroban",
+                func.id
+            ),
+        ));
+        return Err(());
+    }
+
     let returns = function_returns(func, resolve_to);
     let ty = function_type(func, true, resolve_to);
 
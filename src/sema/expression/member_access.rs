@@ -382,6 +382,13 @@ pub(super) fn member_access(
                         .to_string(),
                 ));
                 return Err(());
+            } else if ns.target == Target::Soroban {
+                diagnostics.push(Diagnostic::error(
+                    expr.loc(),
+                    "balance is not available on Soroban. Call 'balance' on the token contract instead"
+                        .to_string(),
+                ));
+                return Err(());
             }
             used_variable(ns, &expr, symtable);
             return Ok(Expression::Builtin {
@@ -21,6 +21,7 @@ use super::symtable::{Symtable, VarScope};
 use crate::sema::contracts::is_base;
 use crate::sema::eval::eval_const_number;
 use crate::sema::{symtable::LoopScopes, using::user_defined_operator_binding};
+use crate::Target;
 use num_bigint::{BigInt, Sign};
 use num_rational::BigRational;
 use num_traits::{FromPrimitive, ToPrimitive, Zero};
@@ -176,6 +177,8 @@ impl Expression {
                 }
             }
 
+            soroban_storage_array_copy(loc, &r, ns, diagnostics)?;
+
             return Expression::StorageLoad {
                 loc: *loc,
                 ty: *r,
@@ -1299,6 +1302,28 @@ impl Expression {
     }
 }
 
+/// On Soroban, every element of an array in storage has a key of its own, so arrays cannot be
+/// copied to or from storage as a whole
+pub(crate) fn soroban_storage_array_copy(
+    loc: &pt::Loc,
+    ty: &Type,
+    ns: &Namespace,
+    diagnostics: &mut Diagnostics,
+) -> Result<(), ()> {
+    if ns.target == Target::Soroban && matches!(ty.deref_any(), Type::Array(..)) {
+        diagnostics.push(Diagnostic::error(
+            *loc,
+            format!(
+                "copying '{}' to or from storage is not supported on Soroban, access its elements instead",
+                ty.deref_any().to_string(ns)
+            ),
+        ));
+        Err(())
+    } else {
+        Ok(())
+    }
+}
+
 /// Can this type be cast to a bytes slice
 fn can_cast_to_slice(ty: &Type) -> bool {
     matches!(
@@ -26,6 +26,7 @@ use crate::sema::{
         diagnostics::Diagnostics,
     },
 };
+use crate::Target;
 use num_bigint::BigInt;
 use num_traits::Num;
 use solang_parser::{diagnostics::Diagnostic, pt, pt::CodeLocation};
@@ -187,6 +188,17 @@ pub fn expression(
             let cond = cond.cast(&c.loc(), &Type::Bool, true, ns, diagnostics)?;
 
             let ty = coerce(&left.ty(), &l.loc(), &right.ty(), &r.loc(), ns, diagnostics)?;
+
+            // a mapping cannot be loaded, so this would select between storage references,
+            // and the storage type of the referenced variable must be known at compile time
+            if ns.target == Target::Soroban && ty.is_mapping() {
+                diagnostics.push(Diagnostic::error(
+                    *loc,
+                    "storage references are not supported on Soroban".to_string(),
+                ));
+                return Err(());
+            }
+
             let left = left.cast(&l.loc(), &ty, true, ns, diagnostics)?;
             let right = right.cast(&r.loc(), &ty, true, ns, diagnostics)?;
 
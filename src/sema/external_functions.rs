@@ -45,9 +45,14 @@ pub fn add_external_functions(contract_no: usize, ns: &mut Namespace) {
 
         // add functions to contract functions list
         for function_no in &call_list.solidity {
-            if ns.functions[*function_no].loc_prototype != pt::Loc::Builtin {
-                let func = &ns.functions[*function_no];
+            let func = &ns.functions[*function_no];
+
+            // chain extension functions are implemented by the chain, like builtins
+            let chain_extension = func
+                .contract_no
+                .is_some_and(|contract_no| ns.contracts[contract_no].chain_extension.is_some());
 
+            if func.loc_prototype != pt::Loc::Builtin && !chain_extension {
                 // make sure we are not adding a public function which is not a base or library
                 if func.is_public() {
                     // free function are not public, else this unwrap would panic
@@ -103,6 +108,41 @@ pub fn add_external_functions(contract_no: usize, ns: &mut Namespace) {
     }
 
     ns.contracts[contract_no].emits_events = emits_events;
+
+    // the errors of this contract are those declared in the contract or its bases, and any
+    // other errors which its functions revert with
+    let bases = ns.contract_bases(contract_no);
+
+    let mut errors: Vec<usize> = ns
+        .errors
+        .iter()
+        .enumerate()
+        .filter(|(_, error)| error.contract.is_some_and(|c| bases.contains(&c)))
+        .map(|(error_no, _)| error_no)
+        .collect();
+
+    for function_no in ns.contracts[contract_no].all_functions.keys() {
+        for stmt in &ns.functions[*function_no].body {
+            stmt.recurse(&mut errors, check_revert);
+        }
+    }
+
+    errors.sort_unstable();
+    errors.dedup();
+
+    ns.contracts[contract_no].errors = errors;
+}
+
+fn check_revert(stmt: &Statement, errors: &mut Vec<usize>) -> bool {
+    if let Statement::Revert {
+        error_no: Some(error_no),
+        ..
+    } = stmt
+    {
+        errors.push(*error_no);
+    }
+
+    true
 }
 
 fn check_expression(expr: &Expression, call_list: &mut CallList) -> bool {
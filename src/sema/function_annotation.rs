@@ -15,6 +15,7 @@ use crate::sema::expression::resolve_expression::expression;
 use crate::sema::solana_accounts::BuiltinAccounts;
 use crate::Target;
 use indexmap::map::Entry;
+use num_bigint::BigInt;
 use num_traits::ToPrimitive;
 use solang_parser::pt::{self, Annotation, CodeLocation, Visibility};
 use std::str::FromStr;
@@ -70,6 +71,14 @@ pub fn function_prototype_annotations(
                 );
             }
 
+            "nonReentrant" if ns.target.is_polkadot() && func.has_body => {
+                function_nonreentrant(func, annotation, &mut diagnostics)
+            }
+
+            "function_id" if ns.target.is_polkadot() => {
+                function_chain_extension_id(func, annotation, &mut diagnostics, ns)
+            }
+
             _ if !func.has_body => {
                 // function_body_annotations() is called iff there is a body
                 diagnostics.push(Diagnostic::error(
@@ -186,6 +195,133 @@ fn function_selector(
     }
 }
 
+/// Mark the function as rejecting re-entrant calls. The check is done in the Polkadot dispatch,
+/// so the function must be callable externally.
+fn function_nonreentrant(
+    func: &mut Function,
+    annotation: &pt::Annotation,
+    diagnostics: &mut Diagnostics,
+) {
+    if let Some(value) = &annotation.value {
+        diagnostics.push(Diagnostic::error(
+            value.loc(),
+            "'@nonReentrant' annotation does not take a value".into(),
+        ));
+        return;
+    }
+
+    if !matches!(
+        func.ty,
+        pt::FunctionTy::Function | pt::FunctionTy::Fallback | pt::FunctionTy::Receive
+    ) {
+        diagnostics.push(Diagnostic::error(
+            annotation.loc,
+            format!("'@nonReentrant' not permitted on {}", func.ty),
+        ));
+        return;
+    }
+
+    if !func.is_public() {
+        diagnostics.push(Diagnostic::error(
+            annotation.loc,
+            format!(
+                "'@nonReentrant' only permitted on 'public' or 'external' function, not '{}'",
+                func.visibility
+            ),
+        ));
+        return;
+    }
+
+    if let Some(prev) = func.nonreentrant {
+        duplicate_annotation(
+            diagnostics,
+            "nonReentrant",
+            annotation.loc,
+            prev,
+            func.ty.as_str(),
+        );
+        return;
+    }
+
+    func.nonreentrant = Some(annotation.loc);
+}
+
+/// Set the function id of a function in a chain extension interface
+fn function_chain_extension_id(
+    func: &mut Function,
+    annotation: &pt::Annotation,
+    diagnostics: &mut Diagnostics,
+    ns: &mut Namespace,
+) {
+    if func.ty != pt::FunctionTy::Function
+        || func
+            .contract_no
+            .and_then(|contract_no| ns.contracts[contract_no].chain_extension)
+            .is_none()
+    {
+        diagnostics.push(Diagnostic::error(
+            annotation.loc,
+            "'@function_id' is only permitted on functions of a chain extension".into(),
+        ));
+        return;
+    }
+
+    if let Some((prev, _)) = func.chain_extension_id {
+        duplicate_annotation(
+            diagnostics,
+            "function_id",
+            annotation.loc,
+            prev,
+            func.ty.as_str(),
+        );
+        return;
+    }
+
+    let value = annotation.value.as_ref().unwrap();
+
+    if let Some(id) = annotation_number(value, &Type::Uint(16), diagnostics, ns) {
+        func.chain_extension_id = Some((annotation.loc, id.to_u16().unwrap()));
+    }
+}
+
+/// Resolve the literal number in an annotation, which must fit into `ty`
+pub(super) fn annotation_number(
+    value: &pt::Expression,
+    ty: &Type,
+    diagnostics: &mut Diagnostics,
+    ns: &mut Namespace,
+) -> Option<BigInt> {
+    let expr = match value {
+        pt::Expression::HexNumberLiteral(loc, n, None) => {
+            hex_number_literal(loc, n, ns, diagnostics, ResolveTo::Type(ty))
+        }
+        pt::Expression::NumberLiteral(loc, base, exp, unit) => {
+            let unit = unit_literal(loc, unit, ns, diagnostics);
+
+            number_literal(loc, base, exp, ns, &unit, diagnostics, ResolveTo::Type(ty))
+        }
+        _ => {
+            diagnostics.push(Diagnostic::error(
+                value.loc(),
+                "literal number expected".into(),
+            ));
+            return None;
+        }
+    };
+
+    match expr {
+        Ok(Expression::NumberLiteral { loc, value, .. }) => {
+            if let Some(diagnostic) = overflow_diagnostic(&value, ty, &loc) {
+                diagnostics.push(diagnostic);
+                None
+            } else {
+                Some(value)
+            }
+        }
+        _ => None,
+    }
+}
+
 /// Collect the seeds, bump, payer, and space for constructors. This is a no-op on Polkadot/EVM since
 /// there should be no seed or bump annotations permitted on other targets.
 ///
@@ -217,6 +353,9 @@ pub(super) fn function_body_annotations(
                 // selectors already done in function_prototype_annotations
                 // without using a symbol table
             }
+            "nonReentrant" | "function_id" if ns.target.is_polkadot() => {
+                // also done in function_prototype_annotations
+            }
             "seed" if is_solana_constructor => {
                 let ty = Type::Slice(Box::new(Type::Bytes(1)));
 
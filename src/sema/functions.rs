@@ -305,6 +305,19 @@ pub fn contract_function(
         }
     };
 
+    if func.ty == pt::FunctionTy::Function
+        && matches!(
+            visibility,
+            pt::Visibility::Public(_) | pt::Visibility::External(_)
+        )
+    {
+        if let Some(id) = &func.name {
+            if !ns.check_soroban_symbol(id, "function") {
+                success = false;
+            }
+        }
+    }
+
     let mut diagnostics = Diagnostics::default();
 
     let (params, params_success) = resolve_params(
@@ -483,8 +496,9 @@ pub fn contract_function(
     function_prototype_annotations(&mut fdecl, annotations, ns);
 
     if func.ty == pt::FunctionTy::Constructor {
-        // In the eth solidity only one constructor is allowed
-        if ns.target == Target::EVM {
+        // In the eth solidity only one constructor is allowed. On Soroban, the constructor
+        // is called from the exported `init` function, so there can only be one too.
+        if ns.target == Target::EVM || ns.target == Target::Soroban {
             if let Some(prev_func_no) = ns.contracts[contract_no]
                 .functions
                 .iter()
@@ -908,6 +922,16 @@ pub fn resolve_params(
                         diagnostics.push(Diagnostic::error(p.ty.loc(), message));
                         success = false
                     }
+
+                    // Soroban passes every argument as a `Val`
+                    if ns.target == Target::Soroban && !ty.is_soroban_val(ns) {
+                        let message = format!(
+                            "parameter of type '{}' not supported in public or external functions on Soroban",
+                            ty.to_string(ns)
+                        );
+                        diagnostics.push(Diagnostic::error(p.ty.loc(), message));
+                        success = false
+                    }
                 }
 
                 let ty = if !ty.can_have_data_location() {
@@ -931,6 +955,29 @@ pub fn resolve_params(
                         success = false;
                     }
 
+                    // the storage type of the referenced variable must be known at compile time
+                    if ns.target == Target::Soroban {
+                        diagnostics.push(Diagnostic::error(
+                            loc,
+                            "storage references are not supported on Soroban".to_string(),
+                        ));
+                        success = false;
+                    }
+
+                    // a string or bytes reference could be a mapping entry, which has a different slot
+                    if ns.target.ink_storage_keys()
+                        && matches!(ty, Type::String | Type::DynamicBytes)
+                    {
+                        diagnostics.push(Diagnostic::error(
+                            loc,
+                            format!(
+                                "storage references to '{}' are not supported with ink! storage keys",
+                                ty.to_string(ns)
+                            ),
+                        ));
+                        success = false;
+                    }
+
                     ty_loc.use_end_from(&loc);
 
                     Type::StorageRef(false, Box::new(ty))
@@ -1036,6 +1083,16 @@ pub fn resolve_returns(
                         diagnostics.push(Diagnostic::error(r.ty.loc(), message));
                         success = false
                     }
+
+                    // Soroban returns a `Val`
+                    if ns.target == Target::Soroban && !ty.is_soroban_val(ns) {
+                        let message = format!(
+                            "return type '{}' not supported in public or external functions on Soroban",
+                            ty.to_string(ns)
+                        );
+                        diagnostics.push(Diagnostic::error(r.ty.loc(), message));
+                        success = false
+                    }
                 }
                 let ty = if !ty.can_have_data_location() {
                     if let Some(storage) = &r.storage {
@@ -1060,6 +1117,27 @@ pub fn resolve_returns(
                                 success = false;
                             }
 
+                            if ns.target == Target::Soroban {
+                                diagnostics.push(Diagnostic::error(
+                                    loc,
+                                    "storage references are not supported on Soroban".to_string(),
+                                ));
+                                success = false;
+                            }
+
+                            if ns.target.ink_storage_keys()
+                                && matches!(ty, Type::String | Type::DynamicBytes)
+                            {
+                                diagnostics.push(Diagnostic::error(
+                                    loc,
+                                    format!(
+                                        "storage references to '{}' are not supported with ink! storage keys",
+                                        ty.to_string(ns)
+                                    ),
+                                ));
+                                success = false;
+                            }
+
                             ty_loc.use_end_from(&loc);
 
                             Type::StorageRef(false, Box::new(ty))
@@ -159,6 +159,7 @@ fn sema_file(file: &ResolvedFile, resolver: &mut FileResolver, ns: &mut ast::Nam
                     var,
                     file_no,
                     &item.doccomments,
+                    &[],
                     None,
                     ns,
                     &mut Symtable::default(),
@@ -278,6 +279,14 @@ fn resolve_import(
     } else {
         match resolver.resolve_file(parent, &os_filename) {
             Err(message) => {
+                // the builtins of other targets are not files
+                let message = match os_filename.to_str() {
+                    Some(builtins @ ("polkadot" | "solana")) => {
+                        format!("'{builtins}' builtins are not available on {}", ns.target)
+                    }
+                    _ => message,
+                };
+
                 ns.diagnostics
                     .push(ast::Diagnostic::error(filename.loc, message));
 
@@ -463,6 +472,21 @@ fn collect_annotations_doccomments<'a>(
                         let tags =
                             parse_doccomments(comments, doc_comment_start, part.loc().start());
 
+                        match part {
+                            pt::ContractPart::VariableDefinition(_) => (),
+                            pt::ContractPart::FunctionDefinition(_) => {
+                                parts_annotations = annotations_with_value(
+                                    parts_annotations,
+                                    &["nonReentrant"],
+                                    ns,
+                                );
+                            }
+                            _ => {
+                                parts_annotations =
+                                    annotations_with_value(parts_annotations, &[], ns);
+                            }
+                        }
+
                         parts.push(ContractPart {
                             part,
                             doccomments: tags,
@@ -495,7 +519,7 @@ fn collect_annotations_doccomments<'a>(
                 contract_no,
                 loc: contract.loc,
                 ty: contract.ty.clone(),
-                annotations,
+                annotations: annotations_with_value(annotations, &[], ns),
                 doccomments,
                 name: contract.name.as_ref(),
                 base: contract.base.clone(),
@@ -534,6 +558,33 @@ fn collect_annotations_doccomments<'a>(
     SourceUnit { items, contracts }
 }
 
+/// Only annotations on state variables may omit the value, like `@persistent`, and the
+/// annotations listed in `without_value`, like `@nonReentrant` on functions. Generate
+/// diagnostic errors for any others, and remove them.
+fn annotations_with_value<'a>(
+    annotations: Vec<&'a pt::Annotation>,
+    without_value: &[&str],
+    ns: &mut ast::Namespace,
+) -> Vec<&'a pt::Annotation> {
+    annotations
+        .into_iter()
+        .filter(|note| {
+            if without_value.contains(&note.id.name.as_str()) {
+                return true;
+            }
+
+            if note.value.is_none() {
+                ns.diagnostics.push(ast::Diagnostic::error(
+                    note.loc,
+                    format!("'@{}' annotation requires a value", note.id.name),
+                ));
+            }
+
+            note.value.is_some()
+        })
+        .collect()
+}
+
 /// If an item does not allow annotations, then generate diagnostic errors for any annotions
 fn annotions_not_allowed(annotations: &[&pt::Annotation], item: &str, ns: &mut ast::Namespace) {
     for note in annotations {
@@ -377,6 +377,8 @@ fn read_expression(expr: &Expression, state: &mut StateCheck) -> bool {
                 Builtin::GetAddress
                 | Builtin::BlockNumber
                 | Builtin::Slot
+                | Builtin::Epoch
+                | Builtin::SlotsPerEpoch
                 | Builtin::Timestamp
                 | Builtin::BlockCoinbase
                 | Builtin::BlockDifficulty
@@ -384,6 +386,9 @@ fn read_expression(expr: &Expression, state: &mut StateCheck) -> bool {
                 | Builtin::Sender
                 | Builtin::Origin
                 | Builtin::Gasleft
+                | Builtin::RemainingComputeUnits
+                | Builtin::RentMinimumBalance
+                | Builtin::RentExempt
                 | Builtin::Gasprice
                 | Builtin::GasLimit
                 | Builtin::MinimumBalance
@@ -395,7 +400,15 @@ fn read_expression(expr: &Expression, state: &mut StateCheck) -> bool {
 
         Expression::Builtin {
             loc,
-            kind: Builtin::PayableSend | Builtin::PayableTransfer | Builtin::SelfDestruct,
+            kind:
+                Builtin::PayableSend
+                | Builtin::PayableTransfer
+                | Builtin::SelfDestruct
+                | Builtin::RequireAuth
+                | Builtin::RequireAuthForArgs
+                | Builtin::ExtendTtl
+                | Builtin::XcmExecute
+                | Builtin::XcmSend,
             ..
         } => state.write(loc),
         Expression::Builtin {
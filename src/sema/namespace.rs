@@ -39,9 +39,10 @@ impl Namespace {
             Target::Polkadot {
                 address_length,
                 value_length,
+                ..
             } => (address_length, value_length),
             Target::Solana => (32, 8),
-            Target::Soroban => (32, 64),
+            Target::Soroban => (32, 16),
         };
 
         let mut ns = Namespace {
@@ -767,6 +768,30 @@ impl Namespace {
         }
     }
 
+    /// On Soroban, functions and events are identified by their name as a `Symbol`, which may
+    /// only contain letters, digits and `_`. Solidity identifiers may contain `$` as well.
+    /// Returns false if the name cannot be a `Symbol`.
+    pub(super) fn check_soroban_symbol(&mut self, id: &pt::Identifier, kind: &str) -> bool {
+        if self.target != Target::Soroban
+            || id
+                .name
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+        {
+            return true;
+        }
+
+        self.diagnostics.push(Diagnostic::error(
+            id.loc,
+            format!(
+                "{kind} name '{}' is not a valid Soroban symbol, which may only contain letters, digits and '_'",
+                id.name
+            ),
+        ));
+
+        false
+    }
+
     /// Check if an name would shadow an existing symbol
     pub fn check_shadowing(
         &mut self,
@@ -707,6 +707,12 @@ fn statement(
             Ok(false)
         }
         pt::Statement::Expression(loc, expr) => {
+            if let Some(stmt) = require_custom_error(loc, expr, context, symtable, diagnostics, ns)
+            {
+                res.push(stmt?);
+                return Ok(true);
+            }
+
             let expr = match expr {
                 // delete statement
                 pt::Expression::Delete(_, expr) => {
@@ -891,6 +897,89 @@ fn statement(
     }
 }
 
+/// Resolve `require(condition, CustomError(...))`, which is rewritten to
+/// `if (!condition) revert CustomError(...);`
+///
+/// Returns `None` if the statement is not a require with a custom error.
+fn require_custom_error(
+    loc: &pt::Loc,
+    expr: &pt::Expression,
+    context: &mut ExprContext,
+    symtable: &mut Symtable,
+    diagnostics: &mut Diagnostics,
+    ns: &mut Namespace,
+) -> Option<Result<Statement, ()>> {
+    let pt::Expression::FunctionCall(_, func, args) = expr else {
+        return None;
+    };
+
+    match func.as_ref() {
+        pt::Expression::Variable(id) if id.name == "require" => (),
+        _ => return None,
+    }
+
+    let [cond, error] = args.as_slice() else {
+        return None;
+    };
+
+    let (error_loc, path) = match error {
+        pt::Expression::FunctionCall(loc, ty, _)
+        | pt::Expression::NamedFunctionCall(loc, ty, _) => (loc, ns.expr_to_identifier_path(ty)?),
+        _ => return None,
+    };
+
+    // only rewrite if the second argument names an error
+    ns.resolve_error(
+        context.file_no,
+        context.contract_no,
+        &path,
+        &mut Diagnostics::default(),
+    )
+    .ok()?;
+
+    let cond = match expression(
+        cond,
+        context,
+        ns,
+        symtable,
+        diagnostics,
+        ResolveTo::Type(&Type::Bool),
+    ) {
+        Ok(cond) => cond,
+        Err(()) => return Some(Err(())),
+    };
+    used_variable(ns, &cond, symtable);
+
+    let cond = match cond.cast(&cond.loc(), &Type::Bool, true, ns, diagnostics) {
+        Ok(cond) => cond,
+        Err(()) => return Some(Err(())),
+    };
+
+    let path = Some(path);
+    let revert = match error {
+        pt::Expression::FunctionCall(_, _, args) => {
+            revert_pos_arg(error_loc, &path, args, context, symtable, diagnostics, ns)
+        }
+        pt::Expression::NamedFunctionCall(_, _, args) => {
+            revert_named_arg(error_loc, &path, args, context, symtable, diagnostics, ns)
+        }
+        _ => unreachable!(),
+    };
+
+    Some(revert.map(|revert| {
+        Statement::If(
+            *loc,
+            true,
+            Expression::Not {
+                loc: cond.loc(),
+                expr: cond.into(),
+            },
+            vec![revert],
+            Vec::new(),
+        )
+    }))
+}
+
 /// Resolve a revert statement with position arguments, and optional error, e.g.
 /// ```ignore
 /// revert();
@@ -962,13 +1051,6 @@ fn revert_pos_arg(
             ));
         }
 
-        if ns.target == Target::Solana {
-            ns.diagnostics.push(Diagnostic::error(
-                *loc,
-                format!("revert with custom errors not supported on {}", ns.target),
-            ));
-        }
-
         ns.errors[error_no].used = true;
 
         Ok(Statement::Revert {
@@ -1135,13 +1217,6 @@ fn revert_named_arg(
             }
         }
 
-        if ns.target == Target::Solana {
-            ns.diagnostics.push(Diagnostic::error(
-                *loc,
-                format!("revert with custom errors not supported on {}", ns.target),
-            ));
-        }
-
         let mut args = Vec::new();
         let error = &ns.errors[error_no];
 
@@ -1962,6 +2037,27 @@ fn resolve_var_decl_ty(
         }
 
         if let pt::StorageLocation::Storage(loc) = storage {
+            // the storage type of the referenced variable must be known at compile time
+            if ns.target == Target::Soroban {
+                diagnostics.push(Diagnostic::error(
+                    *loc,
+                    "storage references are not supported on Soroban".to_string(),
+                ));
+                return Err(());
+            }
+
+            // a string or bytes reference could be a mapping entry, which has a different slot
+            if ns.target.ink_storage_keys() && matches!(var_ty, Type::String | Type::DynamicBytes) {
+                diagnostics.push(Diagnostic::error(
+                    *loc,
+                    format!(
+                        "storage references to '{}' are not supported with ink! storage keys",
+                        var_ty.to_string(ns)
+                    ),
+                ));
+                return Err(());
+            }
+
             loc_ty.use_end_from(loc);
             var_ty = Type::StorageRef(false, Box::new(var_ty));
         }
@@ -2256,17 +2352,6 @@ fn try_catch(
     ns: &mut Namespace,
     diagnostics: &mut Diagnostics,
 ) -> Result<(Statement, bool), ()> {
-    if ns.target == Target::Solana {
-        diagnostics.push(Diagnostic::error(
-            *loc,
-            "The try-catch statement is not supported on Solana. Please, go to \
-             https://solang.readthedocs.io/en/latest/language/statements.html#try-catch-statement \
-             for more information"
-                .to_string(),
-        ));
-        return Err(());
-    }
-
     let mut expr = expr.remove_parenthesis();
     let mut ok = None;
 
@@ -2567,6 +2652,14 @@ fn try_catch(
 
         match clause_stmt {
             CatchClause::Simple(catch_loc, param, stmt) => {
+                if ns.target == Target::Solana {
+                    ns.diagnostics.push(Diagnostic::warning(
+                        *catch_loc,
+                        "catch clause is never executed on Solana, since a failed call aborts the transaction"
+                            .to_string(),
+                    ));
+                }
+
                 context.enter_scope();
 
                 let mut catch_param = None;
@@ -2651,6 +2744,14 @@ fn try_catch(
                     ns.diagnostics.push(Diagnostic::error(id.loc, message));
                     return Err(());
                 }
+                if ns.target == Target::Solana {
+                    let message = format!(
+                        "catch '{}' is not supported on Solana, use a catch-all clause",
+                        id.name
+                    );
+                    ns.diagnostics.push(Diagnostic::error(id.loc, message));
+                    return Err(());
+                }
                 if let Some(annotation) = &param.annotation {
                     ns.diagnostics
                         .push(unexpected_parameter_annotation(annotation.loc))
@@ -481,9 +481,9 @@ contract runner {
     assert_eq!(ns.diagnostics.len(), 3);
     assert!(ns.diagnostics.contains_message("found contract 'runner'"));
     assert!(ns.diagnostics.contains_message("found contract 'aborting'"));
-    assert!(ns.diagnostics.contains_message("The try-catch statement is not \
-     supported on Solana. Please, go to \
-     https://solang.readthedocs.io/en/latest/language/statements.html#try-catch-statement for more information"));
+    assert!(ns
+        .diagnostics
+        .contains_message("catch 'Error' is not supported on Solana, use a catch-all clause"));
 }
 
 #[test]
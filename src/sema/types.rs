@@ -10,13 +10,14 @@ use super::{
     diagnostics::Diagnostics,
     ContractDefinition, SOLANA_SPARSE_ARRAY_SIZE,
 };
+use crate::sema::function_annotation::annotation_number;
 use crate::sema::namespace::ResolveTypeContext;
 use crate::Target;
 use base58::{FromBase58, FromBase58Error};
 use indexmap::IndexMap;
 use itertools::Itertools;
 use num_bigint::BigInt;
-use num_traits::{One, Zero};
+use num_traits::{One, ToPrimitive, Zero};
 use petgraph::algo::{all_simple_paths, tarjan_scc};
 use petgraph::stable_graph::IndexType;
 use petgraph::Directed;
@@ -620,8 +621,14 @@ fn contract_annotations(
     ns: &mut ast::Namespace,
 ) {
     let mut seen_program_id = None;
+    let mut seen_chain_extension = None;
 
     for note in annotations {
+        if ns.target.is_polkadot() && note.id.name == "chain_extension" {
+            chain_extension_annotation(contract_no, note, &mut seen_chain_extension, ns);
+            continue;
+        }
+
         if ns.target != Target::Solana || note.id.name != "program_id" {
             ns.diagnostics.push(Diagnostic::error(
                 note.loc,
@@ -695,6 +702,47 @@ fn contract_annotations(
     }
 }
 
+/// Resolve the extension id of a chain extension interface, declared with
+/// `@chain_extension(0x1234)`
+fn chain_extension_annotation(
+    contract_no: usize,
+    note: &pt::Annotation,
+    seen: &mut Option<pt::Loc>,
+    ns: &mut ast::Namespace,
+) {
+    if !ns.contracts[contract_no].is_interface() {
+        ns.diagnostics.push(Diagnostic::error(
+            note.loc,
+            format!(
+                "'@chain_extension' is only permitted on interfaces, not {}",
+                ns.contracts[contract_no].ty
+            ),
+        ));
+        return;
+    }
+
+    if let Some(prev_loc) = seen {
+        ns.diagnostics.push(Diagnostic::error_with_note(
+            note.loc,
+            "duplicate chain_extension annotation".into(),
+            *prev_loc,
+            "location of previous chain_extension annotation".into(),
+        ));
+        return;
+    }
+
+    *seen = Some(note.loc);
+
+    let value = note.value.as_ref().unwrap();
+    let mut diagnostics = Diagnostics::default();
+
+    if let Some(id) = annotation_number(value, &Type::Uint(16), &mut diagnostics, ns) {
+        ns.contracts[contract_no].chain_extension = id.to_u16();
+    }
+
+    ns.diagnostics.extend(diagnostics);
+}
+
 /// Resolve a parsed struct definition. The return value will be true if the entire
 /// definition is valid; however, whatever could be parsed will be added to the resolved
 /// contract, so that we can continue producing compiler messages for the remainder
@@ -809,6 +857,10 @@ fn event_decl(
     let mut fields: Vec<Parameter<Type>> = Vec::new();
     let mut indexed_fields = 0;
 
+    if let Some(name) = &def.name {
+        ns.check_soroban_symbol(name, "event");
+    }
+
     for field in &def.fields {
         let mut diagnostics = Diagnostics::default();
 
@@ -832,6 +884,16 @@ fn event_decl(
                 "mapping type is not permitted as event field".to_string(),
             ));
             ty = Type::Unresolved;
+        } else if ns.target == Target::Soroban && ty != Type::Unresolved && !ty.is_soroban_val(ns) {
+            // Soroban events are published as `Val`s
+            ns.diagnostics.push(Diagnostic::error(
+                field.loc,
+                format!(
+                    "event field of type '{}' is not supported on Soroban",
+                    ty.to_string(ns)
+                ),
+            ));
+            ty = Type::Unresolved;
         }
 
         let name = if let Some(name) = &field.name {
@@ -1954,6 +2016,23 @@ impl Type {
         matches!(self, Type::Address(_) | Type::Contract(_))
     }
 
+    /// Can a value of this type be converted to a Soroban `Val`
+    pub fn is_soroban_val(&self, ns: &Namespace) -> bool {
+        match self {
+            Type::Bool
+            | Type::Int(_)
+            | Type::Uint(_)
+            | Type::Enum(_)
+            | Type::Bytes(_)
+            | Type::String
+            | Type::DynamicBytes
+            | Type::Address(_)
+            | Type::Contract(_) => true,
+            Type::UserType(no) => ns.user_types[*no].ty.is_soroban_val(ns),
+            _ => false,
+        }
+    }
+
     /// Does the type contain any mapping type
     pub fn contains_mapping(&self, ns: &Namespace) -> bool {
         self.contains_mapping_internal(ns, &mut HashSet::new())
@@ -285,6 +285,15 @@ fn check_call_args(ns: &mut Namespace, call_args: &CallArgs, symtable: &mut Symt
     if let Some(program_id) = &call_args.program_id {
         used_variable(ns, program_id.as_ref(), symtable);
     }
+    if let Some(proof_size) = &call_args.proof_size {
+        used_variable(ns, proof_size.as_ref(), symtable);
+    }
+    if let Some(storage_deposit_limit) = &call_args.storage_deposit_limit {
+        used_variable(ns, storage_deposit_limit.as_ref(), symtable);
+    }
+    if let Some(code_hash) = &call_args.code_hash {
+        used_variable(ns, code_hash.as_ref(), symtable);
+    }
 }
 
 /// Marks as used variables that appear in an expression with right and left hand side.
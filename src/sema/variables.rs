@@ -3,8 +3,8 @@
 use super::{
     annotions_not_allowed,
     ast::{
-        Diagnostic, Expression, Function, Mapping, Namespace, Parameter, Statement, StructType,
-        Symbol, Type, Variable,
+        Diagnostic, Expression, Function, Mapping, Namespace, Parameter, Statement, StorageType,
+        StructType, Symbol, Type, Variable,
     },
     contracts::is_base,
     diagnostics::Diagnostics,
@@ -14,12 +14,14 @@ use super::{
     ContractDefinition,
 };
 use crate::sema::expression::resolve_expression::expression;
+use crate::sema::expression::soroban_storage_array_copy;
 use crate::sema::namespace::ResolveTypeContext;
+use crate::Target;
 use solang_parser::{
     doccomment::DocComment,
     pt::{self, CodeLocation, OptionalCodeLocation},
 };
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 pub struct DelayedResolveInitializer<'a> {
     var_no: usize,
@@ -37,13 +39,20 @@ pub fn contract_variables<'a>(
 
     for part in &def.parts {
         if let pt::ContractPart::VariableDefinition(ref s) = &part.part {
-            annotions_not_allowed(&part.annotations, "variable", ns);
+            // only Soroban has annotations for state variables
+            let annotations = if ns.target == Target::Soroban {
+                part.annotations.as_slice()
+            } else {
+                annotions_not_allowed(&part.annotations, "variable", ns);
+                &[]
+            };
 
             if let Some(delay) = variable_decl(
                 Some(def),
                 s,
                 file_no,
                 &part.doccomments,
+                annotations,
                 Some(def.contract_no),
                 ns,
                 &mut symtable,
@@ -61,6 +70,7 @@ pub fn variable_decl<'a>(
     def: &'a pt::VariableDefinition,
     file_no: usize,
     tags: &[DocComment],
+    annotations: &[&pt::Annotation],
     contract_no: Option<usize>,
     ns: &mut Namespace,
     symtable: &mut Symtable,
@@ -319,6 +329,34 @@ pub fn variable_decl<'a>(
         return None;
     }
 
+    if ns.target.ink_storage_keys() && contract_no.is_some() && !constant {
+        let mut diagnostics = Diagnostics::default();
+
+        check_ink_storage_keys(
+            &def.ty.loc(),
+            &ty,
+            true,
+            &mut HashSet::new(),
+            ns,
+            &mut diagnostics,
+        );
+
+        if diagnostics.any_errors() {
+            ns.diagnostics.extend(diagnostics);
+            return None;
+        }
+    }
+
+    if ns.target == Target::Soroban && contract_no.is_some() && !constant && storage_struct(&ty) {
+        ns.diagnostics.push(Diagnostic::error(
+            def.ty.loc(),
+            "structs in contract storage are not supported on Soroban".to_string(),
+        ));
+        return None;
+    }
+
+    let storage_type = variable_annotations(annotations, constant, ns);
+
     let mut diagnostics = Diagnostics::default();
 
     let initializer = if constant {
@@ -392,6 +430,7 @@ pub fn variable_decl<'a>(
         assigned: def.initializer.is_some(),
         initializer,
         read: matches!(visibility, pt::Visibility::Public(_)),
+        storage_type,
     };
 
     let var_no = if let Some(contract_no) = contract_no {
@@ -428,6 +467,8 @@ pub fn variable_decl<'a>(
     // for public variables in contracts, create an accessor function
     if success && matches!(visibility, pt::Visibility::Public(_)) {
         if let Some(contract_no) = contract_no {
+            ns.check_soroban_symbol(def.name.as_ref().unwrap(), "function");
+
             // The accessor function returns the value of the storage variable, constant or not.
             let mut expr = if constant {
                 Expression::ConstantVariable {
@@ -517,6 +558,125 @@ pub fn variable_decl<'a>(
     ret
 }
 
+/// Check that the mappings in a state variable can be stored under ink! storage keys
+fn check_ink_storage_keys(
+    loc: &pt::Loc,
+    ty: &Type,
+    fixed_slot: bool,
+    structs_visited: &mut HashSet<usize>,
+    ns: &Namespace,
+    diagnostics: &mut Diagnostics,
+) {
+    match ty {
+        Type::Mapping(Mapping { value, .. }) => {
+            if !fixed_slot {
+                diagnostics.push(Diagnostic::error(
+                    *loc,
+                    "mapping in a dynamic array is not supported with ink! storage keys"
+                        .to_string(),
+                ));
+            }
+
+            if matches!(
+                value.as_ref(),
+                Type::Mapping(..) | Type::Struct(_) | Type::Array(..)
+            ) {
+                diagnostics.push(Diagnostic::error(
+                    *loc,
+                    format!(
+                        "mapping with values of type '{}' is not supported with ink! storage keys",
+                        value.to_string(ns)
+                    ),
+                ));
+            }
+        }
+        Type::Struct(StructType::UserDefined(struct_no)) if structs_visited.insert(*struct_no) => {
+            for field in &ns.structs[*struct_no].fields {
+                check_ink_storage_keys(
+                    loc,
+                    &field.ty,
+                    fixed_slot,
+                    structs_visited,
+                    ns,
+                    diagnostics,
+                );
+            }
+        }
+        Type::Array(elem, dims) => {
+            let fixed_slot = fixed_slot && dims.iter().all(|dim| dim.array_length().is_some());
+
+            check_ink_storage_keys(loc, elem, fixed_slot, structs_visited, ns, diagnostics);
+        }
+        _ => (),
+    }
+}
+
+/// Structs cannot be stored on Soroban yet. Does the type of a state variable contain a struct,
+/// including in the elements of arrays and the values of mappings.
+fn storage_struct(ty: &Type) -> bool {
+    match ty {
+        Type::Struct(_) => true,
+        Type::Array(elem, _) => storage_struct(elem),
+        Type::Mapping(Mapping { value, .. }) => storage_struct(value),
+        _ => false,
+    }
+}
+
+/// Resolve the annotations on a state variable. On Soroban, the durability of the variable
+/// can be set with `@persistent`, `@temporary` or `@instance`.
+fn variable_annotations(
+    annotations: &[&pt::Annotation],
+    constant: bool,
+    ns: &mut Namespace,
+) -> Option<StorageType> {
+    let mut storage_type: Option<(pt::Loc, StorageType)> = None;
+
+    for note in annotations {
+        let ty = match note.id.name.as_str() {
+            "persistent" => StorageType::Persistent,
+            "temporary" => StorageType::Temporary,
+            "instance" => StorageType::Instance,
+            _ => {
+                ns.diagnostics.push(Diagnostic::error(
+                    note.loc,
+                    format!("unknown annotation '{}' on state variable", note.id.name),
+                ));
+                continue;
+            }
+        };
+
+        if let Some(value) = &note.value {
+            ns.diagnostics.push(Diagnostic::error(
+                value.loc(),
+                format!("'@{}' annotation does not take a value", note.id.name),
+            ));
+        } else if constant {
+            ns.diagnostics.push(Diagnostic::error(
+                note.loc,
+                format!(
+                    "'@{}' annotation not allowed on constant variable",
+                    note.id.name
+                ),
+            ));
+        } else if let Some((prev_loc, prev)) = &storage_type {
+            ns.diagnostics.push(Diagnostic::error_with_note(
+                note.loc,
+                format!(
+                    "variable already declared '@{}', cannot be '@{}' too",
+                    prev.as_str(),
+                    note.id.name
+                ),
+                *prev_loc,
+                format!("previous '@{}' annotation", prev.as_str()),
+            ));
+        } else {
+            storage_type = Some((note.loc, ty));
+        }
+    }
+
+    storage_type.map(|(_, ty)| ty)
+}
+
 /// For accessor functions, create the parameter list and the return expression
 fn collect_parameters(
     ty: &Type,
@@ -796,6 +956,10 @@ pub fn resolve_initializers(
         let var = &ns.contracts[*contract_no].variables[*var_no];
         let ty = var.ty.clone();
 
+        if soroban_storage_array_copy(&initializer.loc(), &ty, ns, &mut diagnostics).is_err() {
+            continue;
+        }
+
         let mut context = ExprContext {
             file_no,
             contract_no: Some(*contract_no),
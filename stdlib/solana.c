@@ -8,10 +8,6 @@
 extern uint64_t solang_dispatch(SolParameters *param);
 extern void __init_heap();
 
-// The address 'SysvarC1ock11111111111111111111111111111111' base58 decoded
-static const SolPubkey clock_address = {0x06, 0xa7, 0xd5, 0x17, 0x18, 0xc7, 0x74, 0xc9, 0x28, 0x56, 0x63,
-                                        0x98, 0x69, 0x1d, 0x5e, 0xb6, 0x8b, 0x5e, 0xb8, 0xa3, 0x9b, 0x4b,
-                                        0x6d, 0x5c, 0x73, 0x55, 0x5b, 0x21, 0x00, 0x00, 0x00, 0x00};
 // The address 'Sysvar1nstructions1111111111111111111111111' base58 decoded
 static const SolPubkey instructions_address = {0x06, 0xa7, 0xd5, 0x17, 0x18, 0x7b, 0xd1, 0x66, 0x35, 0xda, 0xd4,
                                                0x04, 0x55, 0xfd, 0xc2, 0xc0, 0xc1, 0x24, 0xc6, 0x8f, 0x21, 0x56,
@@ -33,18 +29,13 @@ uint64_t entrypoint(const uint8_t *input)
         return ret;
     }
 
-    params.ka_clock = NULL;
     params.ka_instructions = NULL;
 
     for (int account_no = 0; account_no < params.ka_num; account_no++)
     {
         const SolAccountInfo *acc = &params.ka[account_no];
 
-        if (SolPubkey_same(&clock_address, acc->key))
-        {
-            params.ka_clock = acc;
-        }
-        else if (SolPubkey_same(&instructions_address, acc->key))
+        if (SolPubkey_same(&instructions_address, acc->key))
         {
             params.ka_instructions = acc;
         }
@@ -173,26 +164,89 @@ uint64_t signature_verify(uint8_t *public_key, struct vector *message, struct ve
     return 1;
 }
 
-struct clock_layout
+uint64_t sol_get_rent_sysvar(void *rent);
+
+struct rent_layout
 {
-    uint64_t slot;
-    uint64_t epoch_start_timestamp;
-    uint64_t epoch;
-    uint64_t leader_schedule_epoch;
-    uint64_t unix_timestamp;
+    uint64_t lamports_per_byte_year;
+    // This is an f64
+    uint64_t exemption_threshold;
+    uint8_t burn_percent;
 };
 
-struct clock_layout *sol_clock(SolParameters *params)
+// Every account is charged rent for this many bytes on top of its data
+#define ACCOUNT_STORAGE_OVERHEAD 128
+
+// Multiply two 64 bit values into a 128 bit value, without a 128 bit multiply
+static __uint128_t mul64(uint64_t left, uint64_t right)
+{
+    uint64_t left_lo = left & 0xffffffff, left_hi = left >> 32;
+    uint64_t right_lo = right & 0xffffffff, right_hi = right >> 32;
+
+    uint64_t lo_lo = left_lo * right_lo;
+    uint64_t lo_hi = left_lo * right_hi;
+    uint64_t hi_lo = left_hi * right_lo;
+    uint64_t hi_hi = left_hi * right_hi;
+
+    uint64_t mid = (lo_lo >> 32) + (lo_hi & 0xffffffff) + (hi_lo & 0xffffffff);
+
+    uint64_t lo = (mid << 32) | (lo_lo & 0xffffffff);
+    uint64_t hi = hi_hi + (lo_hi >> 32) + (hi_lo >> 32) + (mid >> 32);
+
+    return ((__uint128_t)hi << 64) | lo;
+}
+
+// The lamports an account with space bytes of data needs to be rent exempt. This is
+// Rent::minimum_balance() from the Solana SDK; the f64 exemption threshold is decoded and
+// multiplied using integer arithmetic, and like a float to integer cast in Rust, the result
+// saturates. Unlike the SDK, it does not round balances above 2^53 lamports.
+uint64_t sol_rent_minimum_balance(uint64_t space)
 {
-    if (!params->ka_clock)
+    struct rent_layout rent;
+
+    if (sol_get_rent_sysvar(&rent))
     {
-        sol_log("clock account missing from transaction");
+        sol_log("failed to get rent sysvar");
         sol_panic();
     }
 
-    struct clock_layout *clock_data = (struct clock_layout *)params->ka_clock->data;
+    uint64_t lamports = (ACCOUNT_STORAGE_OVERHEAD + space) * rent.lamports_per_byte_year;
+
+    uint64_t exponent = (rent.exemption_threshold >> 52) & 0x7ff;
+
+    // zero or subnormal threshold
+    if (exponent == 0 || lamports == 0)
+    {
+        return 0;
+    }
+
+    // the threshold is mantissa * 2^(exponent - 1075)
+    uint64_t mantissa = (rent.exemption_threshold & 0xfffffffffffffull) | (1ull << 52);
+
+    __uint128_t balance = mul64(lamports, mantissa);
+
+    if (exponent >= 1075)
+    {
+        uint64_t shift = exponent - 1075;
+
+        if (shift >= 64 || balance > (UINT64_MAX >> shift))
+        {
+            return UINT64_MAX;
+        }
+
+        return (uint64_t)balance << shift;
+    }
+
+    uint64_t shift = 1075 - exponent;
+
+    if (shift >= 128)
+    {
+        return 0;
+    }
+
+    balance >>= shift;
 
-    return clock_data;
+    return balance > UINT64_MAX ? UINT64_MAX : (uint64_t)balance;
 }
 
 struct account_data_header
@@ -340,4 +340,19 @@ contract nonpayableConstructor {
 	
 	constructor () {}
 	function foo() public pure {}
-}
\ No newline at end of file
+}
+contract nonReentrant {
+	// BEGIN-CHECK: Contract: nonReentrant
+
+	// CHECK: # function polkadot_call_dispatch public:false selector: nonpayable:false
+
+	// CHECK: block3: # func_0_dispatch
+	// CHECK: 	branchcond (unsigned more (builtin ReentranceCount ()) > uint32 0), block4, block5
+	// CHECK: block4: # func_0_reentered
+	// CHECK: 	assert-failure
+	// CHECK: block5: # func_0_not_reentered
+	// CHECK: 	 = call nonReentrant::nonReentrant::function::foo 
+
+	@nonReentrant
+	function foo() public payable {}
+}
@@ -0,0 +1,45 @@
+// RUN: --target polkadot --emit cfg
+
+contract MappingStorage {
+    mapping(address => uint64) balances;
+    mapping(uint64 => bool) flags;
+
+    // BEGIN-CHECK: MappingStorage::MappingStorage::function::set__address_uint64
+    function set(address owner, uint64 amount) public {
+        // CHECK: branchcond ((arg #1) == uint64 0), block1, block2
+        // CHECK: block1: # clear_entry
+        // CHECK: clear storage slot((keccak256 uint256 0, (arg #0))) ty:uint64
+        // CHECK: block2: # set_entry
+        // CHECK: store storage slot((keccak256 uint256 0, (arg #0))) ty:uint64 = (arg #1)
+        balances[owner] = amount;
+    }
+
+    // BEGIN-CHECK: MappingStorage::MappingStorage::function::withdraw__address
+    function withdraw(address owner) public returns (uint64 amount) {
+        // CHECK: %temp.10 = take storage slot((keccak256 uint256 0, (arg #0))) ty:uint64
+        // NOT-CHECK: clear storage
+        amount = balances[owner];
+        delete balances[owner];
+    }
+
+    // BEGIN-CHECK: MappingStorage::MappingStorage::function::has_balance__address
+    function has_balance(address owner) public view returns (bool) {
+        // CHECK: %temp.11 = contains storage slot((keccak256 uint256 0, (arg #0)))
+        // NOT-CHECK: load storage
+        return balances[owner] != 0;
+    }
+
+    // BEGIN-CHECK: MappingStorage::MappingStorage::function::flag__uint64
+    function flag(uint64 key) public view returns (bool) {
+        // CHECK: %temp.12 = contains storage slot((keccak256 uint256 1, (arg #0)))
+        // NOT-CHECK: load storage
+        return flags[key];
+    }
+
+    // BEGIN-CHECK: MappingStorage::MappingStorage::function::enable__uint64
+    function enable(uint64 key) public {
+        // CHECK: store storage slot((keccak256 uint256 1, (arg #0))) ty:bool = true
+        // NOT-CHECK: clear storage
+        flags[key] = true;
+    }
+}
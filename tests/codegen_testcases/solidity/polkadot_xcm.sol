@@ -0,0 +1,23 @@
+// RUN: --target polkadot --emit cfg
+
+contract Xcm {
+    // BEGIN-CHECK: Xcm::Xcm::function::execute__bytes
+    function execute(bytes message) public {
+        // CHECK: ty:uint32 %xcm_ret.temp.4 = (builtin XcmExecute ((arg #0)))
+        // CHECK: switch %xcm_ret.temp.4:
+        // CHECK: case uint32 0: goto block #1
+        // CHECK: block3: # ret_no_data
+        // CHECK: assert-failure
+        xcm.execute(message);
+    }
+
+    // BEGIN-CHECK: Xcm::Xcm::function::send__bytes_bytes
+    function send(bytes dest, bytes message) public returns (bytes32) {
+        // CHECK: ty:bytes %xcm_hash.temp.5 = (alloc bytes len uint32 32)
+        // CHECK: ty:uint32 %xcm_ret.temp.6 = (builtin XcmSend ((arg #0), (arg #1), %xcm_hash.temp.5))
+        // CHECK: switch %xcm_ret.temp.6:
+        // CHECK: block1: # ret_success
+        // CHECK: return (builtin ReadFromBuffer (%xcm_hash.temp.5, uint32 0))
+        return xcm.send(dest, message);
+    }
+}
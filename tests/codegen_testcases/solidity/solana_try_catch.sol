@@ -0,0 +1,23 @@
+// RUN: --target solana --emit cfg
+
+contract C {
+    // BEGIN-CHECK: C::C::function::call_child__address
+    function call_child(address id) external returns (uint64) {
+        // CHECK: external call::regular
+        // CHECK: == uint64 0), block4, block5
+        try Child.get{program_id: id}() returns (uint64 v) {
+            return v;
+        } catch (bytes memory err) {
+            // CHECK: block5: # ret_bubble
+            // CHECK: = (external call return data)
+            print("failed {}".format(err));
+            return 0;
+        }
+    }
+}
+
+contract Child {
+    function get() external pure returns (uint64) {
+        return 1;
+    }
+}
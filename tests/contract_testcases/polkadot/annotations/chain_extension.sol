@@ -0,0 +1,69 @@
+@chain_extension(0x0001)
+interface Rand {
+    @function_id(1)
+    function fetch(bytes32 subject) external returns (bytes32);
+
+    @function_id(2)
+    function add(uint32 a, uint32 b) external returns (uint32);
+}
+
+@chain_extension(2)
+interface Missing {
+    function foo() external;
+
+    @function_id(3)
+    function bar() external;
+
+    @function_id(3)
+    function baz() external;
+
+    @function_id(0x10000)
+    function big() external;
+
+    @function_id
+    function none() external;
+}
+
+@chain_extension(3)
+@chain_extension(4)
+interface Twice {}
+
+@chain_extension(1)
+contract C {
+    @function_id(4)
+    function f() public {}
+
+    function g() public returns (bytes32) {
+        return Rand.fetch(hex"01");
+    }
+
+    function h() public returns (uint32) {
+        return Rand.add({a: 1, b: 2});
+    }
+
+    function i() public returns (uint32) {
+        return Rand.add{value: 1}(1, 2);
+    }
+}
+
+@chain_extension(0x10000)
+interface Big {}
+
+@chain_extension
+interface NoVal {}
+
+// ---- Expect: diagnostics ----
+// error: 12:5-28: function 'foo' of chain extension 'Missing' requires a '@function_id' annotation
+// error: 18:5-28: function 'baz' has the same function id 3 as function 'bar'
+// 	note 15:5-28: definition of function 'bar'
+// error: 20:18-25: value 65536 does not fit into type uint16.
+// error: 21:5-28: function 'big' of chain extension 'Missing' requires a '@function_id' annotation
+// error: 23:5-17: '@function_id' annotation requires a value
+// error: 24:5-29: function 'none' of chain extension 'Missing' requires a '@function_id' annotation
+// error: 28:1-20: duplicate chain_extension annotation
+// 	note 27:1-20: location of previous chain_extension annotation
+// error: 31:1-20: '@chain_extension' is only permitted on interfaces, not contract
+// error: 33:5-20: '@function_id' is only permitted on functions of a chain extension
+// error: 45:24-34: call arguments not allowed on chain extension calls
+// error: 49:18-25: value 65536 does not fit into type uint16.
+// error: 52:1-17: '@chain_extension' annotation requires a value
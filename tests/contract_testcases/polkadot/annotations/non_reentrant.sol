@@ -0,0 +1,41 @@
+contract c {
+    @nonReentrant
+    function f() public {}
+
+    @nonReentrant
+    @nonReentrant
+    function g() external {}
+
+    @nonReentrant(1)
+    function h() public {}
+
+    @nonReentrant
+    function i() internal {}
+
+    @nonReentrant
+    constructor() {}
+
+    @nonReentrant
+    modifier m() { _; }
+
+    @nonReentrant
+    receive() external payable {}
+
+    @reentrant
+    function j() public {}
+}
+
+interface I {
+    @nonReentrant
+    function f() external;
+}
+
+// ---- Expect: diagnostics ----
+// error: 6:5-18: duplicate @nonReentrant annotation for function
+// 	note 5:5-18: previous @nonReentrant
+// error: 9:19-20: '@nonReentrant' annotation does not take a value
+// error: 12:5-18: '@nonReentrant' only permitted on 'public' or 'external' function, not 'internal'
+// error: 15:5-18: '@nonReentrant' not permitted on constructor
+// error: 18:5-18: '@nonReentrant' not permitted on modifier
+// error: 24:5-15: '@reentrant' annotation requires a value
+// error: 29:5-18: annotation '@nonReentrant' not allowed on function with no body
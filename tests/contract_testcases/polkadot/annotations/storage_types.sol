@@ -0,0 +1,11 @@
+contract c {
+    @persistent
+    uint64 a;
+
+    @selector
+    function f() public {}
+}
+
+// ---- Expect: diagnostics ----
+// error: 2:5-16: annotations not allowed on variable
+// error: 5:5-14: '@selector' annotation requires a value
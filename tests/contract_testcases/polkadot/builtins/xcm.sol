@@ -0,0 +1,12 @@
+contract Xcm {
+    function execute(bytes message) public view {
+        xcm.execute(message);
+    }
+
+    function send(bytes dest, bytes message) public returns (bytes32) {
+        return xcm.send(dest, message);
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 3:9-29: function declared 'view' but this expression writes to state
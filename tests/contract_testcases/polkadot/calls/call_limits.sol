@@ -0,0 +1,30 @@
+contract Caller {
+    function both(Callee callee) public {
+        callee.ping{gas: 1, refTime: 2}();
+    }
+
+    function delegate(Callee callee) public {
+        (bool ok, ) = address(callee).delegatecall{proofSize: 3, storageDepositLimit: 4}("");
+        require(ok);
+    }
+
+    function negative(Callee callee) public {
+        callee.ping{proofSize: -1}();
+    }
+
+    function deposit(Callee callee) public {
+        callee.ping{storageDepositLimit: true}();
+    }
+}
+
+contract Callee {
+    function ping() public {}
+}
+
+// ---- Expect: diagnostics ----
+// error: 3:29-39: 'refTime' cannot be specified together with 'gas'
+// 	note 3:21-27: location of 'gas'
+// warning: 7:23-93: 'proofSize' specified on 'delegatecall' will be ignored
+// warning: 7:23-93: 'storageDepositLimit' specified on 'delegatecall' will be ignored
+// error: 12:32-34: negative value -1 does not fit into type uint64. Cannot implicitly convert signed literal to unsigned type.
+// error: 16:42-46: conversion from bool to uint128 not possible
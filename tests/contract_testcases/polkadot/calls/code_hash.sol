@@ -0,0 +1,34 @@
+contract Factory {
+    function copy(bytes32 hash) public returns (Factory) {
+        return new Factory{codeHash: hash}();
+    }
+
+    function child(bytes32 hash) public returns (Child) {
+        return new Child{codeHash: hash, salt: hash}({v: 1});
+    }
+
+    function external_call(Child c, bytes32 hash) public {
+        c.make{codeHash: hash}();
+    }
+
+    function wrong_type(uint256 hash) public returns (Child) {
+        return new Child{codeHash: hash}(1);
+    }
+
+    function without_hash() public returns (Factory) {
+        return new Factory();
+    }
+}
+
+contract Child {
+    constructor(uint32 v) {}
+
+    function make() public returns (Factory) {
+        return new Factory();
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 11:16-30: 'codeHash' not valid for external calls
+// error: 15:36-40: implicit conversion to bytes32 from uint256 not allowed
+// error: 19:16-29: new cannot construct current contract 'Factory'
@@ -0,0 +1,21 @@
+contract Caller {
+    function ref_time(address callee) external {
+        (bool ok, ) = callee.call{refTime: 1}("");
+        require(ok);
+    }
+
+    function proof_size(address callee) external {
+        (bool ok, ) = callee.call{proofSize: 2}("");
+        require(ok);
+    }
+
+    function deposit(address callee) external {
+        (bool ok, ) = callee.call{storageDepositLimit: 3}("");
+        require(ok);
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 3:35-45: 'refTime' is only permitted for external calls or constructors on polkadot
+// error: 8:35-47: 'proofSize' is only permitted for external calls or constructors on polkadot
+// error: 13:35-57: 'storageDepositLimit' is only permitted for external calls or constructors on polkadot
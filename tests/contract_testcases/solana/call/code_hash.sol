@@ -0,0 +1,11 @@
+@program_id("Foo5mMfYo5RhRcWa4NZ2bwFn4Kdhe8rNK5jchxsKrivA")
+contract Child {}
+
+contract Factory {
+    function child(bytes32 hash) external {
+        Child.new{codeHash: hash}();
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 6:19-33: 'codeHash' is only permitted for constructors on polkadot
@@ -0,0 +1,37 @@
+contract C {
+	function call_child(address id) external returns (uint64) {
+		try Child.get{program_id: id}() returns (uint64 v) {
+			return v;
+		} catch (bytes memory err) {
+			print("failed {}".format(err));
+			return 0;
+		}
+	}
+
+	function create(address id) external {
+		try Child.new{program_id: id}() {
+			print("ok");
+		} catch {
+			print("fail");
+		}
+	}
+
+	function catch_error(address id) external {
+		try Child.get{program_id: id}() returns (uint64 v) {
+			print("ok");
+		} catch Error(string reason) {
+			print(reason);
+		}
+	}
+}
+
+contract Child {
+	function get() external pure returns (uint64) {
+		return 1;
+	}
+}
+
+// ---- Expect: diagnostics ----
+// warning: 5:5-8:4: catch clause is never executed on Solana, since a failed call aborts the transaction
+// warning: 14:5-16:4: catch clause is never executed on Solana, since a failed call aborts the transaction
+// error: 22:11-16: catch 'Error' is not supported on Solana, use a catch-all clause
@@ -0,0 +1,14 @@
+contract Metered {
+	function consumed() public view returns (uint64) {
+		uint64 before = remainingComputeUnits();
+		logComputeUnits();
+		return before - remainingComputeUnits();
+	}
+
+	function notPure() public pure returns (uint64) {
+		return remainingComputeUnits();
+	}
+}
+
+// ---- Expect: diagnostics ----
+// error: 9:10-31: function declared 'pure' but this expression reads from state
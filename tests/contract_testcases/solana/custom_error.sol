@@ -0,0 +1,29 @@
+contract C {
+	error Unauthorized(address caller);
+	error InsufficientBalance(uint64 available, uint64 required);
+
+	function withdraw(uint64 amount, uint64 balance) public pure {
+		require(amount <= balance, InsufficientBalance(balance, amount));
+		require(amount != 0, InsufficientBalance({available: balance, required: 1}));
+
+		if (amount > 100) {
+			revert InsufficientBalance(balance, amount);
+		}
+	}
+
+	function check(address a) public view {
+		require(a == address(this), Unauthorized(a, 1));
+		require(a == address(this), Unauthorized({caller: a, amount: 1}));
+	}
+
+	function check2(address a) public pure {
+		require(a, Unauthorized(a));
+	}
+}
+
+// ---- Expect: diagnostics ----
+// error: 15:31-43: error 'Unauthorized' has 1 fields, 2 provided
+// 	note 2:8-20: definition of 'Unauthorized'
+// error: 16:56-62: error 'Unauthorized' has no field called 'amount'
+// 	note 2:8-20: definition of 'Unauthorized'
+// error: 20:11-12: conversion from address to bool not possible
@@ -0,0 +1,11 @@
+import { lock_delegate_dependency } from "polkadot";
+
+contract Proxy {
+    function lock(uint256 code_hash) public {
+        lock_delegate_dependency(code_hash);
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 1:42-52: 'polkadot' builtins are not available on Solana
+// error: 5:9-33: unknown function or type 'lock_delegate_dependency'
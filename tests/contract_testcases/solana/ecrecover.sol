@@ -0,0 +1,14 @@
+contract signed {
+	function recoverSignerFromSignature(uint8 v, bytes32 r, bytes32 s, bytes32 hash) pure external returns (bytes20) {
+		bytes20 signer = ecrecover(hash, v, r, s);
+		require(signer != bytes20(0), "ECDSA: invalid signature");
+		return signer;
+	}
+
+	function wrongType(uint8 v, bytes32 r, bytes32 s, bytes32 hash) pure external {
+		address signer = ecrecover(hash, v, r, s);
+	}
+}
+
+// ---- Expect: diagnostics ----
+// error: 9:20-29: implicit conversion to address from bytes20 not allowed
@@ -0,0 +1,16 @@
+contract Sysvars {
+	function epoch() public view returns (uint64, uint64) {
+		return (block.epoch, block.slots_per_epoch);
+	}
+
+	function rent(uint64 space) public view returns (uint64, bool) {
+		return (minimumBalance(space), isRentExempt(tx.accounts.dataAccount.lamports, space));
+	}
+
+	function notPure() public pure returns (uint64) {
+		return minimumBalance(0);
+	}
+}
+
+// ---- Expect: diagnostics ----
+// error: 11:10-24: function declared 'pure' but this expression reads from state
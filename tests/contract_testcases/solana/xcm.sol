@@ -0,0 +1,8 @@
+contract Xcm {
+    function execute(bytes message) public {
+        xcm.execute(message);
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 3:9-12: 'xcm' not found
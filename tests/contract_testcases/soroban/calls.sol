@@ -0,0 +1,46 @@
+contract creator {
+    child c;
+
+    constructor(uint64 a) {
+        c = new child{value: 1}();
+    }
+
+    constructor(int64 b) {}
+
+    function f() public {
+        c = new child{gas: 100}();
+    }
+
+    function g(address a) public returns (bool) {
+        (bool ok, ) = a.delegatecall("");
+        return ok;
+    }
+
+    function h() public {
+        c.pair();
+    }
+
+    function i() public view returns (address) {
+        return msg.sender;
+    }
+
+    function j() public view returns (uint128) {
+        return address(this).balance;
+    }
+}
+
+contract child {
+    function pair() public pure returns (uint64, uint64) {
+        return (1, 2);
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 5:23-31: 'value' not permitted for external calls or constructors on Soroban. Transfer the native token explicitly instead
+// error: 8:5-26: constructor already defined
+// 	note 4:5-27: location of previous definition
+// error: 11:23-31: 'gas' not permitted for external calls or constructors on Soroban
+// error: 15:23-41: 'delegatecall' is not supported on Soroban
+// error: 20:9-17: function 'pair' returns more than one value, which is not supported for external calls on Soroban
+// error: 24:16-19: builtin 'msg.sender' does not exist
+// error: 28:16-29: balance is not available on Soroban. Call 'balance' on the token contract instead
@@ -0,0 +1,18 @@
+contract c {
+    function verify(address key, bytes message, bytes signature) public pure returns (bool) {
+        return signatureVerify(key, message, signature);
+    }
+
+    function recover(bytes32 digest, uint8 v, bytes32 r, bytes32 s) public pure returns (address) {
+        return ecrecover(digest, v, r, s);
+    }
+
+    function hash(bytes data) public pure returns (bytes32) {
+        return blake2_256(data);
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 3:32-35: implicit conversion to bytes32 from address not allowed
+// error: 7:16-25: implicit conversion to address from bytes20 not allowed
+// error: 11:16-26: unknown function or type 'blake2_256'
@@ -0,0 +1,18 @@
+contract auth {
+    struct S {
+        uint64 f;
+    }
+
+    function wrong_args(address a) public {
+        a.requireAuth(1);
+    }
+
+    function unsupported(address a) public {
+        S memory s = S(1);
+        a.requireAuthForArgs(s.f, s);
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 7:11-22: builtin function 'requireAuth' expects 0 arguments, 1 provided
+// error: 12:35-36: 'struct auth.S' cannot be passed to 'requireAuthForArgs'
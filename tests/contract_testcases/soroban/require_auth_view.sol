@@ -0,0 +1,13 @@
+contract auth {
+    function view_auth(address a) public view {
+        a.requireAuth();
+    }
+
+    function view_auth_args(address a, uint64 x) public view {
+        a.requireAuthForArgs(x);
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 3:11-22: function declared 'view' but this expression writes to state
+// error: 7:11-29: function declared 'view' but this expression writes to state
@@ -0,0 +1,54 @@
+contract c {
+    uint64[] values;
+    uint64[3] fixed;
+    uint64[2] initialized = [1, 2];
+    uint64[][] nested;
+
+    function load() public view returns (uint64) {
+        uint64[] memory m = values;
+        return m[0];
+    }
+
+    function store(uint32 a) public {
+        uint64[] memory m = new uint64[](a);
+        values = m;
+    }
+
+    function store_fixed() public {
+        fixed = [1, 2, 3];
+    }
+
+    function store_element(uint32 a) public {
+        uint64[] memory m = new uint64[](a);
+        nested[0] = m;
+    }
+
+    function push(uint32 a) public {
+        uint64[] memory m = new uint64[](a);
+        nested.push(m);
+    }
+
+    function pop() public returns (uint64) {
+        uint64[] memory m = nested.pop();
+        return m[0];
+    }
+
+    function elements() public returns (uint64) {
+        values.push(1);
+        values[0] = 2;
+        fixed[1] = values[0];
+        nested.push().push(3);
+        nested.pop();
+        delete fixed;
+        return uint64(values.length) + fixed[1] + nested[0][0];
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 4:29-35: copying 'uint64[2]' to or from storage is not supported on Soroban, access its elements instead
+// error: 8:29-35: copying 'uint64[]' to or from storage is not supported on Soroban, access its elements instead
+// error: 14:9-15: copying 'uint64[]' to or from storage is not supported on Soroban, access its elements instead
+// error: 18:9-14: copying 'uint64[3]' to or from storage is not supported on Soroban, access its elements instead
+// error: 23:9-22: copying 'uint64[]' to or from storage is not supported on Soroban, access its elements instead
+// error: 28:21-22: copying 'uint64[]' to or from storage is not supported on Soroban, access its elements instead
+// error: 32:36-39: copying 'uint64[]' to or from storage is not supported on Soroban, access its elements instead
@@ -0,0 +1,39 @@
+contract c {
+    @persistent
+    uint64[] a;
+    uint64[] d;
+    mapping(uint64 => uint64) m;
+    @temporary
+    mapping(uint64 => uint64) n;
+
+    function f() public {
+        uint64[] storage b = a;
+    }
+
+    function g(uint64[] storage b) internal {
+        b.push(1);
+    }
+
+    function h() internal returns (uint64[] storage) {
+        return a;
+    }
+
+    function i(bool cond) public {
+        (cond ? a : d).push(1);
+    }
+
+    function j(bool cond) public view returns (uint64) {
+        return (cond ? m : n)[1];
+    }
+
+    function k(bool cond) public view returns (uint64) {
+        return cond ? a[0] : d[0];
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 10:18-25: storage references are not supported on Soroban
+// error: 13:25-32: storage references are not supported on Soroban
+// error: 17:45-52: storage references are not supported on Soroban
+// error: 22:17-18: copying 'uint64[]' to or from storage is not supported on Soroban, access its elements instead
+// error: 26:17-29: storage references are not supported on Soroban
@@ -0,0 +1,27 @@
+contract c {
+    struct S {
+        uint64 a;
+        bool b;
+    }
+
+    S s1;
+
+    mapping(uint64 => S) s2;
+
+    S[] s3;
+
+    mapping(address => S[2]) s4;
+
+    uint64 ok;
+
+    function f() public view returns (uint64) {
+        S memory s = S(ok, false);
+        return s.a;
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 7:5-6: structs in contract storage are not supported on Soroban
+// error: 9:5-25: structs in contract storage are not supported on Soroban
+// error: 11:5-8: structs in contract storage are not supported on Soroban
+// error: 13:5-29: structs in contract storage are not supported on Soroban
@@ -0,0 +1,44 @@
+contract c {
+    @persistent
+    @temporary
+    uint64 a;
+
+    @instance(1)
+    uint64 b;
+
+    @persistent
+    uint64 constant C = 1;
+
+    @volatile
+    uint64 d;
+
+    @temporary
+    mapping(uint64 => uint64) e;
+
+    function f() public {
+        e.extendTtl(1, 2);
+    }
+
+    function g() public {
+        a.extendTtl(1);
+    }
+
+    function h() public {
+        a.extendTtl{value: 1}(1, 2);
+    }
+
+    function i() public {
+        e[1].extendTtl(1, 2);
+        a.extendTtl(100, 1000);
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 3:5-15: variable already declared '@persistent', cannot be '@temporary' too
+// 	note 2:5-16: previous '@persistent' annotation
+// error: 6:15-16: '@instance' annotation does not take a value
+// error: 9:5-16: '@persistent' annotation not allowed on constant variable
+// error: 12:5-14: unknown annotation 'volatile' on state variable
+// error: 19:11-20: cannot extend the TTL of 'mapping(uint64 => uint64)', extend the TTL of its elements instead
+// error: 23:11-20: method 'extendTtl()' expects 2 arguments, 1 provided
+// error: 27:20-30: call arguments not allowed on 'extendTtl'
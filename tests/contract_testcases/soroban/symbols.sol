@@ -0,0 +1,29 @@
+contract c {
+    uint64 public count$;
+    uint64 private total$;
+
+    event Transfer$(uint64 amount);
+
+    function get$() public view returns (uint64) {
+        return total$;
+    }
+
+    function set$(uint64 v) external {
+        total$ = v;
+        emit Transfer$(v);
+    }
+
+    function helper$() internal pure returns (uint64) {
+        return 1;
+    }
+
+    function Get_2() public pure returns (uint64) {
+        return helper$();
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 2:19-25: function name 'count$' is not a valid Soroban symbol, which may only contain letters, digits and '_'
+// error: 5:11-20: event name 'Transfer$' is not a valid Soroban symbol, which may only contain letters, digits and '_'
+// error: 7:14-18: function name 'get$' is not a valid Soroban symbol, which may only contain letters, digits and '_'
+// error: 11:14-18: function name 'set$' is not a valid Soroban symbol, which may only contain letters, digits and '_'
@@ -0,0 +1,32 @@
+contract shapes {
+    struct Point {
+        int64 x;
+        int64 y;
+    }
+
+    event Moved(Point to);
+
+    function move(Point memory p) public pure returns (int64) {
+        return p.x;
+    }
+
+    function origin() public pure returns (Point memory) {
+        return Point(0, 0);
+    }
+
+    function sum(uint64[] memory list) external pure returns (uint64 total) {
+        for (uint i = 0; i < list.length; i++) {
+            total += list[i];
+        }
+    }
+
+    function first(Point[2] memory points) internal pure returns (Point memory) {
+        return points[0];
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 7:17-25: event field of type 'struct shapes.Point' is not supported on Soroban
+// error: 9:19-24: parameter of type 'struct shapes.Point' not supported in public or external functions on Soroban
+// error: 13:44-49: return type 'struct shapes.Point' not supported in public or external functions on Soroban
+// error: 17:18-26: parameter of type 'uint64[]' not supported in public or external functions on Soroban
@@ -93,3 +93,8 @@ fn solana_general() {
 fn solana_specific() {
     assert_compile("docs/examples/solana", Target::Solana);
 }
+
+#[test]
+fn soroban_specific() {
+    assert_compile("docs/examples/soroban", Target::Soroban);
+}
@@ -423,6 +423,8 @@ fn test_stringfy_external_call_insn() {
                 accounts: solang::sema::ast::ExternalCallAccounts::AbsentArgument,
                 seeds: None,
                 gas: identifier(7),
+                proof_size: None,
+                storage_deposit_limit: None,
                 callty: CallTy::Regular,
                 contract_function_no: None,
                 flags: None,
@@ -110,6 +110,8 @@ impl WasmCode {
 pub struct Contract {
     code: WasmCode,
     storage: HashMap<StorageKey, Vec<u8>>,
+    /// Code hashes locked with `lock_delegate_dependency`.
+    delegate_dependencies: Vec<Hash>,
 }
 
 impl From<WasmCode> for Contract {
@@ -117,6 +119,7 @@ impl From<WasmCode> for Contract {
         Self {
             code,
             storage: HashMap::new(),
+            delegate_dependencies: Vec::new(),
         }
     }
 }
@@ -204,6 +207,23 @@ pub struct Event {
     pub topics: Vec<Hash>,
 }
 
+/// An XCM message executed or sent by a contract.
+#[derive(Clone)]
+pub struct XcmMessage {
+    /// The destination of a sent message, or `None` if the message was executed.
+    pub dest: Option<Vec<u8>>,
+    pub message: Vec<u8>,
+}
+
+/// The weight and storage deposit limits of a call or instantiation made by a contract.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallLimits {
+    pub ref_time: u64,
+    pub proof_size: u64,
+    /// `None` if there is no limit.
+    pub storage_deposit: Option<u128>,
+}
+
 /// The runtime provides the state of the mocked blockchain node during contract execution.
 #[derive(Default, Clone)]
 struct Runtime {
@@ -229,6 +249,12 @@ struct Runtime {
     events: Vec<Event>,
     /// The set of called events, needed for reentrancy protection.
     called_accounts: HashSet<usize>,
+    /// The accounts of all contracts on the call stack, including the one being executed.
+    call_stack: Vec<usize>,
+    /// All XCM messages executed or sent during contract execution.
+    xcm_messages: Vec<XcmMessage>,
+    /// The limits of all calls and instantiations made during contract execution.
+    call_limits: Vec<CallLimits>,
 }
 
 impl Runtime {
@@ -259,6 +285,7 @@ impl Runtime {
         runtime.input = Some(input);
         runtime.output = Default::default();
         runtime.called_accounts.insert(self.caller_account);
+        runtime.call_stack.push(callee);
         runtime
     }
 
@@ -269,6 +296,8 @@ impl Runtime {
     fn accept_state(&mut self, callee_state: Self, transferred_value: u128) {
         self.debug_buffer = callee_state.debug_buffer;
         self.events = callee_state.events;
+        self.xcm_messages = callee_state.xcm_messages;
+        self.call_limits = callee_state.call_limits;
         self.accounts = callee_state.accounts;
         self.accounts[self.caller_account].value -= transferred_value;
     }
@@ -342,6 +371,17 @@ fn read_buf(mem: &[u8], ptr: u32, len: u32) -> Vec<u8> {
     mem[ptr as usize..(ptr + len) as usize].to_vec()
 }
 
+/// Storage keys which are not 32 bytes long are hashed, so that all keys fit in the mock storage.
+/// pallet-contracts hashes every storage key.
+fn read_storage_key(mem: &[u8], ptr: u32, len: u32) -> StorageKey {
+    let key = read_buf(mem, ptr, len);
+
+    match StorageKey::try_from(key.as_slice()) {
+        Ok(key) => key,
+        Err(_) => blake2b(32, &[], &key).as_bytes().try_into().unwrap(),
+    }
+}
+
 fn read_value(mem: &[u8], ptr: u32) -> u128 {
     u128::from_le_bytes(read_buf(mem, ptr, 16).try_into().unwrap())
 }
@@ -354,6 +394,14 @@ fn read_hash(mem: &[u8], ptr: u32) -> Hash {
     Hash::try_from(&mem[ptr as usize..(ptr + 32) as usize]).unwrap()
 }
 
+fn read_call_limits(mem: &[u8], ref_time: u64, proof_size: u64, deposit_ptr: u32) -> CallLimits {
+    CallLimits {
+        ref_time,
+        proof_size,
+        storage_deposit: (deposit_ptr != u32::MAX).then(|| read_value(mem, deposit_ptr)),
+    }
+}
+
 /// Host functions mock the original implementation, refer to the [pallet docs][1] for more information.
 ///
 /// [1]: https://docs.rs/pallet-contracts/latest/pallet_contracts/api_doc/index.html
@@ -408,8 +456,7 @@ impl Runtime {
         out_ptr: u32,
         out_len_ptr: u32,
     ) -> Result<u32, Trap> {
-        let key = StorageKey::try_from(read_buf(mem, key_ptr, key_len))
-            .expect("storage key size must be 32 bytes");
+        let key = read_storage_key(mem, key_ptr, key_len);
         let value = match vm.contract().storage.get(&key) {
             Some(value) => value,
             _ => return Ok(3), // In pallet-contracts, ReturnCode::KeyNotFound == 3
@@ -429,8 +476,7 @@ impl Runtime {
         value_ptr: u32,
         value_len: u32,
     ) -> Result<u32, Trap> {
-        let key = StorageKey::try_from(read_buf(mem, key_ptr, key_len))
-            .expect("storage key size must be 32 bytes");
+        let key = read_storage_key(mem, key_ptr, key_len);
         let value = mem[value_ptr as usize..(value_ptr + value_len) as usize].to_vec();
         println!("set_storage: {}={}", hex::encode(key), hex::encode(&value));
 
@@ -442,8 +488,7 @@ impl Runtime {
 
     #[seal(1)]
     fn clear_storage(key_ptr: u32, key_len: u32) -> Result<u32, Trap> {
-        let key = StorageKey::try_from(read_buf(mem, key_ptr, key_len))
-            .expect("storage key size must be 32 bytes");
+        let key = read_storage_key(mem, key_ptr, key_len);
         println!("clear_storage: {}", hex::encode(key));
 
         match vm.contract().storage.remove(&key) {
@@ -452,6 +497,37 @@ impl Runtime {
         }
     }
 
+    #[seal(0)]
+    fn take_storage(
+        key_ptr: u32,
+        key_len: u32,
+        out_ptr: u32,
+        out_len_ptr: u32,
+    ) -> Result<u32, Trap> {
+        let key = read_storage_key(mem, key_ptr, key_len);
+        let value = match vm.contract().storage.remove(&key) {
+            Some(value) => value,
+            _ => return Ok(3), // In pallet-contracts, ReturnCode::KeyNotFound == 3
+        };
+        println!("take_storage: {}={}", hex::encode(key), hex::encode(&value));
+
+        write_buf(mem, out_ptr, &value);
+        write_buf(mem, out_len_ptr, &(value.len() as u32).to_le_bytes());
+
+        Ok(0)
+    }
+
+    #[seal(1)]
+    fn contains_storage(key_ptr: u32, key_len: u32) -> Result<u32, Trap> {
+        let key = read_storage_key(mem, key_ptr, key_len);
+        println!("contains_storage: {}", hex::encode(key));
+
+        match vm.contract().storage.get(&key) {
+            Some(value) => Ok(value.len() as u32),
+            _ => Ok(u32::MAX), // In pallets contract, u32::MAX is the "none sentinel"
+        }
+    }
+
     #[seal(0)]
     fn hash_keccak_256(input_ptr: u32, input_len: u32, output_ptr: u32) -> Result<(), Trap> {
         let mut hasher = Keccak::v256();
@@ -482,11 +558,13 @@ impl Runtime {
         Ok(())
     }
 
-    #[seal(1)]
+    #[seal(2)]
     fn seal_call(
         flags: u32,
         callee_ptr: u32,
-        _gas: u64,
+        ref_time_limit: u64,
+        proof_size_limit: u64,
+        deposit_ptr: u32,
         value_ptr: u32,
         input_ptr: u32,
         input_len: u32,
@@ -511,6 +589,13 @@ impl Runtime {
         let value = read_value(mem, value_ptr);
         let callee_address = read_account(mem, callee_ptr);
 
+        vm.call_limits.push(read_call_limits(
+            mem,
+            ref_time_limit,
+            proof_size_limit,
+            deposit_ptr,
+        ));
+
         let callee = match vm
             .accounts
             .iter()
@@ -565,10 +650,12 @@ impl Runtime {
         Ok(())
     }
 
-    #[seal(1)]
+    #[seal(2)]
     fn instantiate(
         code_hash_ptr: u32,
-        _gas: u64,
+        ref_time_limit: u64,
+        proof_size_limit: u64,
+        deposit_ptr: u32,
         value_ptr: u32,
         input_data_ptr: u32,
         input_data_len: u32,
@@ -584,6 +671,13 @@ impl Runtime {
         let input = read_buf(mem, input_data_ptr, input_data_len);
         let value = read_value(mem, value_ptr);
 
+        vm.call_limits.push(read_call_limits(
+            mem,
+            ref_time_limit,
+            proof_size_limit,
+            deposit_ptr,
+        ));
+
         if value > vm.accounts[vm.account].value {
             return Ok(5); // ReturnCode::TransferFailed
         }
@@ -759,6 +853,9 @@ impl Runtime {
 
     /// Mock chain extension with ID 123 that writes the reversed input to the output buf.
     /// Returns the sum of the input data.
+    ///
+    /// Function 1 of extension 1 writes the input unchanged to the output buf and returns 0.
+    /// Function 2 of extension 1 fails with status code 1.
     #[seal(0)]
     fn call_chain_extension(
         id: u32,
@@ -767,11 +864,20 @@ impl Runtime {
         output_ptr: u32,
         output_len_ptr: u32,
     ) -> Result<u32, Trap> {
-        assert_eq!(id, 123, "unkown chain extension");
         assert!(read_len(mem, output_len_ptr) == 16384 && input_len <= 16384);
 
         let mut data = read_buf(mem, input_ptr, input_len);
-        data.reverse();
+
+        match id {
+            123 => data.reverse(),
+            0x0001_0001 => {
+                write_buf(mem, output_ptr, &data);
+                write_buf(mem, output_len_ptr, &(data.len() as u32).to_le_bytes());
+                return Ok(0);
+            }
+            0x0001_0002 => return Ok(1),
+            _ => panic!("unknown chain extension"),
+        }
 
         write_buf(mem, output_ptr, &data);
         write_buf(mem, output_len_ptr, &(data.len() as u32).to_le_bytes());
@@ -794,6 +900,23 @@ impl Runtime {
         Ok((vm.accounts[vm.caller_account].address == [0; 32]).into())
     }
 
+    #[seal(0)]
+    fn reentrance_count() -> Result<u32, Trap> {
+        let count = vm.call_stack.iter().filter(|a| **a == vm.account).count();
+        Ok(count as u32 - 1)
+    }
+
+    #[seal(0)]
+    fn account_reentrance_count(account_ptr: u32) -> Result<u32, Trap> {
+        let address = read_account(mem, account_ptr);
+        let count = vm
+            .call_stack
+            .iter()
+            .filter(|a| vm.accounts[**a].address == address)
+            .count();
+        Ok(count as u32)
+    }
+
     #[seal(0)]
     fn set_code_hash(code_hash_ptr: u32) -> Result<u32, Trap> {
         let hash = read_hash(mem, code_hash_ptr);
@@ -803,6 +926,143 @@ impl Runtime {
         }
         Ok(7) // ReturnCode::CodeNoteFound
     }
+
+    #[seal(0)]
+    fn lock_delegate_dependency(code_hash_ptr: u32) -> Result<(), Trap> {
+        let hash = read_hash(mem, code_hash_ptr);
+        if !vm.blobs.iter().any(|code| code.hash == hash) {
+            return Err(Trap::new("CodeNotFound"));
+        }
+
+        let contract = vm.contract();
+        if contract.code.hash == hash {
+            return Err(Trap::new("CannotAddSelfAsDelegateDependency"));
+        }
+        if contract.delegate_dependencies.contains(&hash) {
+            return Err(Trap::new("DelegateDependencyAlreadyExists"));
+        }
+
+        contract.delegate_dependencies.push(hash);
+        Ok(())
+    }
+
+    #[seal(0)]
+    fn unlock_delegate_dependency(code_hash_ptr: u32) -> Result<(), Trap> {
+        let hash = read_hash(mem, code_hash_ptr);
+        let dependencies = &mut vm.contract().delegate_dependencies;
+        let Some(index) = dependencies.iter().position(|h| *h == hash) else {
+            return Err(Trap::new("DelegateDependencyNotFound"));
+        };
+
+        dependencies.remove(index);
+        Ok(())
+    }
+
+    #[seal(0)]
+    fn sr25519_verify(
+        signature_ptr: u32,
+        pub_key_ptr: u32,
+        message_len: u32,
+        message_ptr: u32,
+    ) -> Result<u32, Trap> {
+        let signature = schnorrkel::Signature::from_bytes(&read_buf(mem, signature_ptr, 64));
+        let pub_key = schnorrkel::PublicKey::from_bytes(&read_buf(mem, pub_key_ptr, 32));
+        let message = read_buf(mem, message_ptr, message_len);
+
+        match (signature, pub_key) {
+            (Ok(signature), Ok(pub_key))
+                if pub_key
+                    .verify_simple(b"substrate", &message, &signature)
+                    .is_ok() =>
+            {
+                Ok(0)
+            }
+            _ => Ok(12), // ReturnCode::Sr25519VerifyFailed
+        }
+    }
+
+    #[seal(0)]
+    fn ecdsa_recover(
+        signature_ptr: u32,
+        message_hash_ptr: u32,
+        output_ptr: u32,
+    ) -> Result<u32, Trap> {
+        let signature = read_buf(mem, signature_ptr, 65);
+        let message_hash = read_buf(mem, message_hash_ptr, 32);
+
+        let v = match signature[64] {
+            v if v > 26 => v - 27,
+            v => v,
+        };
+
+        let pub_key = k256::ecdsa::Signature::from_slice(&signature[..64])
+            .ok()
+            .zip(k256::ecdsa::RecoveryId::from_byte(v))
+            .and_then(|(signature, recovery_id)| {
+                k256::ecdsa::VerifyingKey::recover_from_prehash(
+                    &message_hash,
+                    &signature,
+                    recovery_id,
+                )
+                .ok()
+            });
+
+        match pub_key {
+            Some(pub_key) => {
+                write_buf(mem, output_ptr, pub_key.to_encoded_point(true).as_bytes());
+                Ok(0)
+            }
+            None => Ok(11), // ReturnCode::EcdsaRecoveryFailed
+        }
+    }
+
+    #[seal(0)]
+    fn ecdsa_to_eth_address(key_ptr: u32, output_ptr: u32) -> Result<u32, Trap> {
+        let Ok(pub_key) = k256::ecdsa::VerifyingKey::from_sec1_bytes(&read_buf(mem, key_ptr, 33))
+        else {
+            return Ok(11); // ReturnCode::EcdsaRecoveryFailed
+        };
+
+        let mut hasher = Keccak::v256();
+        let mut hash = [0u8; 32];
+        hasher.update(&pub_key.to_encoded_point(false).as_bytes()[1..]);
+        hasher.finalize(&mut hash);
+
+        write_buf(mem, output_ptr, &hash[12..]);
+        Ok(0)
+    }
+
+    #[seal(0)]
+    fn xcm_execute(msg_ptr: u32, msg_len: u32) -> Result<u32, Trap> {
+        let message = read_buf(mem, msg_ptr, msg_len);
+        if message.is_empty() {
+            return Ok(13); // ReturnCode::XcmExecutionFailed
+        }
+
+        vm.xcm_messages.push(XcmMessage {
+            dest: None,
+            message,
+        });
+        Ok(0)
+    }
+
+    #[seal(0)]
+    fn xcm_send(dest_ptr: u32, msg_ptr: u32, msg_len: u32, output_ptr: u32) -> Result<u32, Trap> {
+        // The only destination understood by the mock is a `VersionedLocation::V4` without any
+        // junctions, which is encoded as the version, the number of parents and `Here`.
+        let dest = read_buf(mem, dest_ptr, 3);
+        let message = read_buf(mem, msg_ptr, msg_len);
+        if dest[0] != 4 || dest[2] != 0 || message.is_empty() {
+            return Ok(14); // ReturnCode::XcmSendFailed
+        }
+
+        write_buf(mem, output_ptr, blake2b(32, &[], &message).as_bytes());
+        vm.xcm_messages.push(XcmMessage {
+            dest: Some(dest),
+            message,
+        });
+        Ok(0)
+    }
 }
 
 /// Provides a mock implementation of substrates [contracts pallet][1]
@@ -819,6 +1079,9 @@ impl MockSubstrate {
         runtime.debug_buffer.clear();
         runtime.events.clear();
         runtime.called_accounts.clear();
+        runtime.call_stack.clear();
+        runtime.xcm_messages.clear();
+        runtime.call_limits.clear();
         self.0 = runtime.call(export, callee, input, value).unwrap()?;
         self.0.data_mut().transferred_value = 0;
 
@@ -868,6 +1131,27 @@ impl MockSubstrate {
         self.0.data().events.clone()
     }
 
+    /// Get the XCM messages executed or sent by the last function or constructor call.
+    pub fn xcm_messages(&self) -> Vec<XcmMessage> {
+        self.0.data().xcm_messages.clone()
+    }
+
+    /// Get the limits of the calls and instantiations made by the last function or constructor
+    /// call.
+    pub fn call_limits(&self) -> Vec<CallLimits> {
+        self.0.data().call_limits.clone()
+    }
+
+    /// Get the code hashes locked as delegate dependencies by the account that was (or is about
+    /// to be) called.
+    pub fn delegate_dependencies(&self) -> &[Hash] {
+        &self.0.data().accounts[self.0.data().account]
+            .contract
+            .as_ref()
+            .unwrap()
+            .delegate_dependencies
+    }
+
     /// Get a list of all deployed contracts.
     pub fn contracts(&self) -> Vec<&Contract> {
         self.0
@@ -1051,12 +1335,25 @@ pub fn build_solidity_with_options(src: &str, log_err: bool) -> MockSubstrate {
     MockSubstrate(Store::new(&Engine::default(), Runtime::new(blobs)))
 }
 
+/// A variant of `build_solidity()` which compiles for the given Polkadot `target`.
+pub fn build_solidity_with_target(src: &str, target: Target) -> MockSubstrate {
+    let blobs = build_wasm_with_target(src, true, target)
+        .iter()
+        .map(|(code, abi)| WasmCode::new(abi, code))
+        .collect();
+
+    MockSubstrate(Store::new(&Engine::default(), Runtime::new(blobs)))
+}
+
 pub fn build_wasm(src: &str, log_err: bool) -> Vec<(Vec<u8>, String)> {
+    build_wasm_with_target(src, log_err, Target::default_polkadot())
+}
+
+pub fn build_wasm_with_target(src: &str, log_err: bool, target: Target) -> Vec<(Vec<u8>, String)> {
     let tmp_file = OsStr::new("test.sol");
     let mut cache = FileResolver::default();
     cache.set_file_contents(tmp_file.to_str().unwrap(), src.to_string());
     let opt = inkwell::OptimizationLevel::Default;
-    let target = Target::default_polkadot();
     let (wasm, ns) = compile(
         tmp_file,
         &mut cache,
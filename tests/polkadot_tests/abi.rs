@@ -1,11 +1,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{build_wasm, load_abi};
+use crate::{build_wasm, build_wasm_with_target, load_abi};
 use ink_metadata::{InkProject, TypeSpec};
 use once_cell::sync::Lazy;
 use scale_info::{
     form::PortableForm, Path, TypeDef, TypeDefComposite, TypeDefPrimitive, TypeDefVariant,
 };
+use solang::Target;
 use std::{collections::HashSet, sync::Mutex};
 
 macro_rules! path {
@@ -338,3 +339,43 @@ fn overloaded_but_not_overridden_function_is_mangled() {
 
     assert!(expected_function_names.is_empty());
 }
+
+/// The storage layout can only describe mappings with ink! storage keys, since the other values
+/// are stored under 32 byte slots.
+#[test]
+fn storage_layout() {
+    let src = r#"
+    struct S { uint64 a; bool[2] b; mapping(uint32 => bool) m; }
+    contract Storage {
+        uint64 x;
+        S s;
+        int32[] list;
+        mapping(address => uint128) balances;
+        string name;
+    }"#;
+    let abi = load_abi(&build_wasm(src, false)[0].1);
+    let layout = serde_json::to_value(abi.layout()).unwrap();
+    assert!(layout["struct"]["fields"].as_array().unwrap().is_empty());
+
+    let target = Target::Polkadot {
+        address_length: 32,
+        value_length: 16,
+        ink_storage_keys: true,
+    };
+    let abi = load_abi(&build_wasm_with_target(src, false, target)[0].1);
+    let layout = serde_json::to_value(abi.layout()).unwrap();
+    let fields = layout["struct"]["fields"].as_array().unwrap();
+
+    let names: Vec<_> = fields.iter().map(|f| f["name"].as_str().unwrap()).collect();
+    assert_eq!(names, ["s", "balances"]);
+
+    // mappings are keyed by their slot, like an ink! `Mapping`
+    let m = &fields[0]["layout"]["struct"]["fields"];
+    assert_eq!(m.as_array().unwrap().len(), 1);
+    assert_eq!(m[0]["name"], "m");
+    assert_eq!(m[0]["layout"]["root"]["root_key"], "0x04000000");
+
+    let balances = &fields[1]["layout"]["root"];
+    assert_eq!(balances["root_key"], "0x06000000");
+    assert_eq!(balances["layout"]["leaf"]["key"], "0x06000000");
+}
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use blake2_rfc::blake2b::blake2b;
 use parity_scale_codec::{Decode, Encode};
+use tiny_keccak::{Hasher, Keccak};
 
 use crate::build_solidity;
 
@@ -776,6 +778,38 @@ fn call_chain_extension() {
     assert_eq!(ret.1, data.iter().cloned().rev().collect::<Vec<_>>());
 }
 
+#[test]
+fn chain_extension_interface() {
+    let mut runtime = build_solidity(
+        r#"
+        @chain_extension(1)
+        interface Mock {
+            @function_id(1)
+            function echo(uint64 a, string b) external returns (uint64, string);
+
+            @function_id(2)
+            function fail() external;
+        }
+
+        contract Foo {
+            function echo(uint64 a, string b) public returns (uint64, string) {
+                return Mock.echo(a, b);
+            }
+
+            function fail() public {
+                Mock.fail();
+            }
+        }"#,
+    );
+
+    let input = (42u64, "hello".to_string());
+    runtime.function("echo", input.encode());
+    assert_eq!(runtime.output(), input.encode());
+
+    runtime.function_expect_failure("fail", Vec::new());
+    assert!(runtime.output().is_empty());
+}
+
 #[test]
 fn is_contract() {
     let mut runtime = build_solidity(
@@ -846,6 +880,38 @@ fn set_code_hash() {
     assert_eq!(runtime.output(), 1u32.encode());
 }
 
+#[test]
+fn delegate_dependency() {
+    let mut runtime = build_solidity(
+        r#"
+        import "polkadot";
+
+        contract Dependent {
+            function lock(Hash code_hash) external {
+                lock_delegate_dependency(code_hash);
+            }
+
+            function unlock(Hash code_hash) external {
+                unlock_delegate_dependency(code_hash);
+            }
+        }
+
+        contract Library {
+            function foo() external pure returns (uint32) {
+                return 42;
+            }
+        }"#,
+    );
+
+    let library_code_hash = runtime.blobs()[1].hash;
+
+    runtime.function("lock", library_code_hash.as_ref().to_vec());
+    assert_eq!(runtime.delegate_dependencies(), &[library_code_hash]);
+
+    runtime.function("unlock", library_code_hash.as_ref().to_vec());
+    assert!(runtime.delegate_dependencies().is_empty());
+}
+
 #[test]
 fn caller_is_root() {
     let mut runtime = build_solidity(
@@ -866,3 +932,188 @@ fn caller_is_root() {
     runtime.function("test", [0; 32].to_vec());
     assert_eq!(runtime.output(), true.encode());
 }
+
+#[test]
+fn reentrance_count() {
+    let mut runtime = build_solidity(
+        r#"
+        import { reentrance_count, account_reentrance_count } from "polkadot";
+        contract Test {
+            function outer() public returns (uint32, uint32) {
+                // ALLOW_REENTRY
+                (bool ok, bytes raw) = address(this).call{flags: 8}(
+                    abi.encodeWithSelector(this.inner.selector)
+                );
+                require(ok);
+                return abi.decode(raw, (uint32, uint32));
+            }
+
+            function inner() public view returns (uint32, uint32) {
+                return (reentrance_count(), account_reentrance_count(address(this)));
+            }
+
+            function other(address a) public view returns (uint32) {
+                return account_reentrance_count(a);
+            }
+        }"#,
+    );
+
+    runtime.function("inner", vec![]);
+    assert_eq!(runtime.output(), (0u32, 1u32).encode());
+
+    runtime.function("outer", vec![]);
+    assert_eq!(runtime.output(), (1u32, 2u32).encode());
+
+    runtime.function("other", [1; 32].encode());
+    assert_eq!(runtime.output(), 0u32.encode());
+}
+
+#[test]
+fn xcm() {
+    let mut runtime = build_solidity(
+        r#"
+        contract Xcm {
+            function execute(bytes message) public {
+                xcm.execute(message);
+            }
+
+            function send(bytes dest, bytes message) public returns (bytes32) {
+                return xcm.send(dest, message);
+            }
+        }"#,
+    );
+
+    let message = vec![4, 8, 15, 16, 23, 42];
+
+    runtime.function("execute", message.encode());
+    let sent = runtime.xcm_messages();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].dest, None);
+    assert_eq!(sent[0].message, message);
+
+    // VersionedLocation::V4 of the relay chain
+    let dest = vec![4, 1, 0];
+
+    runtime.function("send", (dest.clone(), message.clone()).encode());
+    assert_eq!(runtime.output(), blake2b(32, &[], &message).as_bytes());
+    let sent = runtime.xcm_messages();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].dest, Some(dest.clone()));
+    assert_eq!(sent[0].message, message);
+
+    runtime.function_expect_failure("execute", Vec::<u8>::new().encode());
+    assert!(runtime
+        .debug_buffer()
+        .contains("runtime_error: xcm execute failed"));
+
+    runtime.function_expect_failure("send", (dest, Vec::<u8>::new()).encode());
+    assert!(runtime
+        .debug_buffer()
+        .contains("runtime_error: xcm send failed"));
+    assert!(runtime.xcm_messages().is_empty());
+}
+
+#[test]
+fn signature_verify() {
+    let mut runtime = build_solidity(
+        r#"
+        contract Verifier {
+            function verify(bytes32 key, bytes message, bytes signature) public pure returns (bool) {
+                return signatureVerify(key, message, signature);
+            }
+        }"#,
+    );
+
+    let keypair = schnorrkel::MiniSecretKey::from_bytes(&[7; 32])
+        .unwrap()
+        .expand_to_keypair(schnorrkel::ExpansionMode::Ed25519);
+    let message = b"hello polkadot".to_vec();
+    let signature = keypair.sign_simple(b"substrate", &message).to_bytes();
+    let key = keypair.public.to_bytes();
+
+    runtime.function(
+        "verify",
+        (key, message.clone(), signature.to_vec()).encode(),
+    );
+    assert_eq!(runtime.output(), true.encode());
+
+    let mut bad_signature = signature;
+    bad_signature[0] ^= 1;
+
+    runtime.function(
+        "verify",
+        (key, message.clone(), bad_signature.to_vec()).encode(),
+    );
+    assert_eq!(runtime.output(), false.encode());
+
+    // an sr25519 signature is always 64 bytes
+    runtime.function("verify", (key, message, signature[..63].to_vec()).encode());
+    assert_eq!(runtime.output(), false.encode());
+}
+
+#[test]
+fn ecrecover() {
+    let mut runtime = build_solidity(
+        r#"
+        import "polkadot";
+
+        contract Recover {
+            function signer(bytes32 hash, uint8 v, bytes32 r, bytes32 s) public pure returns (bytes20) {
+                return ecrecover(hash, v, r, s);
+            }
+
+            function to_eth_address(uint8[33] key) public pure returns (bytes20) {
+                return ecdsa_to_eth_address(key);
+            }
+        }"#,
+    );
+
+    let hash: [u8; 32] =
+        hex::decode("7b438b8a4eebc0b8d79f8188f8d5ab3b7958105a9d3ac58c12e3cf4c3b4c548b")
+            .unwrap()
+            .try_into()
+            .unwrap();
+    let r: [u8; 32] =
+        hex::decode("9804f8f7593e9ad2d06f5cd88f69ad3b3d48b486e63d4968901ba463bc648200")
+            .unwrap()
+            .try_into()
+            .unwrap();
+    let s: [u8; 32] =
+        hex::decode("70a8faa3826a18518743b655a0d24b54e9cea0b84a70edeec304d53b41e8d25b")
+            .unwrap()
+            .try_into()
+            .unwrap();
+    let signer = hex::decode("4a62316623ad457f02cdc5d997ded67a383ec569").unwrap();
+
+    runtime.function("signer", (hash, 28u8, r, s).encode());
+    assert_eq!(runtime.output(), signer);
+
+    // the wrong recovery id gives a different key
+    runtime.function("signer", (hash, 27u8, r, s).encode());
+    assert_ne!(runtime.output(), signer);
+
+    // recovery fails for an invalid signature
+    runtime.function("signer", (hash, 28u8, [0u8; 32], s).encode());
+    assert_eq!(runtime.output(), vec![0; 20]);
+
+    let signing_key = k256::ecdsa::SigningKey::from_slice(&[3; 32]).unwrap();
+    let pub_key = signing_key.verifying_key().to_encoded_point(false);
+
+    let mut hasher = Keccak::v256();
+    let mut key_hash = [0u8; 32];
+    hasher.update(&pub_key.as_bytes()[1..]);
+    hasher.finalize(&mut key_hash);
+
+    let key: [u8; 33] = signing_key
+        .verifying_key()
+        .to_encoded_point(true)
+        .as_bytes()
+        .try_into()
+        .unwrap();
+
+    runtime.function("to_eth_address", key.encode());
+    assert_eq!(runtime.output(), key_hash[12..].to_vec());
+
+    runtime.function("to_eth_address", [0u8; 33].encode());
+    assert_eq!(runtime.output(), vec![0; 20]);
+}
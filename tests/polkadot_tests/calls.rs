@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{build_solidity, build_solidity_with_options};
+use crate::{build_solidity, build_solidity_with_options, CallLimits};
 use parity_scale_codec::{Decode, Encode};
 use primitive_types::U256;
 use solang::{
@@ -1311,3 +1311,96 @@ fn try_catch_multiple_error_args() {
     runtime.function_expect_failure("a", Vec::new());
     assert_eq!(runtime.output(), expected_output);
 }
+
+#[test]
+fn non_reentrant() {
+    let mut runtime = build_solidity(
+        r##"
+        contract Guarded {
+            @nonReentrant
+            function guarded(bool reenter) public returns (bool) {
+                if (reenter) {
+                    // ALLOW_REENTRY, so only the annotation rejects the call
+                    (bool ok, ) = address(this).call{flags: 8}(
+                        abi.encodeWithSelector(this.guarded.selector, false)
+                    );
+                    return ok;
+                }
+
+                return true;
+            }
+
+            function unguarded(bool reenter) public returns (bool) {
+                if (reenter) {
+                    (bool ok, ) = address(this).call{flags: 8}(
+                        abi.encodeWithSelector(this.unguarded.selector, false)
+                    );
+                    return ok;
+                }
+
+                return true;
+            }
+
+            function reenter_guarded() public returns (bool ok) {
+                (ok, ) = address(this).call{flags: 8}(
+                    abi.encodeWithSelector(this.guarded.selector, false)
+                );
+            }
+        }"##,
+    );
+
+    runtime.function("guarded", false.encode());
+    assert_eq!(runtime.output(), true.encode());
+
+    runtime.function("guarded", true.encode());
+    assert_eq!(runtime.output(), false.encode());
+
+    runtime.function("unguarded", true.encode());
+    assert_eq!(runtime.output(), true.encode());
+
+    // any frame of this contract on the call stack makes the call re-entrant
+    runtime.function("reenter_guarded", vec![]);
+    assert_eq!(runtime.output(), false.encode());
+}
+
+#[test]
+fn call_limits() {
+    let mut runtime = build_solidity(
+        r##"
+        contract Caller {
+            function limited() public {
+                Callee callee = new Callee{refTime: 1000, storageDepositLimit: 500}();
+
+                callee.ping{refTime: 2000, proofSize: 3000, storageDepositLimit: 4000}();
+                callee.ping{gas: 5000}();
+
+                (bool ok, ) = address(callee).call{proofSize: 6000}(
+                    abi.encodeCall(Callee.ping, ())
+                );
+                require(ok);
+            }
+        }
+
+        contract Callee {
+            function ping() public {}
+        }"##,
+    );
+
+    runtime.function("limited", vec![]);
+
+    let limits = |ref_time, proof_size, storage_deposit| CallLimits {
+        ref_time,
+        proof_size,
+        storage_deposit,
+    };
+
+    assert_eq!(
+        runtime.call_limits(),
+        vec![
+            limits(1000, 0, Some(500)),
+            limits(2000, 3000, Some(4000)),
+            limits(5000, 0, None),
+            limits(0, 6000, None),
+        ]
+    );
+}
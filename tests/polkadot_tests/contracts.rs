@@ -188,6 +188,45 @@ fn creation_code() {
     assert_eq!(runtime.output(), runtime.contracts()[1].code.blob.encode());
 }
 
+#[test]
+fn new_with_code_hash() {
+    let mut runtime = build_solidity(
+        r##"
+        contract Factory {
+            uint32 public generation;
+
+            constructor(uint32 g) {
+                generation = g;
+            }
+
+            function spawn(bytes32 hash) public returns (uint32) {
+                Factory f = new Factory{codeHash: hash}(generation + 1);
+                return f.generation();
+            }
+
+            function try_spawn(bytes32 hash) public returns (bool) {
+                try new Factory{codeHash: hash}(0) returns (Factory) {
+                    return true;
+                } catch (bytes) {
+                    return false;
+                }
+            }
+        }"##,
+    );
+
+    runtime.constructor(0, 0u32.encode());
+
+    let hash = runtime.blobs()[0].hash;
+    runtime.function("spawn", hash.as_ref().to_vec());
+    assert_eq!(runtime.output(), 1u32.encode());
+
+    runtime.function("try_spawn", hash.as_ref().to_vec());
+    assert_eq!(runtime.output(), true.encode());
+
+    runtime.function("try_spawn", [0u8; 32].to_vec());
+    assert_eq!(runtime.output(), false.encode());
+}
+
 #[test]
 fn issue666() {
     let mut runtime = build_solidity(
@@ -1,10 +1,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use blake2_rfc::blake2b::blake2b;
 use parity_scale_codec::{Decode, Encode};
 use rand::Rng;
 use std::collections::HashMap;
 
-use crate::build_solidity;
+use crate::{build_solidity, build_solidity_with_target};
+use solang::{file_resolver::FileResolver, Target};
+use std::ffi::OsStr;
 
 #[test]
 fn basic() {
@@ -398,3 +401,232 @@ fn test_address() {
         assert_eq!(runtime.output(), Val(val.1).encode());
     }
 }
+
+#[test]
+fn zero_value_clears_entry() {
+    let mut runtime = build_solidity(
+        r##"
+        contract c {
+            mapping(uint64 => uint64) balances;
+            mapping(uint64 => bool) flags;
+
+            function set(uint64 key, uint64 value) public {
+                balances[key] = value;
+            }
+
+            function add(uint64 key, uint64 value) public {
+                balances[key] += value;
+            }
+
+            function has_balance(uint64 key) public view returns (bool) {
+                return balances[key] != 0;
+            }
+
+            function withdraw(uint64 key) public returns (uint64 value) {
+                value = balances[key];
+                delete balances[key];
+            }
+
+            function set_flag(uint64 key, bool value) public {
+                flags[key] = value;
+            }
+
+            function flag(uint64 key) public view returns (bool) {
+                return flags[key];
+            }
+        }"##,
+    );
+
+    runtime.function("set", (1u64, 100u64).encode());
+    assert_eq!(runtime.storage().len(), 1);
+
+    runtime.function("has_balance", 1u64.encode());
+    assert_eq!(runtime.output(), true.encode());
+
+    runtime.function("has_balance", 2u64.encode());
+    assert_eq!(runtime.output(), false.encode());
+
+    // storing zero removes the entry rather than storing the zero
+    runtime.function("set", (1u64, 0u64).encode());
+    assert!(runtime.storage().is_empty());
+
+    runtime.function("has_balance", 1u64.encode());
+    assert_eq!(runtime.output(), false.encode());
+
+    runtime.function("add", (1u64, 5u64).encode());
+    runtime.function("add", (1u64, 5u64).encode());
+    assert_eq!(runtime.storage().len(), 1);
+
+    runtime.function("withdraw", 1u64.encode());
+    assert_eq!(runtime.output(), 10u64.encode());
+    assert!(runtime.storage().is_empty());
+
+    runtime.function("withdraw", 1u64.encode());
+    assert_eq!(runtime.output(), 0u64.encode());
+
+    runtime.function("set_flag", (7u64, true).encode());
+    assert_eq!(runtime.storage().len(), 1);
+
+    runtime.function("flag", 7u64.encode());
+    assert_eq!(runtime.output(), true.encode());
+
+    runtime.function("flag", 8u64.encode());
+    assert_eq!(runtime.output(), false.encode());
+
+    runtime.function("set_flag", (7u64, false).encode());
+    assert!(runtime.storage().is_empty());
+
+    runtime.function("flag", 7u64.encode());
+    assert_eq!(runtime.output(), false.encode());
+}
+
+#[test]
+fn ink_storage_keys() {
+    let mut runtime = build_solidity_with_target(
+        r##"
+        contract c {
+            uint32 count;
+            mapping(address => uint64) balances;
+            mapping(string => bool) names;
+
+            function set(address owner, uint64 value) public {
+                balances[owner] = value;
+            }
+
+            function get(address owner) public view returns (uint64) {
+                return balances[owner];
+            }
+
+            function has_balance(address owner) public view returns (bool) {
+                return balances[owner] != 0;
+            }
+
+            function add_name(string name) public {
+                names[name] = true;
+            }
+
+            function has_name(string name) public view returns (bool) {
+                return names[name];
+            }
+        }"##,
+        Target::Polkadot {
+            address_length: 32,
+            value_length: 16,
+            ink_storage_keys: true,
+        },
+    );
+
+    // like an ink! `Mapping`, the storage key is the root key followed by the mapping key
+    let ink_key =
+        |key: Vec<u8>| -> [u8; 32] { blake2b(32, &[], &key).as_bytes().try_into().unwrap() };
+
+    let owner = [7u8; 32];
+
+    runtime.function("set", (owner, 100u64).encode());
+    assert_eq!(
+        runtime.storage()[&ink_key((1u32, owner).encode())],
+        100u64.encode()
+    );
+
+    runtime.function("get", owner.encode());
+    assert_eq!(runtime.output(), 100u64.encode());
+
+    runtime.function("get", [8u8; 32].encode());
+    assert_eq!(runtime.output(), 0u64.encode());
+
+    // ink! stores zeros, so an entry which exists may still be zero
+    runtime.function("set", (owner, 0u64).encode());
+    assert_eq!(
+        runtime.storage()[&ink_key((1u32, owner).encode())],
+        0u64.encode()
+    );
+
+    runtime.function("has_balance", owner.encode());
+    assert_eq!(runtime.output(), false.encode());
+
+    runtime.function("get", owner.encode());
+    assert_eq!(runtime.output(), 0u64.encode());
+
+    runtime.function("add_name", "alice".to_string().encode());
+    assert_eq!(
+        runtime.storage()[&ink_key((2u32, "alice".to_string()).encode())],
+        true.encode()
+    );
+
+    runtime.function("has_name", "alice".to_string().encode());
+    assert_eq!(runtime.output(), true.encode());
+
+    runtime.function("has_name", "bob".to_string().encode());
+    assert_eq!(runtime.output(), false.encode());
+}
+
+#[test]
+fn ink_storage_keys_unsupported() {
+    let mut cache = FileResolver::default();
+
+    cache.set_file_contents(
+        "a.sol",
+        r#"
+        struct S { mapping(uint32 => bool) flags; }
+
+        contract c {
+            mapping(uint32 => mapping(uint32 => bool)) nested;
+            mapping(uint32 => S) structs;
+            mapping(uint32 => uint64[]) arrays;
+            S[] list;
+            S[2] pair;
+            mapping(bytes => string) strings;
+
+            function f(bytes k) public view returns (uint32) {
+                string storage s = strings[k];
+                return bytes(s).length;
+            }
+
+            function g(string storage s) internal view returns (bytes storage) {}
+        }
+        "#
+        .to_string(),
+    );
+
+    let ns = solang::parse_and_resolve(
+        OsStr::new("a.sol"),
+        &mut cache,
+        Target::Polkadot {
+            address_length: 32,
+            value_length: 16,
+            ink_storage_keys: true,
+        },
+    );
+
+    let errors = ns.diagnostics.errors();
+
+    assert_eq!(errors.len(), 7);
+    assert_eq!(
+        errors[0].message,
+        "mapping with values of type 'mapping(uint32 => bool)' is not supported with ink! storage keys"
+    );
+    assert_eq!(
+        errors[1].message,
+        "mapping with values of type 'struct S' is not supported with ink! storage keys"
+    );
+    assert_eq!(
+        errors[2].message,
+        "mapping with values of type 'uint64[]' is not supported with ink! storage keys"
+    );
+    assert_eq!(
+        errors[3].message,
+        "mapping in a dynamic array is not supported with ink! storage keys"
+    );
+    assert_eq!(
+        errors[4].message,
+        "storage references to 'string' are not supported with ink! storage keys"
+    );
+    assert_eq!(
+        errors[5].message,
+        "storage references to 'string' are not supported with ink! storage keys"
+    );
+    assert_eq!(
+        errors[6].message,
+        "storage references to 'bytes' are not supported with ink! storage keys"
+    );
+}
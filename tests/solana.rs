@@ -2,7 +2,7 @@
 
 use crate::borsh_encoding::{decode_at_offset, encode_arguments, BorshToken};
 use anchor_syn::idl::types::{Idl, IdlAccountItem};
-use base58::{FromBase58, ToBase58};
+use base58::ToBase58;
 use byteorder::{ByteOrder, LittleEndian, WriteBytesExt};
 use itertools::Itertools;
 use libc::c_char;
@@ -106,6 +106,51 @@ struct ClockLayout {
     unix_timestamp: u64,
 }
 
+/// The clock sysvar returned by `sol_get_clock_sysvar`
+const CLOCK: ClockLayout = ClockLayout {
+    slot: 70818331,
+    epoch: 102,
+    epoch_start_timestamp: 946684800,
+    leader_schedule_epoch: 1231231312,
+    unix_timestamp: 1620656423,
+};
+
+/// The rent sysvar returned by `sol_get_rent_sysvar`, with the default rent of mainnet
+#[derive(Serialize)]
+struct RentLayout {
+    lamports_per_byte_year: u64,
+    exemption_threshold: f64,
+    burn_percent: u8,
+    padding: [u8; 7],
+}
+
+const RENT: RentLayout = RentLayout {
+    lamports_per_byte_year: 3480,
+    exemption_threshold: 2.0,
+    burn_percent: 50,
+    padding: [0; 7],
+};
+
+/// The epoch schedule sysvar returned by `sol_get_epoch_schedule_sysvar`
+#[derive(Serialize)]
+struct EpochScheduleLayout {
+    slots_per_epoch: u64,
+    leader_schedule_slot_offset: u64,
+    warmup: bool,
+    padding: [u8; 7],
+    first_normal_epoch: u64,
+    first_normal_slot: u64,
+}
+
+const EPOCH_SCHEDULE: EpochScheduleLayout = EpochScheduleLayout {
+    slots_per_epoch: 432000,
+    leader_schedule_slot_offset: 432000,
+    warmup: false,
+    padding: [0; 7],
+    first_normal_epoch: 0,
+    first_normal_slot: 0,
+};
+
 #[derive(Deserialize)]
 struct CreateAccount {
     instruction: u32,
@@ -218,30 +263,6 @@ impl VirtualMachineBuilder {
             });
         }
 
-        // Add clock account
-        let clock_account: Account = "SysvarC1ock11111111111111111111111111111111"
-            .from_base58()
-            .unwrap()
-            .try_into()
-            .unwrap();
-
-        let clock_layout = ClockLayout {
-            slot: 70818331,
-            epoch: 102,
-            epoch_start_timestamp: 946684800,
-            leader_schedule_epoch: 1231231312,
-            unix_timestamp: 1620656423,
-        };
-
-        account_data.insert(
-            clock_account,
-            AccountState {
-                data: bincode::serialize(&clock_layout).unwrap(),
-                owner: None,
-                lamports: 0,
-            },
-        );
-
         account_data.insert([0; 32], AccountState::default());
 
         let cur = programs.last().unwrap().clone();
@@ -634,6 +655,96 @@ fn sol_log_u64(
     *result = ProgramResult::Ok(0)
 }
 
+fn sol_log_compute_units_(
+    context: &mut SyscallContext,
+    _arg1: u64,
+    _arg2: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    _memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    let message = format!("Program consumption: {} units remaining", context.remaining);
+
+    println!("log compute units: {message}");
+
+    if let Ok(mut vm) = context.vm.try_borrow_mut() {
+        vm.logs.push_str(&message);
+    }
+    *result = ProgramResult::Ok(0)
+}
+
+fn sol_remaining_compute_units(
+    context: &mut SyscallContext,
+    _arg1: u64,
+    _arg2: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    _memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    *result = ProgramResult::Ok(context.remaining)
+}
+
+fn write_sysvar<T: Serialize>(
+    sysvar: &T,
+    dest: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    let data = bincode::serialize(sysvar).unwrap();
+
+    let sysvar_result = question_mark!(
+        translate_slice_mut::<u8>(memory_mapping, dest, data.len() as u64),
+        result
+    );
+
+    sysvar_result.copy_from_slice(&data);
+
+    *result = ProgramResult::Ok(0)
+}
+
+fn sol_get_clock_sysvar(
+    _context: &mut SyscallContext,
+    dest: u64,
+    _arg2: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    write_sysvar(&CLOCK, dest, memory_mapping, result);
+}
+
+fn sol_get_rent_sysvar(
+    _context: &mut SyscallContext,
+    dest: u64,
+    _arg2: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    write_sysvar(&RENT, dest, memory_mapping, result);
+}
+
+fn sol_get_epoch_schedule_sysvar(
+    _context: &mut SyscallContext,
+    dest: u64,
+    _arg2: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    write_sysvar(&EPOCH_SCHEDULE, dest, memory_mapping, result);
+}
+
 fn sol_sha256(
     context: &mut SyscallContext,
     src: u64,
@@ -710,6 +821,58 @@ fn sol_keccak256(
     *result = ProgramResult::Ok(0)
 }
 
+fn sol_secp256k1_recover(
+    _context: &mut SyscallContext,
+    hash: u64,
+    recovery_id: u64,
+    signature: u64,
+    dest: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    let hash = question_mark!(translate_slice::<u8>(memory_mapping, hash, 32), result).to_vec();
+    let signature =
+        question_mark!(translate_slice::<u8>(memory_mapping, signature, 64), result).to_vec();
+
+    println!(
+        "sol_secp256k1_recover: hash:{} recovery_id:{recovery_id} signature:{}",
+        hex::encode(&hash),
+        hex::encode(&signature)
+    );
+
+    // Secp256k1RecoverError::InvalidRecoveryId
+    let Some(recovery_id) = u8::try_from(recovery_id)
+        .ok()
+        .and_then(k256::ecdsa::RecoveryId::from_byte)
+    else {
+        *result = ProgramResult::Ok(2);
+        return;
+    };
+
+    let pub_key = k256::ecdsa::Signature::from_slice(&signature)
+        .ok()
+        .and_then(|signature| {
+            k256::ecdsa::VerifyingKey::recover_from_prehash(&hash, &signature, recovery_id).ok()
+        });
+
+    // Secp256k1RecoverError::InvalidSignature
+    let Some(pub_key) = pub_key else {
+        *result = ProgramResult::Ok(3);
+        return;
+    };
+
+    // The uncompressed point without the 0x04 prefix
+    let point = pub_key.to_encoded_point(false);
+
+    let pub_key_result =
+        question_mark!(translate_slice_mut::<u8>(memory_mapping, dest, 64), result);
+
+    pub_key_result.copy_from_slice(&point.as_bytes()[1..]);
+
+    *result = ProgramResult::Ok(0)
+}
+
 fn sol_create_program_address(
     _context: &mut SyscallContext,
     seed_ptr: u64,
@@ -1340,6 +1503,7 @@ fn sol_invoke_signed_c(
             vm.stack.insert(0, p);
 
             let res = vm.execute(&instruction.accounts, &instruction.data);
+
             assert!(matches!(res, StableResult::Ok(0)), "external call failed");
 
             let refs = context.refs.try_borrow_mut().unwrap();
@@ -1388,12 +1552,39 @@ impl VirtualMachine {
             .register_function(b"sol_log_64_", sol_log_u64)
             .unwrap();
 
+        loader
+            .register_function(b"sol_log_compute_units_", sol_log_compute_units_)
+            .unwrap();
+
+        loader
+            .register_function(b"sol_remaining_compute_units", sol_remaining_compute_units)
+            .unwrap();
+
+        loader
+            .register_function(b"sol_get_clock_sysvar", sol_get_clock_sysvar)
+            .unwrap();
+
+        loader
+            .register_function(b"sol_get_rent_sysvar", sol_get_rent_sysvar)
+            .unwrap();
+
+        loader
+            .register_function(
+                b"sol_get_epoch_schedule_sysvar",
+                sol_get_epoch_schedule_sysvar,
+            )
+            .unwrap();
+
         loader.register_function(b"sol_sha256", sol_sha256).unwrap();
 
         loader
             .register_function(b"sol_keccak256", sol_keccak256)
             .unwrap();
 
+        loader
+            .register_function(b"sol_secp256k1_recover", sol_secp256k1_recover)
+            .unwrap();
+
         loader
             .register_function(b"sol_create_program_address", sol_create_program_address)
             .unwrap();
@@ -1,8 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{build_solidity, BorshToken};
-use base58::{FromBase58, ToBase58};
+use crate::{build_solidity, BorshToken, VirtualMachineBuilder};
+use base58::ToBase58;
 use num_bigint::BigInt;
+use solang::codegen::Options;
 
 #[test]
 fn builtins() {
@@ -18,6 +19,12 @@ fn builtins() {
             function mr_blocknumber() public returns (uint64) {
                 return block.number;
             }
+            function mr_epoch() public returns (uint64) {
+                return block.epoch;
+            }
+            function mr_slots_per_epoch() public returns (uint64) {
+                return block.slots_per_epoch;
+            }
             function msg_data(uint32 x) public returns (bytes) {
                 return msg.data;
             }
@@ -35,17 +42,7 @@ fn builtins() {
         .accounts(vec![("dataAccount", data_account)])
         .call();
 
-    let clock_account = <[u8; 32]>::try_from(
-        "SysvarC1ock11111111111111111111111111111111"
-            .from_base58()
-            .unwrap(),
-    )
-    .unwrap();
-    let returns = vm
-        .function("mr_now")
-        .accounts(vec![("clock", clock_account)])
-        .call()
-        .unwrap();
+    let returns = vm.function("mr_now").call().unwrap();
 
     assert_eq!(
         returns,
@@ -55,11 +52,7 @@ fn builtins() {
         }
     );
 
-    let returns = vm
-        .function("mr_slot")
-        .accounts(vec![("clock", clock_account)])
-        .call()
-        .unwrap();
+    let returns = vm.function("mr_slot").call().unwrap();
 
     assert_eq!(
         returns,
@@ -69,11 +62,7 @@ fn builtins() {
         }
     );
 
-    let returns = vm
-        .function("mr_blocknumber")
-        .accounts(vec![("clock", clock_account)])
-        .call()
-        .unwrap();
+    let returns = vm.function("mr_blocknumber").call().unwrap();
 
     assert_eq!(
         returns,
@@ -83,6 +72,26 @@ fn builtins() {
         },
     );
 
+    let returns = vm.function("mr_epoch").call().unwrap();
+
+    assert_eq!(
+        returns,
+        BorshToken::Uint {
+            width: 64,
+            value: BigInt::from(102u64)
+        },
+    );
+
+    let returns = vm.function("mr_slots_per_epoch").call().unwrap();
+
+    assert_eq!(
+        returns,
+        BorshToken::Uint {
+            width: 64,
+            value: BigInt::from(432000u64)
+        },
+    );
+
     let returns = vm
         .function("msg_data")
         .arguments(&[BorshToken::Uint {
@@ -117,6 +126,66 @@ fn builtins() {
     assert_eq!(returns, BorshToken::Address(vm.stack[0].id));
 }
 
+#[test]
+fn rent() {
+    let mut vm = build_solidity(
+        r#"
+        contract rent {
+            function minimum(uint64 space) public view returns (uint64) {
+                return minimumBalance(space);
+            }
+
+            function exempt(uint64 lamports, uint64 space) public view returns (bool) {
+                return isRentExempt(lamports, space);
+            }
+        }"#,
+    );
+
+    let data_account = vm.initialize_data_account();
+    vm.function("new")
+        .accounts(vec![("dataAccount", data_account)])
+        .call();
+
+    // (128 + space) * 3480 lamports per byte year, for two years
+    for (space, lamports) in [(0u64, 890880u64), (165, 2039280), (1024, 8017920)] {
+        let returns = vm
+            .function("minimum")
+            .arguments(&[BorshToken::Uint {
+                width: 64,
+                value: BigInt::from(space),
+            }])
+            .call()
+            .unwrap();
+
+        assert_eq!(
+            returns,
+            BorshToken::Uint {
+                width: 64,
+                value: BigInt::from(lamports),
+            }
+        );
+    }
+
+    for (lamports, exempt) in [(2039279u64, false), (2039280, true), (5000000, true)] {
+        let returns = vm
+            .function("exempt")
+            .arguments(&[
+                BorshToken::Uint {
+                    width: 64,
+                    value: BigInt::from(lamports),
+                },
+                BorshToken::Uint {
+                    width: 64,
+                    value: BigInt::from(165u8),
+                },
+            ])
+            .call()
+            .unwrap();
+
+        assert_eq!(returns, BorshToken::Bool(exempt));
+    }
+}
+
 #[test]
 fn pda() {
     let mut vm = build_solidity(
@@ -319,3 +388,55 @@ fn out_of_bounds_string_write() {
         .call();
     let _ = vm.function("testStringOut").call();
 }
+
+#[test]
+fn compute_units() {
+    let src = r#"
+    contract Metered {
+        function consumed() public view returns (uint64) {
+            uint64 before = remainingComputeUnits();
+            logComputeUnits();
+            return before - remainingComputeUnits();
+        }
+    }
+        "#;
+
+    let mut vm = build_solidity(src);
+
+    let data_account = vm.initialize_data_account();
+    vm.function("new")
+        .accounts(vec![("dataAccount", data_account)])
+        .call();
+
+    let returns = vm
+        .function("consumed")
+        .call()
+        .unwrap()
+        .into_bigint()
+        .unwrap();
+
+    // the syscall itself and the code around it must use some compute units
+    assert!(returns > BigInt::from(0u8));
+    assert!(returns < BigInt::from(1000u16));
+    assert!(vm.logs.starts_with("Program consumption: "));
+    assert!(vm.logs.ends_with(" units remaining"));
+    assert!(!vm.logs.contains("compute_units: "));
+
+    let mut vm = VirtualMachineBuilder::new(src)
+        .opts(Options {
+            log_runtime_errors: true,
+            log_compute_units: true,
+            ..Default::default()
+        })
+        .build();
+
+    let data_account = vm.initialize_data_account();
+    vm.function("new")
+        .accounts(vec![("dataAccount", data_account)])
+        .call();
+
+    vm.logs.clear();
+    vm.function("consumed").call();
+
+    assert!(vm.logs.contains("compute_units: consumed() consumed "));
+}
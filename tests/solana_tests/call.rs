@@ -129,6 +129,61 @@ fn external_call_with_returns() {
     );
 }
 
+#[test]
+fn try_catch_external_call() {
+    let mut vm = build_solidity(
+        r#"
+        contract bar0 {
+            @account(pid)
+            function test_other(int64 y) external returns (int64) {
+                try bar1.test_bar{program_id: tx.accounts.pid.key}(y) returns (int64 v) {
+                    return v;
+                } catch {
+                    // never executed, a failed call aborts the transaction
+                    return -1;
+                }
+            }
+        }
+
+        contract bar1 {
+            function test_bar(int64 y) public pure returns (int64) {
+                return 3 + y;
+            }
+        }"#,
+    );
+
+    let bar1_account = vm.initialize_data_account();
+    let bar1_program_id = vm.stack[0].id;
+    vm.function("new")
+        .accounts(vec![("dataAccount", bar1_account)])
+        .call();
+
+    vm.set_program(0);
+
+    let bar0_account = vm.initialize_data_account();
+    vm.function("new")
+        .accounts(vec![("dataAccount", bar0_account)])
+        .call();
+
+    let res = vm
+        .function("test_other")
+        .arguments(&[BorshToken::Int {
+            width: 64,
+            value: BigInt::from(7),
+        }])
+        .accounts(vec![("pid", bar1_program_id), ("systemProgram", [0; 32])])
+        .call()
+        .unwrap();
+
+    assert_eq!(
+        res,
+        BorshToken::Int {
+            width: 64,
+            value: BigInt::from(10u8)
+        }
+    );
+}
+
 #[test]
 fn external_raw_call_with_returns() {
     let mut vm = build_solidity(
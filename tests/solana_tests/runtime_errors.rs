@@ -327,3 +327,77 @@ contract calle_contract {
     );
     assert!(vm.return_data.is_none());
 }
+
+#[test]
+fn custom_errors() {
+    let mut vm = build_solidity(
+        r#"
+contract Bank {
+    error Unauthorized();
+    error InsufficientBalance(uint64 available, uint64 required);
+
+    function withdraw(uint64 amount, uint64 balance) public pure returns (uint64) {
+        require(amount <= balance, InsufficientBalance(balance, amount));
+
+        return balance - amount;
+    }
+
+    function close() public pure {
+        revert Unauthorized();
+    }
+}"#,
+    );
+
+    vm.set_program(0);
+    let data_account = vm.initialize_data_account();
+    vm.function("new")
+        .accounts(vec![("dataAccount", data_account)])
+        .call();
+
+    let returns = vm
+        .function("withdraw")
+        .arguments(&[
+            BorshToken::Uint {
+                width: 64,
+                value: BigInt::from(10u8),
+            },
+            BorshToken::Uint {
+                width: 64,
+                value: BigInt::from(15u8),
+            },
+        ])
+        .call()
+        .unwrap();
+    assert_eq!(
+        returns,
+        BorshToken::Uint {
+            width: 64,
+            value: BigInt::from(5u8),
+        }
+    );
+
+    // The error code and fields are returned Borsh encoded
+    let res = vm
+        .function("withdraw")
+        .arguments(&[
+            BorshToken::Uint {
+                width: 64,
+                value: BigInt::from(20u8),
+            },
+            BorshToken::Uint {
+                width: 64,
+                value: BigInt::from(15u8),
+            },
+        ])
+        .must_fail();
+    assert_eq!(res.unwrap(), 6001);
+
+    let mut expected = 6001u32.to_le_bytes().to_vec();
+    expected.extend_from_slice(&15u64.to_le_bytes());
+    expected.extend_from_slice(&20u64.to_le_bytes());
+    assert_eq!(vm.return_data.as_ref().unwrap().1, expected);
+
+    let res = vm.function("close").must_fail();
+    assert_eq!(res.unwrap(), 6000);
+    assert_eq!(vm.return_data.as_ref().unwrap().1, 6000u32.to_le_bytes());
+}
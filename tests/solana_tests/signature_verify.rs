@@ -3,6 +3,7 @@
 use crate::{build_solidity, Account, AccountState, BorshToken};
 use base58::FromBase58;
 use ed25519_dalek::{Signature, Signer, SigningKey};
+use num_bigint::BigInt;
 use rand::rngs::OsRng;
 use serde_derive::Serialize;
 use std::convert::TryInto;
@@ -154,6 +155,59 @@ fn verify() {
     assert_eq!(returns, BorshToken::Bool(false));
 }
 
+#[test]
+fn ecrecover() {
+    let mut vm = build_solidity(
+        r#"
+        contract Recover {
+            function signer(bytes32 hash, uint8 v, bytes32 r, bytes32 s) public pure returns (bytes20) {
+                return ecrecover(hash, v, r, s);
+            }
+        }"#,
+    );
+
+    let data_account = vm.initialize_data_account();
+    vm.function("new")
+        .accounts(vec![("dataAccount", data_account)])
+        .call();
+
+    let hash =
+        hex::decode("7b438b8a4eebc0b8d79f8188f8d5ab3b7958105a9d3ac58c12e3cf4c3b4c548b").unwrap();
+    let r =
+        hex::decode("9804f8f7593e9ad2d06f5cd88f69ad3b3d48b486e63d4968901ba463bc648200").unwrap();
+    let s =
+        hex::decode("70a8faa3826a18518743b655a0d24b54e9cea0b84a70edeec304d53b41e8d25b").unwrap();
+    let signer = hex::decode("4a62316623ad457f02cdc5d997ded67a383ec569").unwrap();
+
+    let mut recover = |v: u8, r: &[u8]| {
+        vm.function("signer")
+            .arguments(&[
+                BorshToken::FixedBytes(hash.clone()),
+                BorshToken::Uint {
+                    width: 8,
+                    value: BigInt::from(v),
+                },
+                BorshToken::FixedBytes(r.to_vec()),
+                BorshToken::FixedBytes(s.clone()),
+            ])
+            .call()
+            .unwrap()
+            .into_fixed_bytes()
+            .unwrap()
+    };
+
+    assert_eq!(recover(28, &r), signer);
+
+    // the recovery id without the ethereum offset works too
+    assert_eq!(recover(1, &r), signer);
+
+    // the wrong recovery id gives a different key
+    assert_ne!(recover(27, &r), signer);
+
+    // recovery fails for an invalid signature
+    assert_eq!(recover(28, &[0u8; 32]), vec![0; 20]);
+}
+
 fn encode_instructions(public_key: &[u8], signature: &[u8], message: &[u8]) -> Vec<u8> {
     let offsets = Ed25519SignatureOffsets {
         num_signatures: 1,
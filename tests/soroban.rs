@@ -17,6 +17,15 @@ pub struct SorobanEnv {
 }
 
 pub fn build_solidity(src: &str) -> SorobanEnv {
+    SorobanEnv::new_with_contract(build_wasm(src))
+}
+
+pub fn build_wasm(src: &str) -> Vec<u8> {
+    build_wasm_with_native_token(src, None)
+}
+
+/// Build a contract which uses the given contract as the native token
+pub fn build_wasm_with_native_token(src: &str, native_token: Option<[u8; 32]>) -> Vec<u8> {
     let tmp_file = OsStr::new("test.sol");
     let mut cache = FileResolver::default();
     cache.set_file_contents(tmp_file.to_str().unwrap(), src.to_string());
@@ -33,6 +42,7 @@ pub fn build_solidity(src: &str) -> SorobanEnv {
             #[cfg(feature = "wasm_opt")]
             wasm_opt: Some(contract_build::OptimizationPasses::Z),
             soroban_version: Some(85899345977),
+            soroban_native_token: native_token,
             ..Default::default()
         },
         std::vec!["unknown".to_string()],
@@ -40,8 +50,7 @@ pub fn build_solidity(src: &str) -> SorobanEnv {
     );
     ns.print_diagnostics_in_plain(&cache, false);
     assert!(!wasm.is_empty());
-    let wasm_blob = wasm[0].0.clone();
-    SorobanEnv::new_with_contract(wasm_blob)
+    wasm[0].0.clone()
 }
 
 impl SorobanEnv {
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::build_solidity;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
+use soroban_sdk::{Address, IntoVal, Symbol, Val};
+
+#[test]
+fn require_auth() {
+    let src = build_solidity(
+        r#"contract auth {
+            uint64 public count;
+
+            function increment(address owner) public {
+                owner.requireAuth();
+                count += 1;
+            }
+
+            function add(address owner, uint64 amount) public {
+                owner.requireAuthForArgs(amount);
+                count += amount;
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+    let owner = Address::random(&src.env);
+
+    // no authorization has been given yet
+    src.invoke_contract_expect_error(addr, "increment", vec![owner.into_val(&src.env)]);
+
+    src.env.mock_all_auths();
+
+    src.invoke_contract(addr, "increment", vec![owner.into_val(&src.env)]);
+
+    assert_eq!(
+        src.env.auths(),
+        std::vec![(
+            owner.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    addr.clone(),
+                    Symbol::new(&src.env, "increment"),
+                    (owner.clone(),).into_val(&src.env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+
+    src.invoke_contract(
+        addr,
+        "add",
+        vec![owner.into_val(&src.env), 5_u64.into_val(&src.env)],
+    );
+
+    // only the arguments given to requireAuthForArgs are authorized
+    assert_eq!(
+        src.env.auths(),
+        std::vec![(
+            owner.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    addr.clone(),
+                    Symbol::new(&src.env, "add"),
+                    (5_u64,).into_val(&src.env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+
+    let res = src.invoke_contract(addr, "count", vec![]);
+    let expected: Val = 6_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+}
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::build_solidity;
+use soroban_sdk::{IntoVal, Val};
+
+#[test]
+fn create_and_call() {
+    let runtime = build_solidity(
+        r#"contract caller {
+            adder child;
+
+            function deploy() public {
+                child = new adder();
+            }
+
+            function add(uint64 a, uint64 b) public returns (uint64) {
+                return child.add(a, b);
+            }
+
+            function count() public returns (uint64) {
+                return child.count();
+            }
+        }
+
+        contract adder {
+            uint64 public count = 100;
+
+            function add(uint64 a, uint64 b) public returns (uint64) {
+                count += 1;
+                return a + b;
+            }
+        }"#,
+    );
+
+    let addr = runtime.contracts.last().unwrap();
+
+    runtime.invoke_contract(addr, "deploy", vec![]);
+
+    // the storage initializer of the new contract has run
+    let res = runtime.invoke_contract(addr, "count", vec![]);
+    let expected: Val = 100_u64.into_val(&runtime.env);
+    assert!(expected.shallow_eq(&res));
+
+    let res = runtime.invoke_contract(
+        addr,
+        "add",
+        vec![5_u64.into_val(&runtime.env), 7_u64.into_val(&runtime.env)],
+    );
+    let expected: Val = 12_u64.into_val(&runtime.env);
+    assert!(expected.shallow_eq(&res));
+
+    let res = runtime.invoke_contract(addr, "count", vec![]);
+    let expected: Val = 101_u64.into_val(&runtime.env);
+    assert!(expected.shallow_eq(&res));
+}
+
+#[test]
+fn constructor() {
+    let runtime = build_solidity(
+        r#"contract caller {
+            adder child;
+
+            function deploy(uint64 start) public {
+                child = new adder(start, 5);
+            }
+
+            function count() public returns (uint64) {
+                return child.count();
+            }
+        }
+
+        contract adder {
+            uint64 public count = 100;
+
+            constructor(uint64 start, uint64 step) {
+                count += start * step;
+            }
+        }"#,
+    );
+
+    let addr = runtime.contracts.last().unwrap();
+
+    runtime.invoke_contract(addr, "deploy", vec![3_u64.into_val(&runtime.env)]);
+
+    // the storage initializer runs before the constructor
+    let res = runtime.invoke_contract(addr, "count", vec![]);
+    let expected: Val = 115_u64.into_val(&runtime.env);
+    assert!(expected.shallow_eq(&res));
+}
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::build_solidity;
+use ed25519_dalek::{Signer, SigningKey};
+use sha2::{Digest, Sha256};
+use soroban_sdk::{Bytes, BytesN, IntoVal, Symbol, TryFromVal, Val};
+use tiny_keccak::{Hasher, Keccak};
+
+#[test]
+fn hashes() {
+    let src = build_solidity(
+        r#"contract hasher {
+            function sha(bytes data) public pure returns (bytes32) {
+                return sha256(data);
+            }
+
+            function keccak(bytes data) public pure returns (bytes32) {
+                return keccak256(data);
+            }
+
+            function ripemd(bytes data) public pure returns (bytes20) {
+                return ripemd160(data);
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+    let data = b"hello soroban";
+    let arg: Val = Bytes::from_slice(&src.env, data).into_val(&src.env);
+
+    let res = src.invoke_contract(addr, "sha", vec![arg]);
+    let res = BytesN::<32>::try_from_val(&src.env, &res).unwrap();
+    assert_eq!(res.to_array(), <[u8; 32]>::from(Sha256::digest(data)));
+
+    let mut hasher = Keccak::v256();
+    let mut hash = [0u8; 32];
+    hasher.update(data);
+    hasher.finalize(&mut hash);
+
+    let res = src.invoke_contract(addr, "keccak", vec![arg]);
+    let res = BytesN::<32>::try_from_val(&src.env, &res).unwrap();
+    assert_eq!(res.to_array(), hash);
+
+    let res = src.invoke_contract(addr, "ripemd", vec![arg]);
+    let res = BytesN::<20>::try_from_val(&src.env, &res).unwrap();
+    assert_eq!(
+        hex::encode(res.to_array()),
+        "d48ae480c9daa192c5371f548c0a9ea76df7125f"
+    );
+}
+
+#[test]
+fn signature_verify() {
+    let src = build_solidity(
+        r#"contract verifier {
+            function verify(bytes32 key, bytes message, bytes signature) public pure returns (bool) {
+                return signatureVerify(key, message, signature);
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+    let keypair = SigningKey::from_bytes(&[9; 32]);
+    let message = b"hello soroban";
+    let signature = keypair.sign(message).to_bytes();
+
+    let args = |signature: &[u8; 64]| -> soroban_sdk::Vec<Val> {
+        soroban_sdk::vec![
+            &src.env,
+            BytesN::from_array(&src.env, keypair.verifying_key().as_bytes()).into_val(&src.env),
+            Bytes::from_slice(&src.env, message).into_val(&src.env),
+            Bytes::from_slice(&src.env, signature).into_val(&src.env),
+        ]
+    };
+
+    let verify = Symbol::new(&src.env, "verify");
+
+    let res = src
+        .env
+        .try_invoke_contract::<bool, Val>(addr, &verify, args(&signature));
+    assert!(matches!(res, Ok(Ok(true))));
+
+    // the host aborts if the signature does not match
+    let mut bad_signature = signature;
+    bad_signature[0] ^= 1;
+
+    let res = src
+        .env
+        .try_invoke_contract::<bool, Val>(addr, &verify, args(&bad_signature));
+    assert!(res.is_err());
+}
+
+#[test]
+fn ecrecover() {
+    let src = build_solidity(
+        r#"contract recover {
+            function signer() public pure returns (bytes20) {
+                return ecrecover(
+                    hex"7b438b8a4eebc0b8d79f8188f8d5ab3b7958105a9d3ac58c12e3cf4c3b4c548b",
+                    28,
+                    hex"9804f8f7593e9ad2d06f5cd88f69ad3b3d48b486e63d4968901ba463bc648200",
+                    hex"70a8faa3826a18518743b655a0d24b54e9cea0b84a70edeec304d53b41e8d25b"
+                );
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+
+    let res = src.invoke_contract(addr, "signer", vec![]);
+    let res = BytesN::<20>::try_from_val(&src.env, &res).unwrap();
+    assert_eq!(
+        hex::encode(res.to_array()),
+        "4a62316623ad457f02cdc5d997ded67a383ec569"
+    );
+}
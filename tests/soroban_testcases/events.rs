@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::build_solidity;
+use soroban_sdk::testutils::Events;
+use soroban_sdk::{IntoVal, Symbol, Val, Vec};
+
+#[test]
+fn emit() {
+    let src = build_solidity(
+        r#"contract token {
+            event Transfer(uint64 indexed from, uint64 indexed to, uint64 amount);
+            event Approval(uint64 indexed owner, uint64 spender, uint64 amount);
+
+            function transfer(uint64 from, uint64 to, uint64 amount) public {
+                emit Transfer(from, to, amount);
+            }
+
+            function approve(uint64 owner, uint64 spender, uint64 amount) public {
+                emit Approval(owner, spender, amount);
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+
+    src.invoke_contract(
+        addr,
+        "transfer",
+        vec![
+            1_u64.into_val(&src.env),
+            2_u64.into_val(&src.env),
+            100_u64.into_val(&src.env),
+        ],
+    );
+
+    let events = src.env.events().all();
+    let (contract, topics, data) = events.last().unwrap();
+    assert_eq!(&contract, addr);
+
+    let expected: [Val; 3] = [
+        Symbol::new(&src.env, "Transfer").into_val(&src.env),
+        1_u64.into_val(&src.env),
+        2_u64.into_val(&src.env),
+    ];
+    assert_eq!(topics.len(), 3);
+    for (topic, expected) in topics.iter().zip(expected.iter()) {
+        assert!(expected.shallow_eq(&topic));
+    }
+
+    // a single data field is not wrapped in a vec
+    let expected: Val = 100_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&data));
+
+    src.invoke_contract(
+        addr,
+        "approve",
+        vec![
+            1_u64.into_val(&src.env),
+            3_u64.into_val(&src.env),
+            50_u64.into_val(&src.env),
+        ],
+    );
+
+    let events = src.env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+
+    let expected: [Val; 2] = [
+        Symbol::new(&src.env, "Approval").into_val(&src.env),
+        1_u64.into_val(&src.env),
+    ];
+    assert_eq!(topics.len(), 2);
+    for (topic, expected) in topics.iter().zip(expected.iter()) {
+        assert!(expected.shallow_eq(&topic));
+    }
+
+    let data: Vec<u64> = data.into_val(&src.env);
+    assert_eq!(data.len(), 2);
+    assert_eq!(data.get(0), Some(3));
+    assert_eq!(data.get(1), Some(50));
+}
@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{build_solidity, build_wasm, build_wasm_with_native_token, SorobanEnv};
+use sha2::{Digest, Sha256};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+use soroban_sdk::xdr::{
+    Asset, ContractIdPreimage, Hash, HashIdPreimage, HashIdPreimageContractId, ScAddress, WriteXdr,
+};
+use soroban_sdk::{Address, BytesN, IntoVal, TryFromVal, Val};
+
+static PAYER: &str = r#"contract payer {
+    function pay(address to, uint128 amount) public {
+        payable(to).transfer(amount);
+    }
+
+    function try_pay(address to, uint128 amount) public returns (bool) {
+        return payable(to).send(amount);
+    }
+}"#;
+
+#[test]
+fn block_properties() {
+    let src = build_solidity(
+        r#"contract ledger {
+            function timestamp() public view returns (uint64) {
+                return block.timestamp;
+            }
+
+            function sequence() public view returns (uint64) {
+                return block.number;
+            }
+
+            function this_address() public view returns (address) {
+                return address(this);
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+
+    src.env.ledger().with_mut(|ledger| {
+        ledger.timestamp = 1_700_000_000;
+        ledger.sequence_number = 12345;
+    });
+
+    let res = src.invoke_contract(addr, "timestamp", vec![]);
+    let expected: Val = 1_700_000_000_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let res = src.invoke_contract(addr, "sequence", vec![]);
+    let expected: Val = 12345_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let res = src.invoke_contract(addr, "this_address", vec![]);
+    let res = Address::try_from_val(&src.env, &res).unwrap();
+    assert_eq!(&res, addr);
+}
+
+#[test]
+fn value_transfer() {
+    let mut runtime = SorobanEnv::new();
+
+    runtime.env.mock_all_auths();
+
+    let admin = Address::random(&runtime.env);
+    let token = runtime.env.register_stellar_asset_contract(admin);
+
+    let ScAddress::Contract(Hash(token_id)) = ScAddress::try_from(&token).unwrap() else {
+        panic!("token should be a contract");
+    };
+
+    let addr = runtime.register_contract(build_wasm_with_native_token(PAYER, Some(token_id)));
+
+    StellarAssetClient::new(&runtime.env, &token).mint(&addr, &1000);
+
+    let balances = TokenClient::new(&runtime.env, &token);
+    let to = Address::random(&runtime.env);
+
+    runtime.invoke_contract(
+        &addr,
+        "pay",
+        vec![to.into_val(&runtime.env), 300_u128.into_val(&runtime.env)],
+    );
+
+    assert_eq!(balances.balance(&addr), 700);
+    assert_eq!(balances.balance(&to), 300);
+
+    let res = runtime.invoke_contract(
+        &addr,
+        "try_pay",
+        vec![to.into_val(&runtime.env), 200_u128.into_val(&runtime.env)],
+    );
+    let expected: Val = true.into_val(&runtime.env);
+    assert!(expected.shallow_eq(&res));
+
+    // not enough balance
+    let res = runtime.invoke_contract(
+        &addr,
+        "try_pay",
+        vec![to.into_val(&runtime.env), 1000_u128.into_val(&runtime.env)],
+    );
+    let expected: Val = false.into_val(&runtime.env);
+    assert!(expected.shallow_eq(&res));
+
+    runtime.invoke_contract_expect_error(
+        &addr,
+        "pay",
+        vec![to.into_val(&runtime.env), 1000_u128.into_val(&runtime.env)],
+    );
+
+    assert_eq!(balances.balance(&addr), 500);
+    assert_eq!(balances.balance(&to), 500);
+}
+
+#[test]
+fn default_native_token() {
+    let mut runtime = SorobanEnv::new();
+
+    // Without a configured native token, the Stellar Asset Contract for the native asset of
+    // the network is used. Register a stand-in at its address.
+    let preimage = HashIdPreimage::ContractId(HashIdPreimageContractId {
+        network_id: Hash(runtime.env.ledger().network_id().to_array()),
+        contract_id_preimage: ContractIdPreimage::Asset(Asset::Native),
+    });
+
+    let token_id: [u8; 32] = Sha256::digest(preimage.to_xdr().unwrap()).into();
+    let token = Address::from_contract_id(&BytesN::from_array(&runtime.env, &token_id));
+
+    let native = build_wasm(
+        r#"contract native {
+            address public sender;
+            address public recipient;
+            int128 public total;
+
+            function transfer(address from, address to, int128 amount) public {
+                sender = from;
+                recipient = to;
+                total += amount;
+            }
+        }"#,
+    );
+
+    runtime
+        .env
+        .register_contract_wasm(&token, native.as_slice());
+
+    let addr = runtime.register_contract(build_wasm(PAYER));
+    let to = Address::random(&runtime.env);
+
+    runtime.invoke_contract(
+        &addr,
+        "pay",
+        vec![to.into_val(&runtime.env), 300_u128.into_val(&runtime.env)],
+    );
+
+    let res = runtime.invoke_contract(&token, "sender", vec![]);
+    assert_eq!(Address::try_from_val(&runtime.env, &res).unwrap(), addr);
+
+    let res = runtime.invoke_contract(&token, "recipient", vec![]);
+    assert_eq!(Address::try_from_val(&runtime.env, &res).unwrap(), to);
+
+    let res = runtime.invoke_contract(&token, "total", vec![]);
+    assert_eq!(i128::try_from_val(&runtime.env, &res).unwrap(), 300);
+}
@@ -1,7 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::build_solidity;
-use soroban_sdk::{IntoVal, Val};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, IntoVal, Symbol, Val, U256};
 
 #[test]
 fn math() {
@@ -73,3 +74,170 @@ fn math_same_name() {
     let expected: Val = 6_u64.into_val(&src.env);
     assert!(expected.shallow_eq(&res));
 }
+
+#[test]
+fn wide_integers() {
+    let src = build_solidity(
+        r#"contract wide {
+            function add(int128 a, int128 b) public returns (int128) {
+                return a + b;
+            }
+
+            function mul(uint256 a, uint256 b) public returns (uint256) {
+                return a * b;
+            }
+
+            function narrow(uint8 a) public returns (uint8) {
+                return a;
+            }
+
+            function narrow96(int96 a) public returns (int96) {
+                return a;
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+
+    // small values are stored in the body of the Val
+    let res = src.invoke_contract(
+        addr,
+        "add",
+        vec![5_i128.into_val(&src.env), (-7_i128).into_val(&src.env)],
+    );
+    let res: i128 = res.into_val(&src.env);
+    assert_eq!(res, -2);
+
+    // large values are host objects
+    let res = src.invoke_contract(
+        addr,
+        "add",
+        vec![
+            (-(1_i128 << 100)).into_val(&src.env),
+            (1_i128 << 70).into_val(&src.env),
+        ],
+    );
+    let res: i128 = res.into_val(&src.env);
+    assert_eq!(res, -(1_i128 << 100) + (1_i128 << 70));
+
+    let res = src.invoke_contract(
+        addr,
+        "mul",
+        vec![
+            U256::from_parts(&src.env, 0, 0, u64::MAX, u64::MAX).into_val(&src.env),
+            U256::from_u32(&src.env, 16).into_val(&src.env),
+        ],
+    );
+    let res: U256 = res.into_val(&src.env);
+    assert_eq!(
+        res,
+        U256::from_parts(&src.env, 0, 15, u64::MAX, u64::MAX - 15)
+    );
+
+    let res = src.invoke_contract(addr, "narrow", vec![200_u32.into_val(&src.env)]);
+    let expected: Val = 200_u32.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    // values which do not fit in the parameter type trap
+    let narrow = Symbol::new(&src.env, "narrow");
+    let res = src.env.try_invoke_contract::<Val, Val>(
+        addr,
+        &narrow,
+        soroban_sdk::vec![&src.env, 300_u32.into_val(&src.env)],
+    );
+    assert!(res.is_err());
+
+    // as do values of another type
+    let res = src.env.try_invoke_contract::<Val, Val>(
+        addr,
+        &narrow,
+        soroban_sdk::vec![&src.env, 200_i32.into_val(&src.env)],
+    );
+    assert!(res.is_err());
+
+    let narrow96 = Symbol::new(&src.env, "narrow96");
+    let res = src.env.try_invoke_contract::<Val, Val>(
+        addr,
+        &narrow96,
+        soroban_sdk::vec![&src.env, (1_i128 << 100).into_val(&src.env)],
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn address() {
+    let src = build_solidity(
+        r#"contract addresses {
+            function echo(address a) public returns (address) {
+                return a;
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+
+    let res = src.invoke_contract(addr, "echo", vec![addr.into_val(&src.env)]);
+    let res: Address = res.into_val(&src.env);
+    assert_eq!(&res, addr);
+}
+
+#[test]
+fn address_compare() {
+    let src = build_solidity(
+        r#"contract owned {
+            address owner;
+
+            function set_owner(address a) public {
+                owner = a;
+            }
+
+            function is_owner(address a) public view returns (bool) {
+                return a == owner;
+            }
+
+            function is_other(address a) public view returns (bool) {
+                return a != owner;
+            }
+
+            function only_owner(address a) public view returns (uint64) {
+                require(a == owner);
+                return 1;
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+    let owner = Address::random(&src.env);
+    let other = Address::random(&src.env);
+
+    src.invoke_contract(addr, "set_owner", vec![owner.into_val(&src.env)]);
+
+    // addresses are compared by value, not by the handle of their host object
+    let res = src.invoke_contract(addr, "is_owner", vec![owner.into_val(&src.env)]);
+    let expected: Val = true.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let res = src.invoke_contract(addr, "is_owner", vec![other.into_val(&src.env)]);
+    let expected: Val = false.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let res = src.invoke_contract(addr, "is_other", vec![owner.into_val(&src.env)]);
+    let expected: Val = false.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let res = src.invoke_contract(addr, "is_other", vec![other.into_val(&src.env)]);
+    let expected: Val = true.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let res = src.invoke_contract(addr, "only_owner", vec![owner.into_val(&src.env)]);
+    let expected: Val = 1_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let only_owner = Symbol::new(&src.env, "only_owner");
+    let res = src.env.try_invoke_contract::<Val, Val>(
+        addr,
+        &only_owner,
+        soroban_sdk::vec![&src.env, other.into_val(&src.env)],
+    );
+    assert!(res.is_err());
+}
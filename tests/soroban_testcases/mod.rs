@@ -1,4 +1,10 @@
 // SPDX-License-Identifier: Apache-2.0
+mod auth;
+mod cross_contract_calls;
+mod crypto;
+mod events;
+mod ledger;
 mod math;
 mod print;
+mod spec;
 mod storage;
@@ -2,6 +2,7 @@
 
 use crate::build_solidity;
 use soroban_sdk::testutils::Logs;
+use soroban_sdk::{IntoVal, Val};
 
 #[test]
 fn log_runtime_error() {
@@ -75,3 +76,48 @@ fn print_then_runtime_error() {
     assert!(logs[1].contains("Second call will FAIL!"));
     assert!(logs[2].contains("runtime_error: math overflow in test.sol:6:17-27"));
 }
+
+#[test]
+fn print_dynamic_string() {
+    let src = build_solidity(
+        r#"contract Printer {
+            function greet(uint64 n) public {
+                print("Hello, {}!".format(n));
+                print(string.concat("count is ", n > 1 ? "many" : "one"));
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+
+    src.invoke_contract(addr, "greet", vec![42_u64.into_val(&src.env)]);
+
+    let logs = src.env.logs().all();
+
+    assert!(logs[0].contains("Hello, 42!"));
+    assert!(logs[1].contains("count is many"));
+}
+
+#[test]
+fn require_reason() {
+    let src = build_solidity(
+        r#"contract Checker {
+            function check(uint64 n) public pure returns (uint64) {
+                require(n < 10, "n is {}".format(n));
+                return n;
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+
+    let res = src.invoke_contract(addr, "check", vec![5_u64.into_val(&src.env)]);
+    let expected: Val = 5_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let logs = src.invoke_contract_expect_error(addr, "check", vec![12_u64.into_val(&src.env)]);
+
+    assert!(logs
+        .iter()
+        .any(|log| log.contains("runtime_error: n is 12 require condition failed")));
+}
@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{build_wasm, SorobanEnv};
+use soroban_sdk::xdr::{
+    DepthLimitedRead, ReadXdr, ScSpecEntry, ScSpecTypeBytesN, ScSpecTypeDef, ScSpecTypeUdt,
+};
+use soroban_sdk::{Error, IntoVal, Symbol, Val};
+use wasmparser::{Parser, Payload};
+
+/// Read the spec entries from the custom section of a contract
+fn spec_entries(wasm: &[u8]) -> Vec<ScSpecEntry> {
+    let mut spec = Vec::new();
+
+    for payload in Parser::new(0).parse_all(wasm) {
+        if let Payload::CustomSection(section) = payload.unwrap() {
+            if section.name() == "contractspecv0" {
+                spec.extend_from_slice(section.data());
+            }
+        }
+    }
+
+    let mut read = DepthLimitedRead::new(spec.as_slice(), 1000);
+
+    ScSpecEntry::read_xdr_iter(&mut read)
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+fn udt(name: &str) -> ScSpecTypeDef {
+    ScSpecTypeDef::Udt(ScSpecTypeUdt {
+        name: name.try_into().unwrap(),
+    })
+}
+
+#[test]
+fn types() {
+    let wasm = build_wasm(
+        r#"contract shapes {
+            enum Color { Red, Green, Blue }
+
+            error Unauthorized(uint64 caller);
+
+            event Error(Color color);
+
+            function paint() public {
+                emit Error(Color.Red);
+            }
+
+            function info(
+                uint128 a,
+                int256 b,
+                bytes32 c,
+                bytes memory d,
+                string memory s,
+                address addr
+            ) public pure returns (Color) {
+                return Color.Green;
+            }
+        }"#,
+    );
+
+    let entries = spec_entries(&wasm);
+
+    let function = entries
+        .iter()
+        .find_map(|entry| match entry {
+            ScSpecEntry::FunctionV0(f) if f.name.to_string_lossy() == "info" => Some(f),
+            _ => None,
+        })
+        .unwrap();
+
+    let inputs: Vec<_> = function.inputs.iter().map(|i| i.type_.clone()).collect();
+    assert_eq!(
+        inputs,
+        vec![
+            ScSpecTypeDef::U128,
+            ScSpecTypeDef::I256,
+            ScSpecTypeDef::BytesN(ScSpecTypeBytesN { n: 32 }),
+            ScSpecTypeDef::Bytes,
+            ScSpecTypeDef::String,
+            ScSpecTypeDef::Address,
+        ]
+    );
+    assert_eq!(function.outputs.to_vec(), vec![udt("Color")]);
+
+    let color = entries
+        .iter()
+        .find_map(|entry| match entry {
+            ScSpecEntry::UdtEnumV0(e) => Some(e),
+            _ => None,
+        })
+        .unwrap();
+
+    assert_eq!(color.name.to_string_lossy(), "Color");
+    let cases: Vec<_> = color
+        .cases
+        .iter()
+        .map(|c| (c.name.to_string_lossy(), c.value))
+        .collect();
+    assert_eq!(
+        cases,
+        vec![
+            ("Red".to_string(), 0),
+            ("Green".to_string(), 1),
+            ("Blue".to_string(), 2)
+        ]
+    );
+
+    let errors = entries
+        .iter()
+        .find_map(|entry| match entry {
+            ScSpecEntry::UdtErrorEnumV0(e) => Some(e),
+            _ => None,
+        })
+        .unwrap();
+
+    assert_eq!(errors.cases.len(), 1);
+    assert_eq!(errors.cases[0].name.to_string_lossy(), "Unauthorized");
+    assert_eq!(errors.cases[0].value, 1);
+
+    // events are not described, so they cannot clash with the error enum
+    assert!(!entries
+        .iter()
+        .any(|entry| matches!(entry, ScSpecEntry::UdtStructV0(_))));
+}
+
+#[test]
+fn error_codes() {
+    let wasm = build_wasm(
+        r#"error Unused();
+
+        contract vault {
+            error Unauthorized(uint64 caller);
+            error Locked();
+
+            function withdraw(uint64 caller) public pure returns (uint64) {
+                if (caller == 0) {
+                    revert Locked();
+                }
+                if (caller != 1) {
+                    revert Unauthorized(caller);
+                }
+                return caller;
+            }
+        }"#,
+    );
+
+    // the errors of the contract are numbered from 1 onwards
+    let errors = spec_entries(&wasm)
+        .into_iter()
+        .find_map(|entry| match entry {
+            ScSpecEntry::UdtErrorEnumV0(e) => Some(e),
+            _ => None,
+        })
+        .unwrap();
+
+    let cases: Vec<_> = errors
+        .cases
+        .iter()
+        .map(|c| (c.name.to_string_lossy(), c.value))
+        .collect();
+    assert_eq!(
+        cases,
+        vec![("Unauthorized".to_string(), 1), ("Locked".to_string(), 2)]
+    );
+
+    // reverting with a custom error fails with a contract error carrying its code
+    let src = SorobanEnv::new_with_contract(wasm);
+    let addr = src.contracts.last().unwrap();
+    let withdraw = Symbol::new(&src.env, "withdraw");
+
+    for (caller, code) in [(0_u64, 2), (5, 1)] {
+        let res = src.env.try_invoke_contract::<u64, Error>(
+            addr,
+            &withdraw,
+            soroban_sdk::vec![&src.env, caller.into_val(&src.env)],
+        );
+        assert_eq!(res, Err(Ok(Error::from_contract_error(code))));
+    }
+
+    let res = src.invoke_contract(addr, "withdraw", vec![1_u64.into_val(&src.env)]);
+    let expected: Val = 1_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+}
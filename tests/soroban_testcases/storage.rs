@@ -1,7 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::build_solidity;
-use soroban_sdk::{IntoVal, Val};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, IntoVal, Val};
 
 #[test]
 fn counter() {
@@ -39,3 +40,384 @@ fn counter() {
     let expected: Val = 10_u64.into_val(&src.env);
     assert!(expected.shallow_eq(&res));
 }
+
+#[test]
+fn constructor() {
+    let src = build_solidity(
+        r#"contract counter {
+            uint64 public count = 10;
+
+            constructor(uint64 start) {
+                count += start;
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+
+    src.invoke_contract(addr, "init", vec![5_u64.into_val(&src.env)]);
+
+    let res = src.invoke_contract(addr, "count", vec![]);
+    let expected: Val = 15_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+}
+
+#[test]
+fn init_once() {
+    let src = build_solidity(
+        r#"contract counter {
+            uint64 public count = 10;
+
+            constructor(uint64 start) {
+                count += start;
+            }
+
+            function increment() public {
+                count += 1;
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+
+    src.invoke_contract(addr, "init", vec![5_u64.into_val(&src.env)]);
+    src.invoke_contract(addr, "increment", vec![]);
+
+    // a second init must fail, rather than reset the storage
+    src.invoke_contract_expect_error(addr, "init", vec![1_u64.into_val(&src.env)]);
+
+    let res = src.invoke_contract(addr, "count", vec![]);
+    let expected: Val = 16_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+}
+
+#[test]
+fn mapping() {
+    let src = build_solidity(
+        r#"contract balances {
+            mapping(uint64 => uint64) balance;
+            mapping(uint64 => mapping(uint64 => uint64)) allowance;
+
+            function set(uint64 owner, uint64 amount) public {
+                balance[owner] = amount;
+            }
+
+            function get(uint64 owner) public view returns (uint64) {
+                return balance[owner];
+            }
+
+            function approve(uint64 owner, uint64 spender, uint64 amount) public {
+                allowance[owner][spender] = amount;
+            }
+
+            function allowed(uint64 owner, uint64 spender) public view returns (uint64) {
+                return allowance[owner][spender];
+            }
+
+            function remove(uint64 owner) public {
+                delete balance[owner];
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+
+    src.invoke_contract(
+        addr,
+        "set",
+        vec![1_u64.into_val(&src.env), 100_u64.into_val(&src.env)],
+    );
+    src.invoke_contract(
+        addr,
+        "set",
+        vec![2_u64.into_val(&src.env), 200_u64.into_val(&src.env)],
+    );
+
+    let res = src.invoke_contract(addr, "get", vec![1_u64.into_val(&src.env)]);
+    let expected: Val = 100_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let res = src.invoke_contract(addr, "get", vec![2_u64.into_val(&src.env)]);
+    let expected: Val = 200_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    // missing keys read as zero
+    let res = src.invoke_contract(addr, "get", vec![3_u64.into_val(&src.env)]);
+    let expected: Val = 0_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    src.invoke_contract(addr, "remove", vec![1_u64.into_val(&src.env)]);
+    let res = src.invoke_contract(addr, "get", vec![1_u64.into_val(&src.env)]);
+    let expected: Val = 0_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    src.invoke_contract(
+        addr,
+        "approve",
+        vec![
+            1_u64.into_val(&src.env),
+            2_u64.into_val(&src.env),
+            50_u64.into_val(&src.env),
+        ],
+    );
+
+    let res = src.invoke_contract(
+        addr,
+        "allowed",
+        vec![1_u64.into_val(&src.env), 2_u64.into_val(&src.env)],
+    );
+    let expected: Val = 50_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let res = src.invoke_contract(
+        addr,
+        "allowed",
+        vec![2_u64.into_val(&src.env), 1_u64.into_val(&src.env)],
+    );
+    let expected: Val = 0_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+}
+
+#[test]
+fn dynamic_array() {
+    let src = build_solidity(
+        r#"contract array {
+            uint64[] values;
+
+            function push(uint64 value) public {
+                values.push(value);
+            }
+
+            function pop() public returns (uint64) {
+                return values.pop();
+            }
+
+            function length() public view returns (uint64) {
+                return values.length;
+            }
+
+            function get(uint64 index) public view returns (uint64) {
+                return values[index];
+            }
+
+            function set(uint64 index, uint64 value) public {
+                values[index] = value;
+            }
+
+            function clear() public {
+                delete values;
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+
+    for value in [10_u64, 20, 30] {
+        src.invoke_contract(addr, "push", vec![value.into_val(&src.env)]);
+    }
+
+    let res = src.invoke_contract(addr, "length", vec![]);
+    let expected: Val = 3_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    src.invoke_contract(
+        addr,
+        "set",
+        vec![1_u64.into_val(&src.env), 25_u64.into_val(&src.env)],
+    );
+
+    let res = src.invoke_contract(addr, "get", vec![1_u64.into_val(&src.env)]);
+    let expected: Val = 25_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let res = src.invoke_contract(addr, "pop", vec![]);
+    let expected: Val = 30_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let res = src.invoke_contract(addr, "length", vec![]);
+    let expected: Val = 2_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let logs = src.invoke_contract_expect_error(addr, "get", vec![2_u64.into_val(&src.env)]);
+    assert!(logs
+        .iter()
+        .any(|log| log.contains("array index out of bounds")));
+
+    src.invoke_contract(addr, "clear", vec![]);
+
+    let res = src.invoke_contract(addr, "length", vec![]);
+    let expected: Val = 0_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let logs = src.invoke_contract_expect_error(addr, "pop", vec![]);
+    assert!(logs
+        .iter()
+        .any(|log| log.contains("pop from empty storage array")));
+}
+
+#[test]
+fn string_and_bytes() {
+    let src = build_solidity(
+        r#"contract strings {
+            string name;
+            bytes data;
+
+            function set_name(uint64 n) public {
+                if (n == 1) {
+                    name = "soroban";
+                } else {
+                    name = "";
+                }
+            }
+
+            function name_length() public view returns (uint64) {
+                return uint64(bytes(name).length);
+            }
+
+            function name_is_soroban() public view returns (uint64) {
+                return name == "soroban" ? 1 : 0;
+            }
+
+            function push(uint64 b) public {
+                data.push(bytes1(uint8(b)));
+            }
+
+            function pop() public returns (uint64) {
+                uint8 b = uint8(data.pop());
+                return b;
+            }
+
+            function get(uint64 index) public view returns (uint64) {
+                uint8 b = uint8(data[index]);
+                return b;
+            }
+
+            function set(uint64 index, uint64 b) public {
+                data[index] = bytes1(uint8(b));
+            }
+
+            function data_length() public view returns (uint64) {
+                return data.length;
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+
+    let res = src.invoke_contract(addr, "name_length", vec![]);
+    let expected: Val = 0_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    src.invoke_contract(addr, "set_name", vec![1_u64.into_val(&src.env)]);
+
+    let res = src.invoke_contract(addr, "name_length", vec![]);
+    let expected: Val = 7_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let res = src.invoke_contract(addr, "name_is_soroban", vec![]);
+    let expected: Val = 1_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    src.invoke_contract(addr, "set_name", vec![0_u64.into_val(&src.env)]);
+
+    let res = src.invoke_contract(addr, "name_length", vec![]);
+    let expected: Val = 0_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    for b in [1_u64, 2, 3] {
+        src.invoke_contract(addr, "push", vec![b.into_val(&src.env)]);
+    }
+
+    let res = src.invoke_contract(addr, "data_length", vec![]);
+    let expected: Val = 3_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    src.invoke_contract(
+        addr,
+        "set",
+        vec![0_u64.into_val(&src.env), 0xff_u64.into_val(&src.env)],
+    );
+
+    let res = src.invoke_contract(addr, "get", vec![0_u64.into_val(&src.env)]);
+    let expected: Val = 0xff_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let res = src.invoke_contract(addr, "pop", vec![]);
+    let expected: Val = 3_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let res = src.invoke_contract(addr, "data_length", vec![]);
+    let expected: Val = 2_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    let logs = src.invoke_contract_expect_error(addr, "get", vec![5_u64.into_val(&src.env)]);
+    assert!(logs
+        .iter()
+        .any(|log| log.contains("storage array index out of bounds")));
+}
+
+#[test]
+fn storage_types() {
+    let src = build_solidity(
+        r#"contract durability {
+            @persistent
+            mapping(address => uint64) balances;
+
+            @temporary
+            mapping(address => uint64) nonces;
+
+            @instance
+            uint64 count;
+
+            uint64 total;
+
+            function set(address owner, uint64 amount) public {
+                balances[owner] = amount;
+                nonces[owner] += 1;
+                count += 1;
+                total += amount;
+            }
+
+            function get(address owner) public view returns (uint64) {
+                return balances[owner] + nonces[owner];
+            }
+
+            function extend(address owner) public {
+                balances[owner].extendTtl(100, 1000);
+                nonces[owner].extendTtl(100, 1000);
+                count.extendTtl(100, 1000);
+            }
+        }"#,
+    );
+
+    let addr = src.contracts.last().unwrap();
+    let owner = Address::random(&src.env);
+
+    // extending entries which do not exist yet does nothing
+    src.invoke_contract(addr, "extend", vec![owner.into_val(&src.env)]);
+
+    src.invoke_contract(
+        addr,
+        "set",
+        vec![owner.into_val(&src.env), 10_u64.into_val(&src.env)],
+    );
+
+    src.invoke_contract(addr, "extend", vec![owner.into_val(&src.env)]);
+
+    let res = src.invoke_contract(addr, "get", vec![owner.into_val(&src.env)]);
+    let expected: Val = 11_u64.into_val(&src.env);
+    assert!(expected.shallow_eq(&res));
+
+    // The key of a variable is its slot. Elements are keyed by a vec of the key of the
+    // variable and the index.
+    src.env.as_contract(addr, || {
+        let storage = src.env.storage();
+
+        assert!(storage.persistent().has(&(0_u64, owner.clone())));
+        assert!(!storage.instance().has(&(0_u64, owner.clone())));
+        assert!(storage.temporary().has(&(1_u64, owner.clone())));
+        assert!(storage.instance().has(&2_u64));
+        assert!(storage.instance().has(&3_u64));
+        assert!(!storage.persistent().has(&3_u64));
+    });
+}
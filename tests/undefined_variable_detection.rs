@@ -25,9 +25,11 @@ fn parse_and_codegen(src: &'static str) -> Namespace {
         generate_debug_information: false,
         log_runtime_errors: false,
         log_prints: true,
+        log_compute_units: false,
         #[cfg(feature = "wasm_opt")]
         wasm_opt: None,
         soroban_version: None,
+        soroban_native_token: None,
     };
 
     codegen(&mut ns, &opt);